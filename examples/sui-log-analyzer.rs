@@ -1,7 +1,7 @@
 use fluxus::api::{DataStream, io::CollectionSink};
 use fluxus::sources::Source;
 use fluxus::utils::window::WindowConfig;
-use fluxus_source_sui::SuiSource;
+use fluxus_source_sui::{SuiNetwork, SuiSource};
 use std::collections::HashMap;
 use std::time::Duration;
 
@@ -11,7 +11,7 @@ async fn main() {
     tracing_subscriber::fmt().init();
 
     // Create a Sui data source using Mainnet, polling every 500ms, fetching max 10 transactions
-    let mut sui_source = SuiSource::new_with_mainnet(500, 10);
+    let mut sui_source = SuiSource::for_network(SuiNetwork::Mainnet, 500, 10);
     sui_source
         .init()
         .await
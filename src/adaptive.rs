@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+/// Adaptive polling interval that follows chain activity
+///
+/// The interval shrinks by `speed_up` whenever a poll returns a full page and
+/// grows by `slow_down` whenever a poll comes back empty, staying between
+/// `min_interval` and `max_interval`. Partially filled pages leave it as is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdaptiveInterval {
+    /// Shortest interval, used while the chain is busy
+    pub min_interval: Duration,
+    /// Longest interval, used while the chain is idle
+    pub max_interval: Duration,
+    /// Factor applied to the interval after a full page, below 1
+    pub speed_up: f64,
+    /// Factor applied to the interval after an empty poll, above 1
+    pub slow_down: f64,
+}
+
+impl AdaptiveInterval {
+    /// Creates a new AdaptiveInterval instance halving and doubling the interval
+    ///
+    /// # Parameters
+    /// * `min_interval` - Shortest polling interval
+    /// * `max_interval` - Longest polling interval
+    pub fn new(min_interval: Duration, max_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            max_interval,
+            speed_up: 0.5,
+            slow_down: 2.0,
+        }
+    }
+
+    /// Returns the interval following a poll that fetched `fetched` out of `page_size` items
+    pub fn next_interval(&self, current: Duration, fetched: usize, page_size: usize) -> Duration {
+        let next = if fetched == 0 {
+            current.mul_f64(self.slow_down)
+        } else if fetched >= page_size {
+            current.mul_f64(self.speed_up)
+        } else {
+            current
+        };
+        next.clamp(self.min_interval, self.max_interval)
+    }
+}
@@ -0,0 +1,104 @@
+use fluxus::utils::models::StreamError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use sui_sdk::types::base_types::SuiAddress;
+use sui_sdk::types::crypto::Signature;
+use sui_sdk::types::signature::GenericSignature;
+
+/// Scheme of the signature authorizing a transaction on behalf of its sender
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AuthScheme {
+    /// Ed25519 key pair
+    Ed25519,
+    /// Secp256k1 key pair
+    Secp256k1,
+    /// Secp256r1 key pair
+    Secp256r1,
+    /// Multisig combining several keys
+    Multisig,
+    /// zkLogin proof of an OpenID credential
+    #[serde(rename = "zklogin")]
+    ZkLogin,
+    /// Passkey backed by a WebAuthn authenticator
+    Passkey,
+}
+
+impl AuthScheme {
+    /// Scheme of a signature
+    fn from_signature(signature: &GenericSignature) -> Self {
+        match signature {
+            GenericSignature::Signature(Signature::Ed25519SuiSignature(_)) => Self::Ed25519,
+            GenericSignature::Signature(Signature::Secp256k1SuiSignature(_)) => Self::Secp256k1,
+            GenericSignature::Signature(Signature::Secp256r1SuiSignature(_)) => Self::Secp256r1,
+            GenericSignature::MultiSig(_) | GenericSignature::MultiSigLegacy(_) => Self::Multisig,
+            GenericSignature::ZkLoginAuthenticator(_) => Self::ZkLogin,
+            GenericSignature::PasskeyAuthenticator(_) => Self::Passkey,
+        }
+    }
+
+    /// Scheme of the sender's signature among the signatures of a transaction
+    ///
+    /// Sponsored transactions are also signed by the gas owner, so the
+    /// signature is picked by the address it belongs to, falling back to the
+    /// first signature if no address can be derived.
+    pub(crate) fn of_sender(signatures: &[GenericSignature], sender: SuiAddress) -> Option<Self> {
+        let signature = signatures
+            .iter()
+            .find(|signature| SuiAddress::try_from(*signature).is_ok_and(|signer| signer == sender))
+            .or_else(|| signatures.first())?;
+        Some(Self::from_signature(signature))
+    }
+
+    /// Scheme of a serialized signature from its leading flag byte
+    pub(crate) fn from_flag(flag: u8) -> Option<Self> {
+        Some(match flag {
+            0x00 => Self::Ed25519,
+            0x01 => Self::Secp256k1,
+            0x02 => Self::Secp256r1,
+            0x03 => Self::Multisig,
+            0x05 => Self::ZkLogin,
+            0x06 => Self::Passkey,
+            _ => return None,
+        })
+    }
+
+    /// Name of the scheme, as serialized
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ed25519 => "ed25519",
+            Self::Secp256k1 => "secp256k1",
+            Self::Secp256r1 => "secp256r1",
+            Self::Multisig => "multisig",
+            Self::ZkLogin => "zklogin",
+            Self::Passkey => "passkey",
+        }
+    }
+}
+
+impl fmt::Display for AuthScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AuthScheme {
+    type Err = StreamError;
+
+    fn from_str(scheme: &str) -> Result<Self, Self::Err> {
+        match scheme {
+            "ed25519" => Ok(Self::Ed25519),
+            "secp256k1" => Ok(Self::Secp256k1),
+            "secp256r1" => Ok(Self::Secp256r1),
+            "multisig" => Ok(Self::Multisig),
+            "zklogin" => Ok(Self::ZkLogin),
+            "passkey" => Ok(Self::Passkey),
+            _ => Err(StreamError::Serialization(format!(
+                "Unknown auth scheme: {}",
+                scheme
+            ))),
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use apache_avro::Schema;
+use fluxus::utils::models::{StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+use crate::{BalanceChange, MoveEventSummary, ObjectChangeKind, ObjectChangeSummary, SuiEvent};
+
+/// Avro schema of [`SuiEvent`], for registering with a schema registry
+///
+/// Unsigned integers are encoded as `long`, balance change amounts as decimal
+/// strings, and transaction metadata, parsed Move events and the raw response
+/// as JSON strings.
+pub const SUI_EVENT_AVRO_SCHEMA: &str = r#"{
+  "type": "record",
+  "name": "SuiEvent",
+  "namespace": "fluxus.sui",
+  "fields": [
+    {"name": "transaction_digest", "type": "string"},
+    {"name": "transaction_type", "type": "string"},
+    {"name": "timestamp", "type": "long"},
+    {"name": "sender", "type": "string"},
+    {"name": "recipient", "type": ["null", "string"], "default": null},
+    {"name": "sender_label", "type": ["null", "string"], "default": null},
+    {"name": "recipient_label", "type": ["null", "string"], "default": null},
+    {"name": "amount", "type": ["null", "long"], "default": null},
+    {"name": "computation_cost", "type": "long"},
+    {"name": "storage_cost", "type": "long"},
+    {"name": "storage_rebate", "type": "long"},
+    {"name": "gas_fee", "type": "long"},
+    {"name": "gas_owner", "type": "string"},
+    {"name": "is_sponsored", "type": "boolean"},
+    {"name": "auth_scheme", "type": ["null", "string"], "default": null},
+    {"name": "success", "type": "boolean"},
+    {"name": "execution_error", "type": ["null", "string"], "default": null},
+    {"name": "events", "type": {"type": "array", "items": {
+      "type": "record",
+      "name": "MoveEventSummary",
+      "fields": [
+        {"name": "event_type", "type": "string"},
+        {"name": "package_id", "type": "string"},
+        {"name": "module", "type": "string"},
+        {"name": "parsed_json", "type": "string"},
+        {"name": "bcs", "type": ["null", "bytes"], "default": null}
+      ]
+    }}},
+    {"name": "balance_changes", "type": {"type": "array", "items": {
+      "type": "record",
+      "name": "BalanceChange",
+      "fields": [
+        {"name": "owner", "type": "string"},
+        {"name": "coin_type", "type": "string"},
+        {"name": "amount", "type": "string"},
+        {"name": "decimals", "type": ["null", "int"], "default": null},
+        {"name": "symbol", "type": ["null", "string"], "default": null},
+        {"name": "formatted_amount", "type": ["null", "string"], "default": null}
+      ]
+    }}},
+    {"name": "object_changes", "type": {"type": "array", "items": {
+      "type": "record",
+      "name": "ObjectChangeSummary",
+      "fields": [
+        {"name": "change_type", "type": {
+          "type": "enum",
+          "name": "ObjectChangeKind",
+          "symbols": ["created", "mutated", "transferred", "wrapped", "deleted", "published"]
+        }},
+        {"name": "object_id", "type": "string"},
+        {"name": "object_type", "type": ["null", "string"], "default": null},
+        {"name": "version", "type": "long"},
+        {"name": "owner", "type": ["null", "string"], "default": null}
+      ]
+    }}},
+    {"name": "uses_shared_objects", "type": "boolean"},
+    {"name": "shared_objects", "type": {"type": "array", "items": "string"}},
+    {"name": "created_count", "type": "long"},
+    {"name": "mutated_count", "type": "long"},
+    {"name": "deleted_count", "type": "long"},
+    {"name": "events_count", "type": "long"},
+    {"name": "metadata", "type": "string"},
+    {"name": "raw_transaction", "type": ["null", "bytes"], "default": null},
+    {"name": "raw", "type": ["null", "string"], "default": null}
+  ]
+}"#;
+
+/// Parsed [`SUI_EVENT_AVRO_SCHEMA`]
+static SCHEMA: LazyLock<Schema> = LazyLock::new(|| {
+    Schema::parse_str(SUI_EVENT_AVRO_SCHEMA).expect("SuiEvent Avro schema is valid")
+});
+
+/// Returns the parsed Avro schema of [`SuiEvent`]
+pub fn sui_event_avro_schema() -> &'static Schema {
+    &SCHEMA
+}
+
+impl SuiEvent {
+    /// Encodes the event as a single Avro datum without container header
+    ///
+    /// Suited for Kafka values, where the schema registry framing is added by
+    /// the producer.
+    pub fn to_avro(&self) -> StreamResult<Vec<u8>> {
+        let record = AvroSuiEvent::try_from(self)?;
+        let value = apache_avro::to_value(record)
+            .and_then(|value| value.resolve(&SCHEMA))
+            .map_err(serialization_error)?;
+        apache_avro::to_avro_datum(&SCHEMA, value).map_err(serialization_error)
+    }
+
+    /// Decodes an event encoded with [`SuiEvent::to_avro`]
+    pub fn from_avro(mut bytes: &[u8]) -> StreamResult<Self> {
+        let value =
+            apache_avro::from_avro_datum(&SCHEMA, &mut bytes, None).map_err(serialization_error)?;
+        let record: AvroSuiEvent = apache_avro::from_value(&value).map_err(serialization_error)?;
+        record.try_into()
+    }
+}
+
+/// Avro representation of [`SuiEvent`]
+#[derive(Serialize, Deserialize)]
+struct AvroSuiEvent {
+    transaction_digest: String,
+    transaction_type: String,
+    timestamp: i64,
+    sender: String,
+    recipient: Option<String>,
+    sender_label: Option<String>,
+    recipient_label: Option<String>,
+    amount: Option<i64>,
+    computation_cost: i64,
+    storage_cost: i64,
+    storage_rebate: i64,
+    gas_fee: i64,
+    gas_owner: String,
+    is_sponsored: bool,
+    auth_scheme: Option<String>,
+    success: bool,
+    execution_error: Option<String>,
+    events: Vec<AvroMoveEvent>,
+    balance_changes: Vec<AvroBalanceChange>,
+    object_changes: Vec<AvroObjectChange>,
+    uses_shared_objects: bool,
+    shared_objects: Vec<String>,
+    created_count: i64,
+    mutated_count: i64,
+    deleted_count: i64,
+    events_count: i64,
+    metadata: String,
+    #[serde(with = "apache_avro::serde_avro_bytes_opt")]
+    raw_transaction: Option<Vec<u8>>,
+    raw: Option<String>,
+}
+
+/// Avro representation of [`MoveEventSummary`]
+#[derive(Serialize, Deserialize)]
+struct AvroMoveEvent {
+    event_type: String,
+    package_id: String,
+    module: String,
+    parsed_json: String,
+    #[serde(with = "apache_avro::serde_avro_bytes_opt")]
+    bcs: Option<Vec<u8>>,
+}
+
+/// Avro representation of [`BalanceChange`]
+#[derive(Serialize, Deserialize)]
+struct AvroBalanceChange {
+    owner: String,
+    coin_type: String,
+    amount: String,
+    decimals: Option<i32>,
+    symbol: Option<String>,
+    formatted_amount: Option<String>,
+}
+
+/// Avro representation of [`ObjectChangeSummary`]
+#[derive(Serialize, Deserialize)]
+struct AvroObjectChange {
+    change_type: ObjectChangeKind,
+    object_id: String,
+    object_type: Option<String>,
+    version: i64,
+    owner: Option<String>,
+}
+
+impl TryFrom<&SuiEvent> for AvroSuiEvent {
+    type Error = StreamError;
+
+    fn try_from(event: &SuiEvent) -> StreamResult<Self> {
+        Ok(Self {
+            transaction_digest: event.transaction_digest.clone(),
+            transaction_type: event.transaction_type.clone(),
+            timestamp: long(event.timestamp)?,
+            sender: event.sender.clone(),
+            recipient: event.recipient.clone(),
+            sender_label: event.sender_label.clone(),
+            recipient_label: event.recipient_label.clone(),
+            amount: event.amount.map(long).transpose()?,
+            computation_cost: long(event.computation_cost)?,
+            storage_cost: long(event.storage_cost)?,
+            storage_rebate: long(event.storage_rebate)?,
+            gas_fee: event.gas_fee,
+            gas_owner: event.gas_owner.clone(),
+            is_sponsored: event.is_sponsored,
+            auth_scheme: event.auth_scheme.map(|scheme| scheme.to_string()),
+            success: event.success,
+            execution_error: event.execution_error.clone(),
+            events: event
+                .events
+                .iter()
+                .map(|move_event| AvroMoveEvent {
+                    event_type: move_event.event_type.clone(),
+                    package_id: move_event.package_id.clone(),
+                    module: move_event.module.clone(),
+                    parsed_json: move_event.parsed_json.to_string(),
+                    bcs: move_event.bcs.clone(),
+                })
+                .collect(),
+            balance_changes: event
+                .balance_changes
+                .iter()
+                .map(|change| AvroBalanceChange {
+                    owner: change.owner.clone(),
+                    coin_type: change.coin_type.clone(),
+                    amount: change.amount.to_string(),
+                    decimals: change.decimals.map(i32::from),
+                    symbol: change.symbol.clone(),
+                    formatted_amount: change.formatted_amount.clone(),
+                })
+                .collect(),
+            object_changes: event
+                .object_changes
+                .iter()
+                .map(|change| {
+                    Ok(AvroObjectChange {
+                        change_type: change.change_type,
+                        object_id: change.object_id.clone(),
+                        object_type: change.object_type.clone(),
+                        version: long(change.version)?,
+                        owner: change.owner.clone(),
+                    })
+                })
+                .collect::<StreamResult<_>>()?,
+            uses_shared_objects: event.uses_shared_objects,
+            shared_objects: event.shared_objects.clone(),
+            created_count: event.created_count as i64,
+            mutated_count: event.mutated_count as i64,
+            deleted_count: event.deleted_count as i64,
+            events_count: event.events_count as i64,
+            metadata: serde_json::to_string(&event.metadata).map_err(serialization_error)?,
+            raw_transaction: event.raw_transaction.clone(),
+            raw: event.raw.as_ref().map(|raw| raw.to_string()),
+        })
+    }
+}
+
+impl TryFrom<AvroSuiEvent> for SuiEvent {
+    type Error = StreamError;
+
+    fn try_from(record: AvroSuiEvent) -> StreamResult<Self> {
+        Ok(Self {
+            transaction_digest: record.transaction_digest,
+            transaction_type: record.transaction_type,
+            timestamp: unsigned(record.timestamp)?,
+            sender: record.sender,
+            recipient: record.recipient,
+            sender_label: record.sender_label,
+            recipient_label: record.recipient_label,
+            amount: record.amount.map(unsigned).transpose()?,
+            computation_cost: unsigned(record.computation_cost)?,
+            storage_cost: unsigned(record.storage_cost)?,
+            storage_rebate: unsigned(record.storage_rebate)?,
+            gas_fee: record.gas_fee,
+            gas_owner: record.gas_owner,
+            is_sponsored: record.is_sponsored,
+            auth_scheme: record
+                .auth_scheme
+                .map(|scheme| scheme.parse())
+                .transpose()?,
+            success: record.success,
+            execution_error: record.execution_error,
+            events: record
+                .events
+                .into_iter()
+                .map(|move_event| {
+                    Ok(MoveEventSummary {
+                        event_type: move_event.event_type,
+                        package_id: move_event.package_id,
+                        module: move_event.module,
+                        parsed_json: serde_json::from_str(&move_event.parsed_json)
+                            .map_err(serialization_error)?,
+                        bcs: move_event.bcs,
+                    })
+                })
+                .collect::<StreamResult<_>>()?,
+            balance_changes: record
+                .balance_changes
+                .into_iter()
+                .map(|change| {
+                    Ok(BalanceChange {
+                        owner: change.owner,
+                        coin_type: change.coin_type,
+                        amount: change.amount.parse().map_err(serialization_error)?,
+                        decimals: change
+                            .decimals
+                            .map(u8::try_from)
+                            .transpose()
+                            .map_err(serialization_error)?,
+                        symbol: change.symbol,
+                        formatted_amount: change.formatted_amount,
+                    })
+                })
+                .collect::<StreamResult<_>>()?,
+            object_changes: record
+                .object_changes
+                .into_iter()
+                .map(|change| {
+                    Ok(ObjectChangeSummary {
+                        change_type: change.change_type,
+                        object_id: change.object_id,
+                        object_type: change.object_type,
+                        version: unsigned(change.version)?,
+                        owner: change.owner,
+                    })
+                })
+                .collect::<StreamResult<_>>()?,
+            uses_shared_objects: record.uses_shared_objects,
+            shared_objects: record.shared_objects,
+            created_count: count(record.created_count)?,
+            mutated_count: count(record.mutated_count)?,
+            deleted_count: count(record.deleted_count)?,
+            events_count: count(record.events_count)?,
+            metadata: serde_json::from_str(&record.metadata).map_err(serialization_error)?,
+            raw_transaction: record.raw_transaction,
+            raw: record
+                .raw
+                .map(|raw| serde_json::from_str(&raw))
+                .transpose()
+                .map_err(serialization_error)?,
+        })
+    }
+}
+
+/// Converts an unsigned value to an Avro `long`
+fn long(value: u64) -> StreamResult<i64> {
+    i64::try_from(value).map_err(serialization_error)
+}
+
+/// Converts an Avro `long` back to an unsigned value
+fn unsigned(value: i64) -> StreamResult<u64> {
+    u64::try_from(value).map_err(serialization_error)
+}
+
+/// Converts an Avro `long` back to a count
+fn count(value: i64) -> StreamResult<usize> {
+    usize::try_from(value).map_err(serialization_error)
+}
+
+/// Wraps an encoding error
+fn serialization_error(e: impl std::fmt::Display) -> StreamError {
+    StreamError::Serialization(e.to_string())
+}
@@ -0,0 +1,242 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::{Balance, BalanceChange as RpcBalanceChange};
+use sui_sdk::types::base_types::SuiAddress;
+use tokio::time::sleep;
+
+use crate::object_change::owner_to_string;
+use crate::retry::{connect_client, now_millis};
+
+/// Balance change of a watched address
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiBalanceChange {
+    /// Watched address
+    pub address: String,
+    /// Coin type, e.g. `0x2::sui::SUI`
+    pub coin_type: String,
+    /// Balance before the change
+    pub previous_balance: u128,
+    /// Balance after the change
+    pub balance: u128,
+    /// Signed difference between the new and previous balance
+    pub delta: i128,
+    /// Time the change was observed
+    pub timestamp: u64,
+}
+
+/// Balance change caused by a transaction, embedded in [`crate::SuiEvent`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct BalanceChange {
+    /// Owner of the balance, an address or the kind of a non-address owner
+    pub owner: String,
+    /// Coin type, e.g. `0x2::sui::SUI`
+    pub coin_type: String,
+    /// Signed change in the coin's smallest unit
+    pub amount: i128,
+    /// Number of decimals of the coin, set when coin metadata enrichment is enabled
+    pub decimals: Option<u8>,
+    /// Coin symbol, set when coin metadata enrichment is enabled
+    pub symbol: Option<String>,
+    /// Change scaled by the coin's decimals, e.g. `-0.002500000` for SUI
+    pub formatted_amount: Option<String>,
+}
+
+impl From<&RpcBalanceChange> for BalanceChange {
+    fn from(change: &RpcBalanceChange) -> Self {
+        Self {
+            owner: owner_to_string(&change.owner),
+            coin_type: change.coin_type.to_string(),
+            amount: change.amount,
+            decimals: None,
+            symbol: None,
+            formatted_amount: None,
+        }
+    }
+}
+
+/// Sui data source polling coin balances of a watchlist of addresses
+///
+/// The first poll of an address records its current balances as a baseline.
+/// Every later poll emits a record for each coin type whose balance changed,
+/// including coin types that appeared since, changing from 0, and coin types
+/// the node no longer reports because they were drained, changing to 0.
+pub struct SuiBalanceSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Watched addresses
+    addresses: Vec<SuiAddress>,
+    /// Coin type to watch, all coin types when not set
+    coin_type: Option<String>,
+    /// Polling interval
+    interval: Duration,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Last observed balance per coin type of every polled address
+    balances: HashMap<SuiAddress, HashMap<String, u128>>,
+    /// Detected changes waiting to be emitted
+    buffer: VecDeque<SuiBalanceChange>,
+}
+
+impl SuiBalanceSource {
+    /// Creates a new SuiBalanceSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `addresses` - Addresses to watch
+    /// * `coin_type` - Coin type to watch, or `None` for all coin types
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(
+        rpc_url: String,
+        addresses: Vec<SuiAddress>,
+        coin_type: Option<String>,
+        interval_ms: u64,
+    ) -> Self {
+        Self {
+            rpc_url,
+            addresses,
+            coin_type,
+            interval: Duration::from_millis(interval_ms),
+            client: None,
+            balances: HashMap::new(),
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Fetches the watched balances of an address
+    async fn fetch_balances(&self, address: SuiAddress) -> StreamResult<Vec<Balance>> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiBalanceSource client not available".to_string())
+        })?;
+
+        let balances = match &self.coin_type {
+            Some(coin_type) => client
+                .coin_read_api()
+                .get_balance(address, Some(coin_type.clone()))
+                .await
+                .map(|balance| vec![balance]),
+            None => client.coin_read_api().get_all_balances(address).await,
+        };
+
+        balances.map_err(|e| {
+            tracing::error!("Failed to fetch balances of {}: {}", address, e);
+            StreamError::Runtime(e.to_string())
+        })
+    }
+
+    /// Polls every watched address and buffers the balances that changed
+    async fn poll(&mut self) -> StreamResult<()> {
+        let timestamp = now_millis();
+
+        for address in self.addresses.clone() {
+            let balances = self.fetch_balances(address).await?;
+            let current: HashMap<String, u128> = balances
+                .iter()
+                .map(|balance| (balance.coin_type.clone(), balance.total_balance))
+                .collect();
+
+            // The first poll of an address is the baseline, not a change
+            let Some(mut previous) = self.balances.insert(address, current) else {
+                continue;
+            };
+
+            for balance in balances {
+                let previous_balance = previous.remove(&balance.coin_type).unwrap_or(0);
+                self.push_change(
+                    address,
+                    balance.coin_type,
+                    previous_balance,
+                    balance.total_balance,
+                    timestamp,
+                );
+            }
+
+            // Nodes leave out coin types whose balance dropped to zero
+            let mut drained: Vec<(String, u128)> = previous.into_iter().collect();
+            drained.sort();
+            for (coin_type, previous_balance) in drained {
+                self.push_change(address, coin_type, previous_balance, 0, timestamp);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Buffers a balance change, unless the balance stayed the same
+    fn push_change(
+        &mut self,
+        address: SuiAddress,
+        coin_type: String,
+        previous_balance: u128,
+        balance: u128,
+        timestamp: u64,
+    ) {
+        if previous_balance == balance {
+            return;
+        }
+        self.buffer.push_back(SuiBalanceChange {
+            address: address.to_string(),
+            coin_type,
+            previous_balance,
+            balance,
+            delta: balance as i128 - previous_balance as i128,
+            timestamp,
+        });
+    }
+
+    /// Emits the balance changes detected but not emitted yet, without polling for more
+    ///
+    /// Call before `close` to flush in-flight records on shutdown.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiBalanceChange>>> {
+        Ok(self.buffer.drain(..).map(Record::new).collect())
+    }
+}
+
+#[async_trait]
+impl Source<SuiBalanceChange> for SuiBalanceSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_client(&self.rpc_url).await?;
+
+        self.client = Some(client);
+        tracing::info!(
+            "SuiBalanceSource initialized with RPC URL: {} watching {} addresses",
+            self.rpc_url,
+            self.addresses.len()
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiBalanceChange>>> {
+        if self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiBalanceSource not initialized".to_string(),
+            ));
+        }
+
+        if self.buffer.is_empty() {
+            sleep(self.interval).await;
+            self.poll().await?;
+        }
+
+        Ok(self.buffer.pop_front().map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        // Buffered changes are kept, the balances they were detected
+        // against already include them
+        self.client = None;
+        tracing::info!("SuiBalanceSource closed");
+        Ok(())
+    }
+}
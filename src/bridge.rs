@@ -0,0 +1,182 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use sui_sdk::rpc_types::EventFilter;
+use sui_sdk::types::{BRIDGE_PACKAGE_ID, Identifier};
+
+use crate::{SuiEventSource, SuiMoveEvent};
+
+/// Kind of bridge activity
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum BridgeAction {
+    /// Tokens were deposited into the bridge on Sui for transfer to another chain
+    Deposited,
+    /// Incoming transfer was approved by the bridge committee
+    Approved,
+    /// Incoming transfer was claimed by its recipient on Sui
+    Claimed,
+}
+
+/// Decoded Sui bridge event
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiBridgeEvent {
+    /// Digest of the emitting transaction
+    pub transaction_digest: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Kind of activity
+    pub action: BridgeAction,
+    /// Bridge message sequence number on the source chain
+    pub seq_num: u64,
+    /// Chain the transfer originates from
+    pub source_chain: String,
+    /// Chain the transfer is destined to (deposits only)
+    pub target_chain: Option<String>,
+    /// Bridged token (deposits only)
+    pub token: Option<String>,
+    /// Bridged amount in the token's bridge decimals (deposits only)
+    pub amount: Option<u64>,
+    /// Sender address on the source chain (deposits only)
+    pub sender: Option<String>,
+    /// Recipient address on the target chain (deposits only)
+    pub recipient: Option<String>,
+}
+
+/// Name of a bridge chain ID
+fn chain_name(chain_id: u64) -> String {
+    match chain_id {
+        0 => "sui_mainnet".to_string(),
+        1 => "sui_testnet".to_string(),
+        2 => "sui_custom".to_string(),
+        10 => "eth_mainnet".to_string(),
+        11 => "eth_sepolia".to_string(),
+        12 => "eth_custom".to_string(),
+        other => format!("unknown({})", other),
+    }
+}
+
+/// Symbol of a bridge token ID
+fn token_name(token_id: u64) -> String {
+    match token_id {
+        0 => "SUI".to_string(),
+        1 => "BTC".to_string(),
+        2 => "ETH".to_string(),
+        3 => "USDC".to_string(),
+        4 => "USDT".to_string(),
+        other => format!("unknown({})", other),
+    }
+}
+
+/// Formats an address encoded as a Move `vector<u8>` as hex
+fn address_bytes(value: &serde_json::Value) -> Option<String> {
+    let bytes = value.as_array()?;
+    let hex: String = bytes
+        .iter()
+        .map(|byte| byte.as_u64().map(|byte| format!("{:02x}", byte)))
+        .collect::<Option<_>>()?;
+    Some(format!("0x{}", hex))
+}
+
+impl SuiBridgeEvent {
+    /// Decodes an event of the bridge module, `None` for other events
+    pub fn decode(event: &SuiMoveEvent) -> Option<Self> {
+        let event_name = event.event_name();
+        let action = match event_name {
+            "TokenDepositedEvent" => BridgeAction::Deposited,
+            "TokenTransferApproved" => BridgeAction::Approved,
+            "TokenTransferClaimed" => BridgeAction::Claimed,
+            _ => return None,
+        };
+
+        if action != BridgeAction::Deposited {
+            let message_key = event.parsed_json.get("message_key")?;
+            let field_u64 = |field: &str| {
+                let value = message_key.get(field)?;
+                value
+                    .as_u64()
+                    .or_else(|| value.as_str().and_then(|value| value.parse().ok()))
+            };
+
+            return Some(Self {
+                transaction_digest: event.transaction_digest.clone(),
+                timestamp: event.timestamp,
+                action,
+                seq_num: field_u64("bridge_seq_num")?,
+                source_chain: chain_name(field_u64("source_chain")?),
+                target_chain: None,
+                token: None,
+                amount: None,
+                sender: None,
+                recipient: None,
+            });
+        }
+
+        Some(Self {
+            transaction_digest: event.transaction_digest.clone(),
+            timestamp: event.timestamp,
+            action,
+            seq_num: event.field_u64("seq_num")?,
+            source_chain: chain_name(event.field_u64("source_chain")?),
+            target_chain: event.field_u64("target_chain").map(chain_name),
+            token: event.field_u64("token_type").map(token_name),
+            amount: event.field_u64("amount"),
+            sender: event
+                .parsed_json
+                .get("sender_address")
+                .and_then(address_bytes),
+            recipient: event
+                .parsed_json
+                .get("target_address")
+                .and_then(address_bytes),
+        })
+    }
+}
+
+/// Sui data source emitting decoded bridge deposits, approvals and claims
+pub struct SuiBridgeSource {
+    /// Wrapped event source scoped to the bridge module
+    source: SuiEventSource,
+}
+
+impl SuiBridgeSource {
+    /// Creates a new SuiBridgeSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        let filter = EventFilter::MoveEventModule {
+            package: BRIDGE_PACKAGE_ID,
+            module: Identifier::new("bridge").expect("bridge is a valid identifier"),
+        };
+        Self {
+            source: SuiEventSource::new(rpc_url, filter, interval_ms, 50),
+        }
+    }
+}
+
+#[async_trait]
+impl Source<SuiBridgeEvent> for SuiBridgeSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiBridgeEvent>>> {
+        // Skip committee and limit events
+        while let Some(event) = self.source.next_event().await? {
+            if let Some(bridge_event) = SuiBridgeEvent::decode(&event) {
+                return Ok(Some(Record::new(bridge_event)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.source.close().await
+    }
+}
@@ -0,0 +1,1036 @@
+use chrono::{DateTime, Utc};
+use fluxus::utils::models::{StreamError, StreamResult};
+use http::header::{AUTHORIZATION, HeaderMap, HeaderName, HeaderValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::{SuiTransactionBlockResponseOptions, TransactionFilter};
+use sui_sdk::types::base_types::{ObjectID, SuiAddress};
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
+
+use crate::coin_cache::CoinMetadataCache;
+use crate::endpoint::EndpointPool;
+use crate::sui::{
+    DEFAULT_DEDUP_WINDOW, DEFAULT_HYDRATION_CONCURRENCY, DEFAULT_LAG_CHECK_INTERVAL,
+    default_query_options,
+};
+use crate::watermark::WatermarkTracker;
+use crate::{
+    AdaptiveInterval, AddressLabeler, AsyncFilterFn, CircuitBreakerPolicy, ConversionMode,
+    CursorStore, DeadLetterFn, DeadLetterSink, EventPredicate, FileCursorStore, FilterFn,
+    GapPolicy, LoadBalancing, RetryPolicy, SuiDeadLetter, SuiEvent, SuiNetwork, SuiQueryOrder,
+    SuiRateLimiter, SuiShard, SuiSource,
+};
+
+/// Maximum page size accepted by Sui fullnodes
+pub(crate) const MAX_PAGE_SIZE: usize = 50;
+
+/// Client supplied through [`SuiSourceBuilder::client`]
+#[derive(Clone)]
+struct InjectedClient(SuiClient);
+
+impl fmt::Debug for InjectedClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SuiClient").finish_non_exhaustive()
+    }
+}
+
+/// Builder for configuring a [`SuiSource`]
+///
+/// ```rust,no_run
+/// use fluxus_source_sui::{SuiNetwork, SuiSource};
+/// use std::time::Duration;
+///
+/// let source = SuiSource::builder()
+///     .network(SuiNetwork::Testnet)
+///     .interval(Duration::from_millis(500))
+///     .max_transactions(20)
+///     .request_timeout(Duration::from_secs(10))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct SuiSourceBuilder {
+    rpc_url: String,
+    client: Option<InjectedClient>,
+    interval: Duration,
+    max_transactions: usize,
+    options: Option<SuiTransactionBlockResponseOptions>,
+    filter: Option<TransactionFilter>,
+    from_address: Option<SuiAddress>,
+    to_address: Option<SuiAddress>,
+    move_function: Option<(ObjectID, Option<String>, Option<String>)>,
+    checkpoint_range: Option<(CheckpointSequenceNumber, CheckpointSequenceNumber)>,
+    start_checkpoint: Option<CheckpointSequenceNumber>,
+    time_range: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    start_digest: Option<TransactionDigest>,
+    cursor_store: Option<Arc<dyn CursorStore>>,
+    prefetch_depth: usize,
+    hydration_concurrency: usize,
+    request_timeout: Option<Duration>,
+    next_timeout: Option<Duration>,
+    headers: HashMap<String, String>,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<(u32, u32)>,
+    rate_limiter: Option<SuiRateLimiter>,
+    fallback_rpc_urls: Vec<String>,
+    failover_threshold: Option<u32>,
+    failover_recovery_interval: Option<Duration>,
+    circuit_breaker: Option<CircuitBreakerPolicy>,
+    load_balancing: LoadBalancing,
+    adaptive_interval: Option<AdaptiveInterval>,
+    dedup_window: usize,
+    gap_policy: GapPolicy,
+    order: SuiQueryOrder,
+    shard: Option<SuiShard>,
+    lag_check_interval: Duration,
+    conversion_mode: ConversionMode,
+    include_failed: bool,
+    skip_system_transactions: bool,
+    zklogin_only: bool,
+    coin_metadata: bool,
+    address_labeler: Option<Arc<dyn AddressLabeler>>,
+    event_filters: Vec<Arc<dyn EventPredicate>>,
+    include_bcs: bool,
+    include_raw_json: bool,
+    watermarks: bool,
+    dead_letter_sink: Option<Arc<dyn DeadLetterSink>>,
+}
+
+impl Default for SuiSourceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SuiSourceBuilder {
+    /// Creates a builder targeting Sui Mainnet with a 500ms interval and batch size of 10
+    pub fn new() -> Self {
+        Self {
+            rpc_url: SuiNetwork::Mainnet.rpc_url().to_string(),
+            client: None,
+            interval: Duration::from_millis(500),
+            max_transactions: 10,
+            options: None,
+            filter: None,
+            from_address: None,
+            to_address: None,
+            move_function: None,
+            checkpoint_range: None,
+            start_checkpoint: None,
+            time_range: None,
+            start_digest: None,
+            cursor_store: None,
+            prefetch_depth: 0,
+            hydration_concurrency: DEFAULT_HYDRATION_CONCURRENCY,
+            request_timeout: None,
+            next_timeout: None,
+            headers: HashMap::new(),
+            retry_policy: RetryPolicy::default(),
+            rate_limit: None,
+            rate_limiter: None,
+            fallback_rpc_urls: Vec::new(),
+            failover_threshold: None,
+            failover_recovery_interval: None,
+            circuit_breaker: None,
+            load_balancing: LoadBalancing::Failover,
+            adaptive_interval: None,
+            dedup_window: DEFAULT_DEDUP_WINDOW.get(),
+            gap_policy: GapPolicy::default(),
+            order: SuiQueryOrder::default(),
+            shard: None,
+            lag_check_interval: DEFAULT_LAG_CHECK_INTERVAL,
+            conversion_mode: ConversionMode::default(),
+            include_failed: true,
+            skip_system_transactions: false,
+            zklogin_only: false,
+            coin_metadata: false,
+            address_labeler: None,
+            event_filters: Vec::new(),
+            include_bcs: false,
+            include_raw_json: false,
+            watermarks: false,
+            dead_letter_sink: None,
+        }
+    }
+
+    /// Creates a builder configured from environment variables
+    ///
+    /// Unset variables keep the defaults of [`new`](Self::new):
+    ///
+    /// * `SUI_NETWORK` - `mainnet`, `testnet`, `devnet`, `localnet` or an RPC URL
+    /// * `SUI_RPC_URL` - RPC endpoint URL, takes precedence over `SUI_NETWORK`
+    /// * `SUI_POLL_INTERVAL_MS` - Polling interval in milliseconds
+    /// * `SUI_MAX_TRANSACTIONS` - Maximum number of transactions fetched per poll
+    /// * `SUI_REQUEST_TIMEOUT_MS` - Timeout applied to each RPC request in milliseconds
+    /// * `SUI_NEXT_TIMEOUT_MS` - Deadline of each `next()` call in milliseconds
+    /// * `SUI_FROM_ADDRESS` - Only fetch transactions sent by this address
+    /// * `SUI_TO_ADDRESS` - Only fetch transactions sent to this address
+    /// * `SUI_MOVE_FUNCTION` - Only fetch transactions calling `package[::module[::function]]`
+    /// * `SUI_RPC_HEADERS` - Extra HTTP headers as comma separated `name=value` pairs
+    /// * `SUI_RPC_BEARER_TOKEN` - Bearer token sent in the `Authorization` header
+    pub fn from_env() -> StreamResult<Self> {
+        let mut builder = Self::new();
+
+        if let Some(network) = env_var("SUI_NETWORK") {
+            builder = builder.network(network.parse()?);
+        }
+        if let Some(rpc_url) = env_var("SUI_RPC_URL") {
+            builder = builder.rpc_url(rpc_url);
+        }
+        if let Some(interval_ms) = parse_env_var::<u64>("SUI_POLL_INTERVAL_MS")? {
+            builder = builder.interval(Duration::from_millis(interval_ms));
+        }
+        if let Some(max_transactions) = parse_env_var("SUI_MAX_TRANSACTIONS")? {
+            builder = builder.max_transactions(max_transactions);
+        }
+        if let Some(timeout_ms) = parse_env_var::<u64>("SUI_REQUEST_TIMEOUT_MS")? {
+            builder = builder.request_timeout(Duration::from_millis(timeout_ms));
+        }
+        if let Some(timeout_ms) = parse_env_var::<u64>("SUI_NEXT_TIMEOUT_MS")? {
+            builder = builder.next_timeout(Duration::from_millis(timeout_ms));
+        }
+        if let Some(address) = parse_env_var("SUI_FROM_ADDRESS")? {
+            builder = builder.with_from_address(address);
+        }
+        if let Some(address) = parse_env_var("SUI_TO_ADDRESS")? {
+            builder = builder.with_to_address(address);
+        }
+        if let Some(move_function) = env_var("SUI_MOVE_FUNCTION") {
+            let (package, module, function) = parse_move_function(&move_function)?;
+            builder = builder.with_move_function(package, module, function);
+        }
+        if let Some(headers) = env_var("SUI_RPC_HEADERS") {
+            for header in headers
+                .split(',')
+                .filter(|header| !header.trim().is_empty())
+            {
+                let (name, value) = header.split_once('=').ok_or_else(|| {
+                    StreamError::Config(format!("Invalid SUI_RPC_HEADERS entry: {}", header))
+                })?;
+                builder = builder.with_header(name.trim(), value.trim());
+            }
+        }
+        if let Some(token) = env_var("SUI_RPC_BEARER_TOKEN") {
+            builder = builder.with_bearer_token(token);
+        }
+
+        Ok(builder)
+    }
+
+    /// Sets the Sui RPC endpoint URL
+    pub fn rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        self.rpc_url = rpc_url.into();
+        self
+    }
+
+    /// Sets the RPC endpoint URL to the fullnode of a well-known or custom network
+    pub fn network(mut self, network: SuiNetwork) -> Self {
+        self.rpc_url = network.rpc_url().to_string();
+        self
+    }
+
+    /// Reuses a client built by the application for the primary endpoint
+    ///
+    /// The client keeps its own settings, e.g. timeouts and connection pool,
+    /// so the request timeout and headers only apply to fallback endpoints.
+    /// The RPC URL should be the one the client is connected to, it is
+    /// reported in health and logs.
+    pub fn client(mut self, client: SuiClient) -> Self {
+        self.client = Some(InjectedClient(client));
+        self
+    }
+
+    /// Adds a fallback RPC endpoint used while the primary endpoint is failing
+    ///
+    /// Fallbacks are tried in the order they were added.
+    pub fn with_fallback_rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        self.fallback_rpc_urls.push(rpc_url.into());
+        self
+    }
+
+    /// Sets the number of consecutive failed requests after which the source
+    /// fails over to the next endpoint, 3 by default
+    pub fn failover_threshold(mut self, failover_threshold: u32) -> Self {
+        self.failover_threshold = Some(failover_threshold);
+        self
+    }
+
+    /// Sets how long after its last failure the primary endpoint is tried again, 60s by default
+    pub fn failover_recovery_interval(mut self, recovery_interval: Duration) -> Self {
+        self.failover_recovery_interval = Some(recovery_interval);
+        self
+    }
+
+    /// Guards every endpoint with a circuit breaker, disabled by default
+    ///
+    /// An endpoint whose circuit is open receives no requests until a probe
+    /// succeeds, the source fails over to the fallback endpoints meanwhile.
+    pub fn circuit_breaker(mut self, policy: CircuitBreakerPolicy) -> Self {
+        self.circuit_breaker = Some(policy);
+        self
+    }
+
+    /// Sets how requests are spread across the primary and fallback endpoints
+    pub fn load_balancing(mut self, strategy: LoadBalancing) -> Self {
+        self.load_balancing = strategy;
+        self
+    }
+
+    /// Sets the polling interval
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Adapts the polling interval to chain activity within the given bounds
+    ///
+    /// The interval set with [`interval`](Self::interval) is used for the
+    /// first poll, clamped to the bounds.
+    pub fn adaptive_interval(mut self, min_interval: Duration, max_interval: Duration) -> Self {
+        self.adaptive_interval = Some(AdaptiveInterval::new(min_interval, max_interval));
+        self
+    }
+
+    /// Uses a fully configured adaptive polling interval
+    pub fn adaptive_interval_with(mut self, adaptive_interval: AdaptiveInterval) -> Self {
+        self.adaptive_interval = Some(adaptive_interval);
+        self
+    }
+
+    /// Sets the number of recently emitted digests remembered to drop duplicate transactions
+    pub fn dedup_window(mut self, window: usize) -> Self {
+        self.dedup_window = window;
+        self
+    }
+
+    /// Sets how checkpoints missing between emitted transactions are handled
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.gap_policy = gap_policy;
+        self
+    }
+
+    /// Sets the order in which the transaction history is walked
+    ///
+    /// Defaults to [`SuiQueryOrder::Latest`], tailing the network from the
+    /// newest transactions.
+    pub fn order(mut self, order: SuiQueryOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Only emits the transactions of checkpoints assigned to shard `index` of `count`
+    ///
+    /// Instances sharing the count and covering every index split the stream
+    /// without duplicates, see [`SuiShard`]. Each instance still pages
+    /// through every transaction while tailing, in checkpoint range mode the
+    /// checkpoints of other shards are not fetched.
+    pub fn shard(mut self, index: u64, count: u64) -> Self {
+        self.shard = Some(SuiShard { index, count });
+        self
+    }
+
+    /// Fetches up to `depth` pages ahead in the background while walking forward
+    ///
+    /// Hides RPC latency behind downstream processing. Prefetching sticks to
+    /// the active endpoint instead of balancing load and does not apply to
+    /// checkpoint ranges. Disabled by default.
+    pub fn prefetch_depth(mut self, depth: usize) -> Self {
+        self.prefetch_depth = depth;
+        self
+    }
+
+    /// Sets how many requests fetching transaction details may run concurrently
+    ///
+    /// Applies when whole checkpoints are fetched, e.g. to backfill gaps.
+    pub fn hydration_concurrency(mut self, concurrency: usize) -> Self {
+        self.hydration_concurrency = concurrency;
+        self
+    }
+
+    /// Sets how often the latest network checkpoint is fetched to measure the consumer lag
+    pub fn lag_check_interval(mut self, interval: Duration) -> Self {
+        self.lag_check_interval = interval;
+        self
+    }
+
+    /// Sets how transactions with fields that cannot be extracted are handled
+    pub fn conversion_mode(mut self, mode: ConversionMode) -> Self {
+        self.conversion_mode = mode;
+        self
+    }
+
+    /// Sets whether transactions that failed to execute are emitted, `true` by default
+    pub fn include_failed(mut self, include_failed: bool) -> Self {
+        self.include_failed = include_failed;
+        self
+    }
+
+    /// Sets whether system transactions are dropped, `false` by default
+    ///
+    /// Consensus commit prologues, epoch changes, randomness and authenticator
+    /// state updates make up most of the unfiltered stream. They are detected
+    /// from the transaction input, which must not be disabled.
+    pub fn skip_system_transactions(mut self, skip: bool) -> Self {
+        self.skip_system_transactions = skip;
+        self
+    }
+
+    /// Sets whether only transactions signed with zkLogin are emitted, `false` by default
+    ///
+    /// The sender's signature scheme is read from the transaction input,
+    /// which must not be disabled.
+    pub fn zklogin_only(mut self, zklogin_only: bool) -> Self {
+        self.zklogin_only = zklogin_only;
+        self
+    }
+
+    /// Adds decimals, symbol and a scaled amount to the balance changes of emitted events
+    ///
+    /// Coin metadata is fetched once per coin type and cached. Disabled by default.
+    pub fn coin_metadata(mut self, enabled: bool) -> Self {
+        self.coin_metadata = enabled;
+        self
+    }
+
+    /// Attaches labels from the given labeler to the sender and recipient of emitted events
+    pub fn address_labeler(mut self, labeler: impl AddressLabeler + 'static) -> Self {
+        self.address_labeler = Some(Arc::new(labeler));
+        self
+    }
+
+    /// Emits only events the given predicate matches
+    ///
+    /// Applied after conversion, enrichment and labeling. Several filters
+    /// must all match.
+    pub fn event_filter(mut self, predicate: impl EventPredicate + 'static) -> Self {
+        self.event_filters.push(Arc::new(predicate));
+        self
+    }
+
+    /// Emits only events for which the closure returns `true`, see [`Self::event_filter`]
+    pub fn with_filter<F>(self, filter: F) -> Self
+    where
+        F: Fn(&SuiEvent) -> bool + Send + Sync + 'static,
+    {
+        self.event_filter(FilterFn(filter))
+    }
+
+    /// Emits only events for which the asynchronous closure resolves to `true`
+    ///
+    /// The closure receives a copy of the event. See [`Self::event_filter`].
+    pub fn with_async_filter<F, Fut>(self, filter: F) -> Self
+    where
+        F: Fn(SuiEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+    {
+        self.event_filter(AsyncFilterFn(filter))
+    }
+
+    /// Passes the BCS encoding of transactions and their events through on emitted events
+    ///
+    /// Lets downstream systems with their own Sui decoders work from the
+    /// canonical encoding. Disabled by default.
+    pub fn include_bcs(mut self, include_bcs: bool) -> Self {
+        self.include_bcs = include_bcs;
+        self
+    }
+
+    /// Attaches the full RPC response to emitted events as JSON in [`crate::SuiEvent::raw`]
+    ///
+    /// For fields the typed event does not cover yet. Which parts of the
+    /// response are populated follows the query options. Disabled by default.
+    pub fn include_raw_json(mut self, include_raw_json: bool) -> Self {
+        self.include_raw_json = include_raw_json;
+        self
+    }
+
+    /// Tracks watermarks derived from the checkpoints of emitted transactions
+    ///
+    /// Read them with [`SuiSource::watermark`] or subscribe with
+    /// [`SuiSource::watermark_receiver`] to close event-time windows. Disabled by
+    /// default and rejected with [`SuiQueryOrder::Descending`], and by
+    /// [`SuiMultiAddressSource`](crate::SuiMultiAddressSource) and
+    /// `SuiSubscriptionSource`, which emit out of checkpoint order.
+    pub fn watermarks(mut self, enabled: bool) -> Self {
+        self.watermarks = enabled;
+        self
+    }
+
+    /// Hands transactions that cannot be converted to the given sink instead of only logging them
+    pub fn dead_letter_sink(mut self, sink: impl DeadLetterSink + 'static) -> Self {
+        self.dead_letter_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Calls `callback` with every transaction that cannot be converted
+    pub fn on_dead_letter<F>(self, callback: F) -> Self
+    where
+        F: Fn(SuiDeadLetter) + Send + Sync + 'static,
+    {
+        self.dead_letter_sink(DeadLetterFn(callback))
+    }
+
+    /// Sets the maximum number of transactions fetched per poll
+    pub fn max_transactions(mut self, max_transactions: usize) -> Self {
+        self.max_transactions = max_transactions;
+        self
+    }
+
+    /// Sets the response options used when querying transactions
+    ///
+    /// Requesting less data speeds up polling at the cost of event fields
+    /// left empty. Input, effects, events, object changes and balance changes
+    /// are requested by default.
+    pub fn query_options(mut self, options: SuiTransactionBlockResponseOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Sets whether the transaction input is requested, sender and metadata are derived from it
+    pub fn show_input(self, show: bool) -> Self {
+        self.update_options(|options| options.show_input = show)
+    }
+
+    /// Sets whether the BCS-encoded transaction is requested, see [`Self::include_bcs`]
+    pub fn show_raw_input(self, show: bool) -> Self {
+        self.update_options(|options| options.show_raw_input = show)
+    }
+
+    /// Sets whether transaction effects are requested, gas and execution status are derived from them
+    pub fn show_effects(self, show: bool) -> Self {
+        self.update_options(|options| options.show_effects = show)
+    }
+
+    /// Sets whether the BCS-encoded transaction effects are requested
+    pub fn show_raw_effects(self, show: bool) -> Self {
+        self.update_options(|options| options.show_raw_effects = show)
+    }
+
+    /// Sets whether Move events are requested
+    pub fn show_events(self, show: bool) -> Self {
+        self.update_options(|options| options.show_events = show)
+    }
+
+    /// Sets whether object changes are requested
+    pub fn show_object_changes(self, show: bool) -> Self {
+        self.update_options(|options| options.show_object_changes = show)
+    }
+
+    /// Sets whether balance changes are requested, recipient and amount are derived from them
+    pub fn show_balance_changes(self, show: bool) -> Self {
+        self.update_options(|options| options.show_balance_changes = show)
+    }
+
+    /// Changes the response options, starting from the defaults if none are set
+    fn update_options(
+        mut self,
+        update: impl FnOnce(&mut SuiTransactionBlockResponseOptions),
+    ) -> Self {
+        update(self.options.get_or_insert_with(default_query_options));
+        self
+    }
+
+    /// Sets the server-side transaction filter
+    pub fn filter(mut self, filter: TransactionFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Only fetches transactions sent by the given address
+    pub fn with_from_address(mut self, address: SuiAddress) -> Self {
+        self.from_address = Some(address);
+        self
+    }
+
+    /// Only fetches transactions sent to the given address
+    ///
+    /// Combined with [`with_from_address`](Self::with_from_address) only
+    /// transactions between both addresses are fetched.
+    pub fn with_to_address(mut self, address: SuiAddress) -> Self {
+        self.to_address = Some(address);
+        self
+    }
+
+    /// Only fetches transactions calling into the given Move package
+    ///
+    /// Narrow the filter down to a module, or a single function of that module,
+    /// e.g. `0x..::amm::swap`.
+    pub fn with_move_function(
+        mut self,
+        package: ObjectID,
+        module: Option<String>,
+        function: Option<String>,
+    ) -> Self {
+        self.move_function = Some((package, module, function));
+        self
+    }
+
+    /// Only ingests transactions from checkpoints `start..=end`
+    ///
+    /// Backfills history: checkpoints are walked in ascending order and the
+    /// source permanently returns `Ok(None)` once the range has been emitted.
+    /// Completion is signaled through [`crate::SuiSourceHandle::wait_finished`].
+    /// Cannot be combined with other transaction filters.
+    pub fn with_checkpoint_range(
+        mut self,
+        start: CheckpointSequenceNumber,
+        end: CheckpointSequenceNumber,
+    ) -> Self {
+        self.checkpoint_range = Some((start, end));
+        self
+    }
+
+    /// Only ingests transactions executed within `from..to`
+    ///
+    /// The boundaries are resolved to a checkpoint range when the source is
+    /// initialized, which is then replayed like [`Self::with_checkpoint_range`].
+    /// `to` must not be later than the latest checkpoint. Cannot be combined
+    /// with a checkpoint range, a start position or transaction filters.
+    pub fn with_time_range(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        self.time_range = Some((from, to));
+        self
+    }
+
+    /// Starts at the first transaction of the given checkpoint instead of the newest transactions
+    ///
+    /// Lets a restarted pipeline resume where it left off and then keep
+    /// polling live. The source backfills up to the newest transactions and
+    /// then switches to tailing without gaps or duplicates, see
+    /// [`crate::SuiSourceHandle::wait_live`]. Cannot be combined with a checkpoint range.
+    pub fn with_start_checkpoint(mut self, checkpoint: CheckpointSequenceNumber) -> Self {
+        self.start_checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Starts right after the given transaction instead of the newest transactions
+    ///
+    /// The digest is used as the query cursor of the first fetch, so a
+    /// pipeline persisting the last emitted digest resumes without gaps or
+    /// duplicates. Cannot be combined with a start checkpoint or a checkpoint range.
+    pub fn with_start_digest(mut self, digest: TransactionDigest) -> Self {
+        self.start_digest = Some(digest);
+        self
+    }
+
+    /// Persists progress in the given store so a restarted source resumes where it left off
+    ///
+    /// A saved cursor takes precedence over the start checkpoint and digest.
+    pub fn cursor_store(mut self, cursor_store: impl CursorStore + 'static) -> Self {
+        self.cursor_store = Some(Arc::new(cursor_store));
+        self
+    }
+
+    /// Persists progress in a JSON file, see [`cursor_store`](Self::cursor_store)
+    pub fn cursor_file(self, path: impl Into<PathBuf>) -> Self {
+        self.cursor_store(FileCursorStore::new(path))
+    }
+
+    /// Sets the timeout applied to each RPC request
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the deadline of each `next()` call, after which it returns an error
+    ///
+    /// Covers the polling interval, retries and failover, so it should be
+    /// well above the polling interval.
+    pub fn next_timeout(mut self, timeout: Duration) -> Self {
+        self.next_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the retry policy applied to client initialization and every fetch
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limits RPC requests to `requests_per_second`, allowing bursts of up to `burst` requests
+    pub fn rate_limit(mut self, requests_per_second: u32, burst: u32) -> Self {
+        self.rate_limit = Some((requests_per_second, burst));
+        self
+    }
+
+    /// Uses a rate limiter shared with other sources, e.g. ones hitting the same fullnode
+    ///
+    /// Takes precedence over [`rate_limit`](Self::rate_limit).
+    pub fn rate_limiter(mut self, rate_limiter: SuiRateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Adds an HTTP header sent with every RPC request, e.g. the API key of a managed RPC provider
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Authenticates every RPC request with `Authorization: Bearer <token>`
+    pub fn with_bearer_token(self, token: impl AsRef<str>) -> Self {
+        self.with_header(AUTHORIZATION.as_str(), bearer(token.as_ref()))
+    }
+
+    /// Validates the configuration and builds the SuiSource
+    pub fn build(self) -> StreamResult<SuiSource> {
+        for rpc_url in std::iter::once(&self.rpc_url).chain(&self.fallback_rpc_urls) {
+            if !rpc_url.starts_with("http://") && !rpc_url.starts_with("https://") {
+                return Err(StreamError::Config(format!(
+                    "Invalid Sui RPC URL: {}",
+                    rpc_url
+                )));
+            }
+        }
+
+        if let LoadBalancing::Weighted(weights) = &self.load_balancing {
+            let endpoint_count = 1 + self.fallback_rpc_urls.len();
+            if weights.len() != endpoint_count || weights.iter().all(|&weight| weight == 0) {
+                return Err(StreamError::Config(format!(
+                    "Weighted load balancing needs one weight per endpoint ({}) and a non-zero weight",
+                    endpoint_count
+                )));
+            }
+        }
+
+        header_map(&self.headers)?;
+
+        if self.failover_threshold == Some(0) {
+            return Err(StreamError::Config(
+                "failover_threshold must be at least 1".to_string(),
+            ));
+        }
+
+        if let Some(policy) = &self.circuit_breaker {
+            if policy.failure_threshold == 0 {
+                return Err(StreamError::Config(
+                    "Circuit breaker failure_threshold must be at least 1".to_string(),
+                ));
+            }
+            if policy.open_duration.is_zero() || policy.open_duration > policy.max_open_duration {
+                return Err(StreamError::Config(
+                    "Circuit breaker open_duration must be greater than zero and at most max_open_duration"
+                        .to_string(),
+                ));
+            }
+        }
+
+        if self.max_transactions == 0 || self.max_transactions > MAX_PAGE_SIZE {
+            return Err(StreamError::Config(format!(
+                "max_transactions must be between 1 and {}, got {}",
+                MAX_PAGE_SIZE, self.max_transactions
+            )));
+        }
+
+        if self.retry_policy.max_attempts == 0 {
+            return Err(StreamError::Config(
+                "retry_policy.max_attempts must be at least 1".to_string(),
+            ));
+        }
+
+        if self
+            .request_timeout
+            .is_some_and(|timeout| timeout.is_zero())
+        {
+            return Err(StreamError::Config(
+                "request_timeout must be greater than zero".to_string(),
+            ));
+        }
+
+        if self.next_timeout.is_some_and(|timeout| timeout.is_zero()) {
+            return Err(StreamError::Config(
+                "next_timeout must be greater than zero".to_string(),
+            ));
+        }
+
+        if let Some(adaptive_interval) = &self.adaptive_interval {
+            // Speeding up from zero never slows down again, busy-looping against the RPC
+            if adaptive_interval.min_interval.is_zero() {
+                return Err(StreamError::Config(
+                    "Adaptive interval minimum must be above zero".to_string(),
+                ));
+            }
+            if adaptive_interval.min_interval > adaptive_interval.max_interval {
+                return Err(StreamError::Config(format!(
+                    "Adaptive interval minimum {:?} exceeds maximum {:?}",
+                    adaptive_interval.min_interval, adaptive_interval.max_interval
+                )));
+            }
+            if !(adaptive_interval.speed_up > 0.0 && adaptive_interval.speed_up <= 1.0)
+                || adaptive_interval.slow_down < 1.0
+            {
+                return Err(StreamError::Config(
+                    "Adaptive interval speed_up must be in (0, 1] and slow_down at least 1"
+                        .to_string(),
+                ));
+            }
+        }
+
+        if self.order == SuiQueryOrder::Descending
+            && (self.checkpoint_range.is_some()
+                || self.time_range.is_some()
+                || self.start_checkpoint.is_some())
+        {
+            return Err(StreamError::Config(
+                "Descending order cannot be combined with checkpoint ranges or a start checkpoint"
+                    .to_string(),
+            ));
+        }
+
+        // Watermarks only advance while checkpoints increase
+        if self.order == SuiQueryOrder::Descending && self.watermarks {
+            return Err(StreamError::Config(
+                "Descending order cannot be combined with watermarks".to_string(),
+            ));
+        }
+
+        if let Some(shard) = &self.shard {
+            shard.validate()?;
+        }
+
+        let dedup_window = NonZeroUsize::new(self.dedup_window)
+            .ok_or_else(|| StreamError::Config("dedup_window must be at least 1".to_string()))?;
+
+        if self.hydration_concurrency == 0 {
+            return Err(StreamError::Config(
+                "hydration_concurrency must be at least 1".to_string(),
+            ));
+        }
+
+        let filter = self.resolve_filter()?;
+
+        if let Some((start, end)) = self.checkpoint_range {
+            if start > end {
+                return Err(StreamError::Config(format!(
+                    "Invalid checkpoint range {}..={}",
+                    start, end
+                )));
+            }
+            if filter.is_some() {
+                return Err(StreamError::Config(
+                    "Checkpoint range cannot be combined with transaction filters".to_string(),
+                ));
+            }
+            if self.start_checkpoint.is_some() || self.start_digest.is_some() {
+                return Err(StreamError::Config(
+                    "Checkpoint range cannot be combined with a start position".to_string(),
+                ));
+            }
+        }
+
+        if let Some((from, to)) = self.time_range {
+            if from >= to {
+                return Err(StreamError::Config(format!(
+                    "Invalid time range {}..{}",
+                    from, to
+                )));
+            }
+            if self.checkpoint_range.is_some() {
+                return Err(StreamError::Config(
+                    "Time range cannot be combined with a checkpoint range".to_string(),
+                ));
+            }
+            if filter.is_some() {
+                return Err(StreamError::Config(
+                    "Time range cannot be combined with transaction filters".to_string(),
+                ));
+            }
+            if self.start_checkpoint.is_some() || self.start_digest.is_some() {
+                return Err(StreamError::Config(
+                    "Time range cannot be combined with a start position".to_string(),
+                ));
+            }
+        }
+
+        if self.start_checkpoint.is_some() && self.start_digest.is_some() {
+            return Err(StreamError::Config(
+                "Start checkpoint cannot be combined with a start digest".to_string(),
+            ));
+        }
+
+        let rate_limiter = match (self.rate_limiter, self.rate_limit) {
+            (Some(rate_limiter), _) => Some(rate_limiter),
+            (None, Some((requests_per_second, burst))) => {
+                Some(SuiRateLimiter::new(requests_per_second, burst)?)
+            }
+            (None, None) => None,
+        };
+
+        let mut endpoints = EndpointPool::new(self.rpc_url.clone(), self.fallback_rpc_urls);
+        if let Some(failover_threshold) = self.failover_threshold {
+            endpoints.failover_threshold = failover_threshold;
+        }
+        if let Some(recovery_interval) = self.failover_recovery_interval {
+            endpoints.recovery_interval = recovery_interval;
+        }
+        endpoints.strategy = self.load_balancing;
+        endpoints.circuit_breaker = self.circuit_breaker;
+
+        let mut source = SuiSource::new(self.rpc_url, 0, self.max_transactions);
+        source.endpoints = endpoints;
+        source.injected_client = self.client.map(|client| client.0);
+        source.interval = match &self.adaptive_interval {
+            Some(adaptive_interval) => self.interval.clamp(
+                adaptive_interval.min_interval,
+                adaptive_interval.max_interval,
+            ),
+            None => self.interval,
+        };
+        source.adaptive_interval = self.adaptive_interval;
+        source.set_dedup_window(dedup_window);
+        source.gap_policy = self.gap_policy;
+        if let Some(options) = self.options {
+            source.options = options;
+        }
+        source.filter = filter;
+        source.checkpoint_range = self.checkpoint_range;
+        source.start_checkpoint = self.start_checkpoint;
+        source.time_range = self.time_range;
+        if let Some(digest) = self.start_digest {
+            source.cursor = Some(digest);
+            source.walk_forward = true;
+        }
+        // Walking forward without a cursor starts at genesis
+        if self.order == SuiQueryOrder::Ascending {
+            source.walk_forward = true;
+        }
+        source.order = self.order;
+        source.shard = self.shard;
+        source.cursor_store = self.cursor_store;
+        source.prefetch_depth = self.prefetch_depth;
+        source.hydration_concurrency = self.hydration_concurrency;
+        source.lag_check_interval = self.lag_check_interval;
+        source.conversion_mode = self.conversion_mode;
+        source.include_failed = self.include_failed;
+        source.skip_system_transactions = self.skip_system_transactions;
+        source.zklogin_only = self.zklogin_only;
+        if self.coin_metadata {
+            source.coin_metadata_cache = Some(CoinMetadataCache::default());
+        }
+        source.address_labeler = self.address_labeler;
+        source.event_filters = self.event_filters;
+        source.include_bcs = self.include_bcs;
+        source.include_raw_json = self.include_raw_json;
+        if self.include_bcs {
+            source.options.show_raw_input = true;
+        }
+        if self.watermarks {
+            source.watermarks = Some(WatermarkTracker::default());
+        }
+        source.dead_letter_sink = self.dead_letter_sink;
+        source.request_timeout = self.request_timeout;
+        source.next_timeout = self.next_timeout;
+        source.headers = self.headers;
+        source.retry_policy = self.retry_policy;
+        source.rate_limiter = rate_limiter;
+
+        Ok(source)
+    }
+
+    /// Combines the raw filter and the convenience filters into the single
+    /// filter accepted by the transaction query
+    fn resolve_filter(&self) -> StreamResult<Option<TransactionFilter>> {
+        let address_filter = match (self.from_address, self.to_address) {
+            (Some(from), Some(to)) => Some(TransactionFilter::FromAndToAddress { from, to }),
+            (Some(from), None) => Some(TransactionFilter::FromAddress(from)),
+            (None, Some(to)) => Some(TransactionFilter::ToAddress(to)),
+            (None, None) => None,
+        };
+
+        let move_function_filter = match &self.move_function {
+            Some((_, None, Some(function))) => {
+                return Err(StreamError::Config(format!(
+                    "Move function filter '{}' requires a module",
+                    function
+                )));
+            }
+            Some((package, module, function)) => Some(TransactionFilter::MoveFunction {
+                package: *package,
+                module: module.clone(),
+                function: function.clone(),
+            }),
+            None => None,
+        };
+
+        let filters: Vec<TransactionFilter> =
+            [self.filter.clone(), address_filter, move_function_filter]
+                .into_iter()
+                .flatten()
+                .collect();
+
+        if filters.len() > 1 {
+            return Err(StreamError::Config(
+                "Only one transaction filter can be applied per query".to_string(),
+            ));
+        }
+
+        Ok(filters.into_iter().next())
+    }
+}
+
+/// Reads a non-empty environment variable
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+/// Reads and parses an environment variable
+fn parse_env_var<T>(name: &str) -> StreamResult<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    env_var(name)
+        .map(|value| {
+            value
+                .trim()
+                .parse()
+                .map_err(|e| StreamError::Config(format!("Invalid value for {}: {}", name, e)))
+        })
+        .transpose()
+}
+
+/// Returns the `Authorization` header value for a bearer token
+pub(crate) fn bearer(token: &str) -> String {
+    format!("Bearer {}", token)
+}
+
+/// Validates HTTP headers and converts them into a header map
+pub(crate) fn header_map(headers: &HashMap<String, String>) -> StreamResult<HeaderMap> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| StreamError::Config(format!("Invalid HTTP header {}: {}", name, e)))?;
+            let value = HeaderValue::from_str(value).map_err(|e| {
+                StreamError::Config(format!("Invalid value of HTTP header {}: {}", name, e))
+            })?;
+            Ok((name, value))
+        })
+        .collect()
+}
+
+/// Parses a `package[::module[::function]]` path into its parts
+pub(crate) fn parse_move_function(
+    path: &str,
+) -> StreamResult<(ObjectID, Option<String>, Option<String>)> {
+    let mut parts = path.trim().splitn(3, "::");
+    let package = parts
+        .next()
+        .unwrap_or_default()
+        .parse()
+        .map_err(|e| StreamError::Config(format!("Invalid Move package in {}: {}", path, e)))?;
+    Ok((
+        package,
+        parts.next().map(str::to_string),
+        parts.next().map(str::to_string),
+    ))
+}
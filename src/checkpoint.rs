@@ -0,0 +1,233 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::Checkpoint;
+use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_sdk::types::sui_serde::BigInt;
+use tokio::time::sleep;
+
+use crate::SuiShard;
+use crate::retry::connect_client;
+
+/// Summary of a Sui checkpoint
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiCheckpoint {
+    /// Checkpoint sequence number
+    pub sequence_number: u64,
+    /// Checkpoint digest
+    pub digest: String,
+    /// Epoch the checkpoint belongs to
+    pub epoch: u64,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Number of transactions in the checkpoint
+    pub transaction_count: usize,
+    /// Total number of transactions on the network up to this checkpoint
+    pub network_total_transactions: u64,
+    /// Computation cost accumulated in the epoch so far
+    pub epoch_computation_cost: u64,
+    /// Storage cost accumulated in the epoch so far
+    pub epoch_storage_cost: u64,
+    /// Storage rebate accumulated in the epoch so far
+    pub epoch_storage_rebate: u64,
+    /// Whether this is the last checkpoint of its epoch
+    pub end_of_epoch: bool,
+}
+
+impl From<Checkpoint> for SuiCheckpoint {
+    fn from(checkpoint: Checkpoint) -> Self {
+        let gas = &checkpoint.epoch_rolling_gas_cost_summary;
+        Self {
+            sequence_number: checkpoint.sequence_number,
+            digest: checkpoint.digest.to_string(),
+            epoch: checkpoint.epoch,
+            timestamp: checkpoint.timestamp_ms,
+            transaction_count: checkpoint.transactions.len(),
+            network_total_transactions: checkpoint.network_total_transactions,
+            epoch_computation_cost: gas.computation_cost,
+            epoch_storage_cost: gas.storage_cost,
+            epoch_storage_rebate: gas.storage_rebate,
+            end_of_epoch: checkpoint.end_of_epoch_data.is_some(),
+        }
+    }
+}
+
+/// Sui data source tailing checkpoints one by one, starting at the latest
+pub struct SuiCheckpointSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval
+    interval: Duration,
+    /// Maximum number of checkpoints to fetch per poll
+    max_checkpoints: usize,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Fetched checkpoints waiting to be emitted
+    buffer: VecDeque<Checkpoint>,
+    /// Next checkpoint to fetch
+    next_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Whether more checkpoints are available after the cursor
+    has_next_page: bool,
+    /// Share of the checkpoints emitted by this instance, all if not set
+    shard: Option<SuiShard>,
+}
+
+impl SuiCheckpointSource {
+    /// Creates a new SuiCheckpointSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `max_checkpoints` - Maximum number of checkpoints to fetch per poll
+    pub fn new(rpc_url: String, interval_ms: u64, max_checkpoints: usize) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            max_checkpoints,
+            client: None,
+            buffer: VecDeque::new(),
+            next_checkpoint: None,
+            has_next_page: false,
+            shard: None,
+        }
+    }
+
+    /// Only emits the checkpoints assigned to shard `index` of `count`, see [`SuiShard`]
+    pub fn with_shard(mut self, index: u64, count: u64) -> StreamResult<Self> {
+        self.shard = Some(SuiShard::new(index, count)?);
+        Ok(self)
+    }
+
+    /// Fetches the checkpoints following the last emitted one
+    async fn fetch_batch(&mut self) -> StreamResult<()> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiCheckpointSource client not available".to_string())
+        })?;
+
+        // Start tailing from the latest checkpoint on the first fetch
+        let next_checkpoint = match self.next_checkpoint {
+            Some(next_checkpoint) => next_checkpoint,
+            None => client
+                .read_api()
+                .get_latest_checkpoint_sequence_number()
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to fetch latest checkpoint: {}", e);
+                    StreamError::Runtime(e.to_string())
+                })?,
+        };
+
+        // The cursor is exclusive, so point it at the checkpoint before the next one
+        let page = client
+            .read_api()
+            .get_checkpoints(
+                next_checkpoint.checked_sub(1).map(BigInt::from),
+                Some(self.max_checkpoints),
+                false,
+            )
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch checkpoints: {}", e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        self.has_next_page = page.has_next_page;
+        self.next_checkpoint = Some(
+            page.data
+                .last()
+                .map_or(next_checkpoint, |checkpoint| checkpoint.sequence_number + 1),
+        );
+
+        if page.data.is_empty() {
+            tracing::info!("No new checkpoints found");
+            return Ok(());
+        }
+
+        let shard = self.shard;
+        let checkpoints: Vec<_> = page
+            .data
+            .into_iter()
+            .filter(|checkpoint| {
+                shard.is_none_or(|shard| shard.contains(checkpoint.sequence_number))
+            })
+            .collect();
+        tracing::debug!("Buffered {} new Sui checkpoints", checkpoints.len());
+        self.buffer.extend(checkpoints);
+
+        Ok(())
+    }
+
+    /// Emits the checkpoints fetched but not emitted yet, without polling for more
+    ///
+    /// Call before `close` to flush in-flight records on shutdown.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiCheckpoint>>> {
+        Ok(self
+            .buffer
+            .drain(..)
+            .map(|checkpoint| Record::new(SuiCheckpoint::from(checkpoint)))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl Source<SuiCheckpoint> for SuiCheckpointSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_client(&self.rpc_url).await?;
+
+        self.client = Some(client);
+        tracing::info!(
+            "SuiCheckpointSource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiCheckpoint>>> {
+        if self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiCheckpointSource not initialized".to_string(),
+            ));
+        }
+
+        if self.buffer.is_empty() {
+            // Polling interval, skipped while catching up on pending pages
+            if !self.has_next_page {
+                sleep(self.interval).await;
+            }
+            self.fetch_batch().await?;
+        }
+
+        Ok(self
+            .buffer
+            .pop_front()
+            .map(|checkpoint| Record::new(SuiCheckpoint::from(checkpoint))))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.client = None;
+
+        // Rewind to the first buffered checkpoint, so a source initialized
+        // again re-fetches what was buffered instead of skipping it
+        if let Some(checkpoint) = self.buffer.front() {
+            tracing::info!(
+                "Discarding {} buffered checkpoints, call drain() before close() to emit them",
+                self.buffer.len()
+            );
+            self.next_checkpoint = Some(checkpoint.sequence_number);
+            self.has_next_page = true;
+            self.buffer.clear();
+        }
+        tracing::info!("SuiCheckpointSource closed");
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_sdk::types::sui_serde::BigInt;
+use tokio::time::sleep;
+
+use crate::sui::timestamped_record;
+use crate::{SuiEvent, SuiSource};
+
+/// Transaction together with its position in the checkpoint sequence
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiCheckpointTransaction {
+    /// Checkpoint containing the transaction
+    pub checkpoint: u64,
+    /// Position of the transaction within its checkpoint
+    pub position: usize,
+    /// Whether this is the last transaction of its checkpoint
+    pub last_in_checkpoint: bool,
+    /// Transaction
+    pub transaction: SuiEvent,
+}
+
+/// Sui data source emitting every transaction in checkpoint order
+///
+/// Checkpoints are walked one by one without gaps and each checkpoint's
+/// transactions are emitted in the order they were executed, so
+/// `(checkpoint, position)` totally orders the stream. The wrapped source
+/// only provides the RPC endpoint, polling interval and query options; its
+/// transaction filter is ignored.
+pub struct SuiCheckpointTransactionSource {
+    /// Source providing the client, query options and event conversion
+    source: SuiSource,
+    /// Next checkpoint to ingest, the latest checkpoint when not set
+    next_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Whether more checkpoints are available after the next one
+    has_next_page: bool,
+    /// Transactions of the current checkpoint waiting to be emitted
+    buffer: VecDeque<SuiCheckpointTransaction>,
+    /// Checkpoint and position of the last emitted transaction
+    last_emitted: Option<(CheckpointSequenceNumber, usize)>,
+}
+
+impl SuiCheckpointTransactionSource {
+    /// Creates a new SuiCheckpointTransactionSource starting at the latest checkpoint
+    ///
+    /// # Parameters
+    /// * `source` - Source providing the RPC endpoint, polling interval and query options
+    pub fn new(source: SuiSource) -> Self {
+        Self {
+            source,
+            next_checkpoint: None,
+            has_next_page: false,
+            buffer: VecDeque::new(),
+            last_emitted: None,
+        }
+    }
+
+    /// Starts ingesting at the given checkpoint instead of the latest one
+    pub fn with_start_checkpoint(mut self, checkpoint: CheckpointSequenceNumber) -> Self {
+        self.next_checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Fetches the next checkpoint and buffers all of its transactions
+    async fn fetch_checkpoint(&mut self) -> StreamResult<()> {
+        let client = self.source.client().ok_or_else(|| {
+            StreamError::Runtime("SuiCheckpointTransactionSource client not available".to_string())
+        })?;
+
+        let next_checkpoint = match self.next_checkpoint {
+            Some(next_checkpoint) => next_checkpoint,
+            None => {
+                self.source.throttle().await;
+                client
+                    .read_api()
+                    .get_latest_checkpoint_sequence_number()
+                    .await
+                    .map_err(|e| {
+                        tracing::error!("Failed to fetch latest checkpoint: {}", e);
+                        StreamError::Runtime(e.to_string())
+                    })?
+            }
+        };
+
+        // The cursor is exclusive, so point it at the checkpoint before the next one
+        self.source.throttle().await;
+        let page = client
+            .read_api()
+            .get_checkpoints(
+                next_checkpoint.checked_sub(1).map(BigInt::from),
+                Some(1),
+                false,
+            )
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch checkpoint {}: {}", next_checkpoint, e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        let Some(checkpoint) = page.data.into_iter().next() else {
+            tracing::info!("Checkpoint {} not available yet", next_checkpoint);
+            self.next_checkpoint = Some(next_checkpoint);
+            self.has_next_page = false;
+            return Ok(());
+        };
+
+        let transactions = self
+            .source
+            .hydrate_transactions(client, &checkpoint.transactions)
+            .await?;
+
+        // Never emit a partial checkpoint
+        if transactions.len() != checkpoint.transactions.len() {
+            return Err(StreamError::Runtime(format!(
+                "Checkpoint {} has {} transactions but {} were returned",
+                checkpoint.sequence_number,
+                checkpoint.transactions.len(),
+                transactions.len()
+            )));
+        }
+
+        // Positions refer to the checkpoint, dead-lettered and filtered out
+        // transactions leave holes. The checkpoint is converted as a whole so
+        // a failing transaction never leaves part of it buffered.
+        let count = transactions.len();
+        let mut converted = Vec::with_capacity(count);
+        for (position, transaction) in transactions.into_iter().enumerate() {
+            if let Some(event) = self.source.process_transaction(transaction).await? {
+                converted.push(SuiCheckpointTransaction {
+                    checkpoint: checkpoint.sequence_number,
+                    position,
+                    last_in_checkpoint: false,
+                    transaction: event,
+                });
+            }
+        }
+        if let Some(last) = converted.last_mut() {
+            last.last_in_checkpoint = true;
+        }
+        // A checkpoint fetched again after close resumes after the last emitted transaction
+        if let Some((emitted_checkpoint, emitted_position)) = self.last_emitted
+            && emitted_checkpoint == checkpoint.sequence_number
+        {
+            converted.retain(|transaction| transaction.position > emitted_position);
+        }
+        self.buffer.extend(converted);
+
+        tracing::debug!(
+            "Buffered {} transactions from checkpoint {}",
+            count,
+            checkpoint.sequence_number
+        );
+        self.next_checkpoint = Some(checkpoint.sequence_number + 1);
+        self.has_next_page = page.has_next_page;
+
+        Ok(())
+    }
+
+    /// Takes the next buffered transaction
+    fn pop(&mut self) -> Option<Record<SuiCheckpointTransaction>> {
+        let transaction = self.buffer.pop_front()?;
+        self.last_emitted = Some((transaction.checkpoint, transaction.position));
+        let timestamp = transaction.transaction.timestamp;
+        Some(timestamped_record(transaction, timestamp))
+    }
+
+    /// Emits the rest of the current checkpoint without fetching the next one
+    ///
+    /// Call before `close` to flush in-flight records on shutdown.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiCheckpointTransaction>>> {
+        Ok(std::iter::from_fn(|| self.pop()).collect())
+    }
+}
+
+#[async_trait]
+impl Source<SuiCheckpointTransaction> for SuiCheckpointTransactionSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiCheckpointTransaction>>> {
+        if !self.source.is_initialized() {
+            return Err(StreamError::Runtime(
+                "SuiCheckpointTransactionSource not initialized".to_string(),
+            ));
+        }
+
+        if self.buffer.is_empty() {
+            // Polling interval, skipped while catching up on produced checkpoints
+            if !self.has_next_page && self.next_checkpoint.is_some() {
+                sleep(self.source.interval).await;
+            }
+            self.fetch_checkpoint().await?;
+        }
+
+        Ok(self.pop())
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        // Rewind to the checkpoint being emitted, so a source initialized
+        // again fetches it again instead of skipping the buffered transactions
+        if let Some(transaction) = self.buffer.front() {
+            tracing::info!(
+                "Discarding {} buffered transactions, call drain() before close() to emit them",
+                self.buffer.len()
+            );
+            self.next_checkpoint = Some(transaction.checkpoint);
+            self.has_next_page = true;
+            self.buffer.clear();
+        }
+        self.source.close().await
+    }
+}
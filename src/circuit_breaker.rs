@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Circuit breaker guarding every RPC endpoint of a [`crate::SuiSource`]
+///
+/// After `failure_threshold` consecutive failures the circuit of an endpoint
+/// opens and no request is sent to it for `open_duration`. The next request
+/// then probes the endpoint: success closes the circuit, failure opens it
+/// again for twice as long, up to `max_open_duration`. Combined with
+/// fallback endpoints the source moves on while the circuit is open.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CircuitBreakerPolicy {
+    /// Consecutive failures after which the circuit opens
+    pub failure_threshold: u32,
+    /// Time the circuit stays open before the first probe
+    pub open_duration: Duration,
+    /// Upper bound of the time the circuit stays open after failed probes
+    pub max_open_duration: Duration,
+}
+
+impl Default for CircuitBreakerPolicy {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(10),
+            max_open_duration: Duration::from_secs(300),
+        }
+    }
+}
+
+/// State of the circuit breaker of an RPC endpoint
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Requests are sent to the endpoint
+    #[default]
+    Closed,
+    /// The endpoint failed too often, no request is sent to it
+    Open,
+    /// The open duration elapsed, the next request probes the endpoint
+    HalfOpen,
+}
+
+/// Circuit of a single endpoint
+#[derive(Debug, Default)]
+pub(crate) struct Circuit {
+    /// Time the circuit opened, `None` while closed
+    opened_at: Option<Instant>,
+    /// Time the circuit stays open, doubled by every failed probe
+    open_duration: Duration,
+}
+
+impl Circuit {
+    /// Current state of the circuit
+    pub(crate) fn state(&self) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() >= self.open_duration => CircuitState::HalfOpen,
+            Some(_) => CircuitState::Open,
+        }
+    }
+
+    /// Closes the circuit after a successful request
+    pub(crate) fn record_success(&mut self) {
+        if self.opened_at.take().is_some() {
+            tracing::info!("Circuit breaker closed after successful probe");
+        }
+    }
+
+    /// Opens the circuit once the threshold is crossed or a probe failed
+    pub(crate) fn record_failure(
+        &mut self,
+        consecutive_failures: u32,
+        policy: &CircuitBreakerPolicy,
+    ) {
+        match self.state() {
+            CircuitState::Closed if consecutive_failures >= policy.failure_threshold => {
+                self.open_duration = policy.open_duration;
+            }
+            CircuitState::HalfOpen => {
+                self.open_duration = self
+                    .open_duration
+                    .saturating_mul(2)
+                    .min(policy.max_open_duration);
+            }
+            _ => return,
+        }
+        tracing::warn!("Circuit breaker open for {:?}", self.open_duration);
+        self.opened_at = Some(Instant::now());
+    }
+}
@@ -0,0 +1,108 @@
+use lru::LruCache;
+use std::num::NonZeroUsize;
+
+use crate::{SuiEvent, SuiSource};
+
+/// Number of coin types whose metadata is remembered
+const COIN_METADATA_CACHE_SIZE: NonZeroUsize = NonZeroUsize::new(1_000).unwrap();
+
+/// Decimals and symbol of a coin type
+#[derive(Clone, Debug)]
+struct CoinInfo {
+    /// Number of decimals of the coin
+    decimals: u8,
+    /// Coin symbol
+    symbol: String,
+}
+
+/// Coin metadata fetched so far, by coin type
+///
+/// Coin types without metadata are remembered too, so they are only looked
+/// up once. Failed lookups are retried with the next transaction. The least
+/// recently used coin types are evicted once the cache is full.
+#[derive(Debug)]
+pub(crate) struct CoinMetadataCache {
+    /// Metadata by coin type, `None` if the coin type has none
+    entries: LruCache<String, Option<CoinInfo>>,
+}
+
+impl Default for CoinMetadataCache {
+    fn default() -> Self {
+        Self {
+            entries: LruCache::new(COIN_METADATA_CACHE_SIZE),
+        }
+    }
+}
+
+impl SuiSource {
+    /// Adds decimals, symbol and scaled amount to the balance changes of an event
+    ///
+    /// Does nothing unless coin metadata enrichment is enabled. Metadata that
+    /// cannot be fetched is skipped without failing the stream.
+    ///
+    /// The cache stays in place while fetching, so a `next()` call cancelled
+    /// mid-lookup keeps everything cached so far.
+    pub(crate) async fn enrich_coin_metadata(&mut self, event: &mut SuiEvent) {
+        if self.coin_metadata_cache.is_none() {
+            return;
+        }
+
+        for change in &mut event.balance_changes {
+            let cached = self
+                .coin_metadata_cache
+                .as_mut()
+                .and_then(|cache| cache.entries.get(&change.coin_type).cloned());
+            let info = match cached {
+                Some(info) => info,
+                None => {
+                    let Some(info) = self.fetch_coin_info(&change.coin_type).await else {
+                        continue;
+                    };
+                    if let Some(cache) = &mut self.coin_metadata_cache {
+                        cache.entries.put(change.coin_type.clone(), info.clone());
+                    }
+                    info
+                }
+            };
+
+            if let Some(info) = info {
+                change.decimals = Some(info.decimals);
+                change.formatted_amount = Some(format_amount(change.amount, info.decimals));
+                change.symbol = Some(info.symbol);
+            }
+        }
+    }
+
+    /// Fetches the metadata of a coin type, `None` if the request failed
+    async fn fetch_coin_info(&self, coin_type: &str) -> Option<Option<CoinInfo>> {
+        let client = self.client()?;
+        self.throttle().await;
+        match client
+            .coin_read_api()
+            .get_coin_metadata(coin_type.to_string())
+            .await
+        {
+            Ok(metadata) => Some(metadata.map(|metadata| CoinInfo {
+                decimals: metadata.decimals,
+                symbol: metadata.symbol,
+            })),
+            Err(e) => {
+                tracing::warn!("Failed to fetch metadata of {}: {}", coin_type, e);
+                None
+            }
+        }
+    }
+}
+
+/// Formats an amount in the coin's smallest unit as a decimal string
+fn format_amount(amount: i128, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let sign = if amount < 0 { "-" } else { "" };
+    let digits = format!("{:0>width$}", amount.unsigned_abs(), width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    if fraction.is_empty() {
+        format!("{sign}{whole}")
+    } else {
+        format!("{sign}{whole}.{fraction}")
+    }
+}
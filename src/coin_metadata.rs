@@ -0,0 +1,198 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::SuiCoinMetadata as SuiRpcCoinMetadata;
+use tokio::time::sleep;
+
+use crate::SuiSource;
+use crate::retry::connect_client;
+
+/// Metadata of a Sui coin type
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiCoinMetadata {
+    /// Coin type, e.g. `0x2::sui::SUI`
+    pub coin_type: String,
+    /// Coin name
+    pub name: String,
+    /// Coin symbol
+    pub symbol: String,
+    /// Number of decimals of the coin
+    pub decimals: u8,
+    /// Coin description
+    pub description: String,
+    /// Icon URL (if set)
+    pub icon_url: Option<String>,
+    /// ID of the metadata object (if known)
+    pub metadata_id: Option<String>,
+}
+
+impl SuiCoinMetadata {
+    /// Builds a metadata record for a coin type
+    pub(crate) fn new(coin_type: String, metadata: SuiRpcCoinMetadata) -> Self {
+        Self {
+            coin_type,
+            name: metadata.name,
+            symbol: metadata.symbol,
+            decimals: metadata.decimals,
+            description: metadata.description,
+            icon_url: metadata.icon_url,
+            metadata_id: metadata.id.map(|id| id.to_string()),
+        }
+    }
+}
+
+/// Sui data source emitting coin metadata
+///
+/// Coin types are either given up front, or discovered from the balance
+/// changes of the transactions streamed by a wrapped [`SuiSource`]. Each coin
+/// type is emitted once.
+pub struct SuiCoinMetadataSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval
+    interval: Duration,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Source whose transactions are scanned for new coin types
+    discovery: Option<SuiSource>,
+    /// Coin types waiting to be fetched
+    pending: VecDeque<String>,
+    /// Coin types already queued
+    seen: HashSet<String>,
+}
+
+impl SuiCoinMetadataSource {
+    /// Creates a new SuiCoinMetadataSource for a fixed list of coin types
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `coin_types` - Coin types to fetch metadata for
+    /// * `interval_ms` - Polling interval in milliseconds once all coin types are emitted
+    pub fn new(rpc_url: String, coin_types: Vec<String>, interval_ms: u64) -> Self {
+        let mut source = Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            client: None,
+            discovery: None,
+            pending: VecDeque::new(),
+            seen: HashSet::new(),
+        };
+        coin_types
+            .into_iter()
+            .for_each(|coin_type| source.enqueue(coin_type));
+        source
+    }
+
+    /// Creates a new SuiCoinMetadataSource discovering coin types from transactions
+    ///
+    /// # Parameters
+    /// * `source` - Source whose transactions' balance changes are scanned
+    pub fn discover(mut source: SuiSource) -> Self {
+        source.options.show_balance_changes = true;
+        Self {
+            rpc_url: source.rpc_url.clone(),
+            interval: source.interval,
+            client: None,
+            discovery: Some(source),
+            pending: VecDeque::new(),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Queues a coin type unless it was queued before
+    fn enqueue(&mut self, coin_type: String) {
+        if self.seen.insert(coin_type.clone()) {
+            self.pending.push_back(coin_type);
+        }
+    }
+
+    /// Scans the next discovered transaction for new coin types
+    async fn discover_next(&mut self) -> StreamResult<()> {
+        let Some(source) = self.discovery.as_mut() else {
+            sleep(self.interval).await;
+            return Ok(());
+        };
+
+        let Some(transaction) = source.next_transaction().await? else {
+            return Ok(());
+        };
+
+        for change in transaction.balance_changes.unwrap_or_default() {
+            self.enqueue(change.coin_type.to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Source<SuiCoinMetadata> for SuiCoinMetadataSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if let Some(source) = self.discovery.as_mut() {
+            source.init().await?;
+        }
+
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_client(&self.rpc_url).await?;
+
+        self.client = Some(client);
+        tracing::info!(
+            "SuiCoinMetadataSource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiCoinMetadata>>> {
+        if self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiCoinMetadataSource not initialized".to_string(),
+            ));
+        }
+
+        if self.pending.is_empty() {
+            self.discover_next().await?;
+        }
+
+        let Some(coin_type) = self.pending.pop_front() else {
+            return Ok(None);
+        };
+
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiCoinMetadataSource client not available".to_string())
+        })?;
+        let metadata = client
+            .coin_read_api()
+            .get_coin_metadata(coin_type.clone())
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch metadata of {}: {}", coin_type, e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        let Some(metadata) = metadata else {
+            tracing::warn!("No metadata found for coin type {}", coin_type);
+            return Ok(None);
+        };
+
+        Ok(Some(Record::new(SuiCoinMetadata::new(coin_type, metadata))))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        if let Some(source) = self.discovery.as_mut() {
+            source.close().await?;
+        }
+        self.client = None;
+        tracing::info!("SuiCoinMetadataSource closed");
+        Ok(())
+    }
+}
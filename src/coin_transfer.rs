@@ -0,0 +1,172 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use sui_sdk::rpc_types::{
+    SuiArgument, SuiCommand, SuiTransactionBlockDataAPI, SuiTransactionBlockKind,
+    SuiTransactionBlockResponse,
+};
+
+use crate::SuiSource;
+use crate::derived::{self, DerivedBuffer, WrappingSource};
+
+/// How a coin transfer was performed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum CoinTransferKind {
+    /// SUI split off the gas coin and sent
+    PaySui,
+    /// Coins split off a non-gas coin and sent
+    Pay,
+    /// Whole coin objects sent as-is
+    Transfer,
+    /// Coins moved by a Move call, e.g. a swap or a contract payout
+    MoveCall,
+    /// Any other transaction kind, e.g. system transactions
+    Other,
+}
+
+impl CoinTransferKind {
+    /// Classifies a transaction by the commands of its programmable transaction block
+    fn of(transaction: &SuiTransactionBlockResponse) -> Self {
+        let Some(SuiTransactionBlockKind::ProgrammableTransaction(ptb)) = transaction
+            .transaction
+            .as_ref()
+            .map(|tx| tx.data.transaction())
+        else {
+            return Self::Other;
+        };
+
+        let mut kind = Self::Transfer;
+        for command in &ptb.commands {
+            match command {
+                SuiCommand::MoveCall(_) => return Self::MoveCall,
+                SuiCommand::SplitCoins(SuiArgument::GasCoin, _) => kind = Self::PaySui,
+                SuiCommand::SplitCoins(_, _) if kind != Self::PaySui => kind = Self::Pay,
+                _ => {}
+            }
+        }
+        kind
+    }
+}
+
+/// Decoded coin transfer
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiCoinTransfer {
+    /// Digest of the transaction
+    pub transaction_digest: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Coin type, e.g. `0x2::sui::SUI`
+    pub coin_type: String,
+    /// Sender of the transaction
+    pub sender: String,
+    /// Address receiving the coins
+    pub recipient: String,
+    /// Amount received, in the coin's smallest unit
+    pub amount: u128,
+    /// How the transfer was performed
+    pub kind: CoinTransferKind,
+}
+
+impl SuiCoinTransfer {
+    /// Decodes the coin transfers of a transaction from its balance changes
+    ///
+    /// Every positive balance change of an address other than the sender is a
+    /// transfer from the sender. The sender's own changes, such as gas
+    /// payments and change coins, are not transfers.
+    pub fn decode(transaction: &SuiTransactionBlockResponse) -> Vec<Self> {
+        let Some(sender) = transaction.transaction.as_ref().map(|tx| *tx.data.sender()) else {
+            return Vec::new();
+        };
+
+        let kind = CoinTransferKind::of(transaction);
+        transaction
+            .balance_changes
+            .iter()
+            .flatten()
+            .filter(|change| change.amount > 0)
+            .filter_map(|change| {
+                let recipient = change.owner.get_owner_address().ok()?;
+                (recipient != sender).then(|| Self {
+                    transaction_digest: transaction.digest.to_string(),
+                    timestamp: transaction.timestamp_ms.unwrap_or(0),
+                    coin_type: change.coin_type.to_string(),
+                    sender: sender.to_string(),
+                    recipient: recipient.to_string(),
+                    amount: change.amount as u128,
+                    kind,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Sui data source emitting one record per coin transfer
+///
+/// Wraps a [`SuiSource`] and decodes the balance changes of its transactions
+/// into sender, recipient and amount.
+pub struct SuiCoinTransferSource {
+    /// Wrapped transaction source
+    source: SuiSource,
+    /// Decoded transfers waiting to be emitted
+    buffer: DerivedBuffer<SuiCoinTransfer>,
+}
+
+impl SuiCoinTransferSource {
+    /// Creates a new SuiCoinTransferSource instance
+    ///
+    /// # Parameters
+    /// * `source` - Source providing the RPC endpoint and transaction filters
+    pub fn new(mut source: SuiSource) -> Self {
+        source.options.show_input = true;
+        source.options.show_balance_changes = true;
+        Self {
+            source,
+            buffer: DerivedBuffer::new(),
+        }
+    }
+
+    /// Emits the transfers of the transactions fetched but not emitted yet,
+    /// without polling for more, see [`SuiSource::drain`]
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiCoinTransfer>>> {
+        derived::drain(self).await
+    }
+}
+
+impl WrappingSource<SuiCoinTransfer> for SuiCoinTransferSource {
+    fn inner(&mut self) -> &mut SuiSource {
+        &mut self.source
+    }
+
+    fn has_buffered(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+}
+
+#[async_trait]
+impl Source<SuiCoinTransfer> for SuiCoinTransferSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiCoinTransfer>>> {
+        // Transactions without transfers leave the buffer empty
+        if self.buffer.is_empty() {
+            let Some(transaction) = self.source.next_transaction().await? else {
+                return Ok(None);
+            };
+            let transfers = SuiCoinTransfer::decode(&transaction);
+            self.buffer.fill(transaction, transfers);
+        }
+
+        Ok(self.buffer.pop().map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.buffer.close(&mut self.source);
+        self.source.close().await
+    }
+}
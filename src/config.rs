@@ -0,0 +1,459 @@
+use chrono::{DateTime, Utc};
+use fluxus::utils::models::{StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+#[cfg(feature = "config")]
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use sui_sdk::types::base_types::SuiAddress;
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
+
+use crate::builder::parse_move_function;
+use crate::{
+    AdaptiveInterval, CircuitBreakerPolicy, ConversionMode, GapPolicy, LoadBalancing, RetryPolicy,
+    StaticAddressLabeler, SuiNetwork, SuiQueryOrder, SuiShard, SuiSource, SuiSourceBuilder,
+};
+
+/// Transaction filters of a [`SuiSourceConfig`]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SuiFilterConfig {
+    /// Only fetch transactions sent by this address
+    pub from_address: Option<SuiAddress>,
+    /// Only fetch transactions sent to this address
+    pub to_address: Option<SuiAddress>,
+    /// Only fetch transactions calling `package[::module[::function]]`
+    pub move_function: Option<String>,
+    /// Only ingest transactions from this inclusive checkpoint range
+    pub checkpoint_range: Option<(CheckpointSequenceNumber, CheckpointSequenceNumber)>,
+    /// Only ingest transactions executed within this time range, end exclusive
+    pub time_range: Option<(DateTime<Utc>, DateTime<Utc>)>,
+}
+
+/// Retry policy of a [`SuiSourceConfig`], see [`RetryPolicy`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SuiRetryConfig {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry in milliseconds
+    pub base_delay_ms: u64,
+    /// Upper bound of the delay between attempts in milliseconds
+    pub max_delay_ms: u64,
+    /// Fraction of the delay that is randomized, between 0 and 1
+    pub jitter: f64,
+    /// Delay after a rate-limit response without Retry-After hint in milliseconds
+    pub rate_limit_cooldown_ms: u64,
+}
+
+impl Default for SuiRetryConfig {
+    fn default() -> Self {
+        let policy = RetryPolicy::default();
+        Self {
+            max_attempts: policy.max_attempts,
+            base_delay_ms: policy.base_delay.as_millis() as u64,
+            max_delay_ms: policy.max_delay.as_millis() as u64,
+            jitter: policy.jitter,
+            rate_limit_cooldown_ms: policy.rate_limit_cooldown.as_millis() as u64,
+        }
+    }
+}
+
+impl From<SuiRetryConfig> for RetryPolicy {
+    fn from(config: SuiRetryConfig) -> Self {
+        Self {
+            max_attempts: config.max_attempts,
+            base_delay: Duration::from_millis(config.base_delay_ms),
+            max_delay: Duration::from_millis(config.max_delay_ms),
+            jitter: config.jitter,
+            rate_limit_cooldown: Duration::from_millis(config.rate_limit_cooldown_ms),
+        }
+    }
+}
+
+/// Circuit breaker of a [`SuiSourceConfig`], see [`CircuitBreakerPolicy`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SuiCircuitBreakerConfig {
+    /// Consecutive failures after which the circuit opens
+    pub failure_threshold: u32,
+    /// Time the circuit stays open before the first probe in milliseconds
+    pub open_duration_ms: u64,
+    /// Upper bound of the time the circuit stays open in milliseconds
+    pub max_open_duration_ms: u64,
+}
+
+impl Default for SuiCircuitBreakerConfig {
+    fn default() -> Self {
+        let policy = CircuitBreakerPolicy::default();
+        Self {
+            failure_threshold: policy.failure_threshold,
+            open_duration_ms: policy.open_duration.as_millis() as u64,
+            max_open_duration_ms: policy.max_open_duration.as_millis() as u64,
+        }
+    }
+}
+
+impl From<SuiCircuitBreakerConfig> for CircuitBreakerPolicy {
+    fn from(config: SuiCircuitBreakerConfig) -> Self {
+        Self {
+            failure_threshold: config.failure_threshold,
+            open_duration: Duration::from_millis(config.open_duration_ms),
+            max_open_duration: Duration::from_millis(config.max_open_duration_ms),
+        }
+    }
+}
+
+/// Client-side rate limit of a [`SuiSourceConfig`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SuiRateLimitConfig {
+    /// Sustained request rate
+    pub requests_per_second: u32,
+    /// Maximum number of requests allowed at once, defaults to `requests_per_second`
+    pub burst: Option<u32>,
+}
+
+/// Response options of a [`SuiSourceConfig`], unset fields keep their default
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SuiQueryOptionsConfig {
+    /// Whether the transaction input is requested
+    pub input: Option<bool>,
+    /// Whether the BCS-encoded transaction is requested
+    pub raw_input: Option<bool>,
+    /// Whether transaction effects are requested
+    pub effects: Option<bool>,
+    /// Whether the BCS-encoded transaction effects are requested
+    pub raw_effects: Option<bool>,
+    /// Whether Move events are requested
+    pub events: Option<bool>,
+    /// Whether object changes are requested
+    pub object_changes: Option<bool>,
+    /// Whether balance changes are requested
+    pub balance_changes: Option<bool>,
+}
+
+/// Adaptive polling interval of a [`SuiSourceConfig`], see [`AdaptiveInterval`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SuiAdaptiveIntervalConfig {
+    /// Shortest polling interval in milliseconds
+    pub min_interval_ms: u64,
+    /// Longest polling interval in milliseconds
+    pub max_interval_ms: u64,
+}
+
+impl From<SuiAdaptiveIntervalConfig> for AdaptiveInterval {
+    fn from(config: SuiAdaptiveIntervalConfig) -> Self {
+        Self::new(
+            Duration::from_millis(config.min_interval_ms),
+            Duration::from_millis(config.max_interval_ms),
+        )
+    }
+}
+
+/// Declarative [`SuiSource`] configuration, loadable from TOML or YAML
+///
+/// Every field is optional and falls back to the [`SuiSourceBuilder`] default.
+///
+/// ```toml
+/// network = "testnet"
+/// interval_ms = 250
+/// max_transactions = 50
+///
+/// [retry]
+/// max_attempts = 3
+///
+/// [headers]
+/// x-api-key = "secret"
+///
+/// [filter]
+/// move_function = "0x2::coin"
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SuiSourceConfig {
+    /// Network to connect to
+    pub network: Option<SuiNetwork>,
+    /// RPC endpoint URL, takes precedence over `network`
+    pub rpc_url: Option<String>,
+    /// Fallback RPC endpoint URLs, tried in order while the primary endpoint is failing
+    pub fallback_rpc_urls: Vec<String>,
+    /// Consecutive failed requests after which the source fails over
+    pub failover_threshold: Option<u32>,
+    /// Time after its last failure before the primary endpoint is tried again in milliseconds
+    pub failover_recovery_interval_ms: Option<u64>,
+    /// Circuit breaker applied to every endpoint
+    pub circuit_breaker: Option<SuiCircuitBreakerConfig>,
+    /// Strategy for spreading requests across the endpoints
+    pub load_balancing: Option<LoadBalancing>,
+    /// Polling interval in milliseconds
+    pub interval_ms: Option<u64>,
+    /// Adaptive polling interval bounds
+    pub adaptive_interval: Option<SuiAdaptiveIntervalConfig>,
+    /// Maximum number of transactions fetched per poll
+    pub max_transactions: Option<usize>,
+    /// Checkpoint to resume from instead of the newest transactions
+    pub start_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Transaction digest to resume after instead of the newest transactions
+    pub start_digest: Option<TransactionDigest>,
+    /// JSON file persisting progress across restarts
+    pub cursor_file: Option<PathBuf>,
+    /// Number of recently emitted digests remembered to drop duplicate transactions
+    pub dedup_window: Option<usize>,
+    /// Number of pages fetched ahead in the background
+    pub prefetch_depth: Option<usize>,
+    /// Maximum number of concurrent requests fetching transaction details
+    pub hydration_concurrency: Option<usize>,
+    /// Interval between consumer lag measurements in milliseconds
+    pub lag_check_interval_ms: Option<u64>,
+    /// Reaction to checkpoints missing between emitted transactions
+    pub gap_policy: Option<GapPolicy>,
+    /// Order in which the transaction history is walked
+    pub order: Option<SuiQueryOrder>,
+    /// Share of the checkpoints emitted by this instance
+    pub shard: Option<SuiShard>,
+    /// Handling of transactions with fields that cannot be extracted
+    pub conversion_mode: Option<ConversionMode>,
+    /// Whether transactions that failed to execute are emitted
+    pub include_failed: Option<bool>,
+    /// Whether system transactions are dropped
+    pub skip_system_transactions: Option<bool>,
+    /// Whether only transactions signed with zkLogin are emitted
+    pub zklogin_only: Option<bool>,
+    /// Whether balance changes are enriched with coin metadata
+    pub coin_metadata: Option<bool>,
+    /// File mapping addresses to labels, see [`StaticAddressLabeler::from_file`]
+    pub address_labels: Option<PathBuf>,
+    /// Whether the BCS encoding of transactions and events is passed through
+    pub include_bcs: Option<bool>,
+    /// Whether the full RPC response is attached to events as JSON
+    pub include_raw_json: Option<bool>,
+    /// Whether watermarks are tracked
+    pub watermarks: Option<bool>,
+    /// Timeout applied to each RPC request in milliseconds
+    pub request_timeout_ms: Option<u64>,
+    /// Deadline of each `next()` call in milliseconds
+    pub next_timeout_ms: Option<u64>,
+    /// Extra HTTP headers sent with every RPC request
+    pub headers: HashMap<String, String>,
+    /// Bearer token sent in the `Authorization` header
+    pub bearer_token: Option<String>,
+    /// Retry policy applied to RPC requests
+    pub retry: Option<SuiRetryConfig>,
+    /// Client-side rate limit applied to RPC requests
+    pub rate_limit: Option<SuiRateLimitConfig>,
+    /// Response options used when querying transactions
+    pub query_options: Option<SuiQueryOptionsConfig>,
+    /// Transaction filters
+    pub filter: SuiFilterConfig,
+}
+
+impl SuiSourceConfig {
+    /// Parses a configuration from TOML
+    #[cfg(feature = "config")]
+    pub fn from_toml_str(config: &str) -> StreamResult<Self> {
+        toml::from_str(config)
+            .map_err(|e| StreamError::Config(format!("Invalid TOML configuration: {}", e)))
+    }
+
+    /// Parses a configuration from YAML
+    #[cfg(feature = "config")]
+    pub fn from_yaml_str(config: &str) -> StreamResult<Self> {
+        serde_yaml::from_str(config)
+            .map_err(|e| StreamError::Config(format!("Invalid YAML configuration: {}", e)))
+    }
+
+    /// Loads a configuration file, picking the format from the `.toml`, `.yaml` or `.yml` extension
+    #[cfg(feature = "config")]
+    pub fn from_file(path: impl AsRef<Path>) -> StreamResult<Self> {
+        let path = path.as_ref();
+        let config = std::fs::read_to_string(path).map_err(|e| {
+            StreamError::Config(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml_str(&config),
+            Some("yaml" | "yml") => Self::from_yaml_str(&config),
+            _ => Err(StreamError::Config(format!(
+                "Unsupported configuration format: {}",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Converts the configuration into a builder for further adjustments
+    pub fn into_builder(self) -> StreamResult<SuiSourceBuilder> {
+        let mut builder = SuiSourceBuilder::new();
+
+        if let Some(network) = self.network {
+            builder = builder.network(network);
+        }
+        if let Some(rpc_url) = self.rpc_url {
+            builder = builder.rpc_url(rpc_url);
+        }
+        for rpc_url in self.fallback_rpc_urls {
+            builder = builder.with_fallback_rpc_url(rpc_url);
+        }
+        if let Some(failover_threshold) = self.failover_threshold {
+            builder = builder.failover_threshold(failover_threshold);
+        }
+        if let Some(recovery_interval_ms) = self.failover_recovery_interval_ms {
+            builder =
+                builder.failover_recovery_interval(Duration::from_millis(recovery_interval_ms));
+        }
+        if let Some(circuit_breaker) = self.circuit_breaker {
+            builder = builder.circuit_breaker(circuit_breaker.into());
+        }
+        if let Some(load_balancing) = self.load_balancing {
+            builder = builder.load_balancing(load_balancing);
+        }
+        if let Some(interval_ms) = self.interval_ms {
+            builder = builder.interval(Duration::from_millis(interval_ms));
+        }
+        if let Some(adaptive_interval) = self.adaptive_interval {
+            builder = builder.adaptive_interval_with(adaptive_interval.into());
+        }
+        if let Some(max_transactions) = self.max_transactions {
+            builder = builder.max_transactions(max_transactions);
+        }
+        if let Some(start_checkpoint) = self.start_checkpoint {
+            builder = builder.with_start_checkpoint(start_checkpoint);
+        }
+        if let Some(start_digest) = self.start_digest {
+            builder = builder.with_start_digest(start_digest);
+        }
+        if let Some(cursor_file) = self.cursor_file {
+            builder = builder.cursor_file(cursor_file);
+        }
+        if let Some(dedup_window) = self.dedup_window {
+            builder = builder.dedup_window(dedup_window);
+        }
+        if let Some(prefetch_depth) = self.prefetch_depth {
+            builder = builder.prefetch_depth(prefetch_depth);
+        }
+        if let Some(hydration_concurrency) = self.hydration_concurrency {
+            builder = builder.hydration_concurrency(hydration_concurrency);
+        }
+        if let Some(interval_ms) = self.lag_check_interval_ms {
+            builder = builder.lag_check_interval(Duration::from_millis(interval_ms));
+        }
+        if let Some(gap_policy) = self.gap_policy {
+            builder = builder.gap_policy(gap_policy);
+        }
+        if let Some(order) = self.order {
+            builder = builder.order(order);
+        }
+        if let Some(shard) = self.shard {
+            builder = builder.shard(shard.index, shard.count);
+        }
+        if let Some(mode) = self.conversion_mode {
+            builder = builder.conversion_mode(mode);
+        }
+        if let Some(include_failed) = self.include_failed {
+            builder = builder.include_failed(include_failed);
+        }
+        if let Some(skip) = self.skip_system_transactions {
+            builder = builder.skip_system_transactions(skip);
+        }
+        if let Some(zklogin_only) = self.zklogin_only {
+            builder = builder.zklogin_only(zklogin_only);
+        }
+        if let Some(coin_metadata) = self.coin_metadata {
+            builder = builder.coin_metadata(coin_metadata);
+        }
+        if let Some(path) = self.address_labels {
+            builder = builder.address_labeler(StaticAddressLabeler::from_file(path)?);
+        }
+        if let Some(include_bcs) = self.include_bcs {
+            builder = builder.include_bcs(include_bcs);
+        }
+        if let Some(include_raw_json) = self.include_raw_json {
+            builder = builder.include_raw_json(include_raw_json);
+        }
+        if let Some(watermarks) = self.watermarks {
+            builder = builder.watermarks(watermarks);
+        }
+        if let Some(timeout_ms) = self.request_timeout_ms {
+            builder = builder.request_timeout(Duration::from_millis(timeout_ms));
+        }
+        if let Some(timeout_ms) = self.next_timeout_ms {
+            builder = builder.next_timeout(Duration::from_millis(timeout_ms));
+        }
+        if let Some(retry) = self.retry {
+            builder = builder.retry_policy(retry.into());
+        }
+        if let Some(rate_limit) = self.rate_limit {
+            builder = builder.rate_limit(
+                rate_limit.requests_per_second,
+                rate_limit.burst.unwrap_or(rate_limit.requests_per_second),
+            );
+        }
+        for (name, value) in self.headers {
+            builder = builder.with_header(name, value);
+        }
+        if let Some(token) = self.bearer_token {
+            builder = builder.with_bearer_token(token);
+        }
+
+        if let Some(options) = self.query_options {
+            if let Some(show) = options.input {
+                builder = builder.show_input(show);
+            }
+            if let Some(show) = options.raw_input {
+                builder = builder.show_raw_input(show);
+            }
+            if let Some(show) = options.effects {
+                builder = builder.show_effects(show);
+            }
+            if let Some(show) = options.raw_effects {
+                builder = builder.show_raw_effects(show);
+            }
+            if let Some(show) = options.events {
+                builder = builder.show_events(show);
+            }
+            if let Some(show) = options.object_changes {
+                builder = builder.show_object_changes(show);
+            }
+            if let Some(show) = options.balance_changes {
+                builder = builder.show_balance_changes(show);
+            }
+        }
+
+        let filter = self.filter;
+        if let Some(address) = filter.from_address {
+            builder = builder.with_from_address(address);
+        }
+        if let Some(address) = filter.to_address {
+            builder = builder.with_to_address(address);
+        }
+        if let Some(move_function) = filter.move_function {
+            let (package, module, function) = parse_move_function(&move_function)?;
+            builder = builder.with_move_function(package, module, function);
+        }
+        if let Some((start, end)) = filter.checkpoint_range {
+            builder = builder.with_checkpoint_range(start, end);
+        }
+        if let Some((from, to)) = filter.time_range {
+            builder = builder.with_time_range(from, to);
+        }
+
+        Ok(builder)
+    }
+}
+
+impl SuiSource {
+    /// Creates a SuiSource from a configuration
+    pub fn from_config(config: SuiSourceConfig) -> StreamResult<Self> {
+        config.into_builder()?.build()
+    }
+
+    /// Creates a SuiSource from a TOML or YAML configuration file
+    #[cfg(feature = "config")]
+    pub fn from_config_file(path: impl AsRef<Path>) -> StreamResult<Self> {
+        Self::from_config(SuiSourceConfig::from_file(path)?)
+    }
+}
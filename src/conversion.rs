@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// How a [`crate::SuiSource`] treats transactions with fields that cannot be extracted
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversionMode {
+    /// Fill missing fields with `"unknown"` or zero and emit the event
+    #[default]
+    Lenient,
+    /// Reject transactions whose sender, timestamp or type cannot be extracted
+    ///
+    /// Rejected transactions go to the dead-letter sink if one is configured,
+    /// otherwise `next()` returns a serialization error.
+    Strict,
+}
@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use fluxus::utils::models::{StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::path::PathBuf;
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
+
+/// Progress of a [`crate::SuiSource`], pointing at the last emitted transaction
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuiCursor {
+    /// Digest of the last emitted transaction
+    pub digest: TransactionDigest,
+    /// Checkpoint of the last emitted transaction
+    pub checkpoint: Option<CheckpointSequenceNumber>,
+}
+
+/// Persistent storage for the progress of a [`crate::SuiSource`]
+///
+/// The cursor is loaded on `init` so a restarted source resumes right after
+/// the last emitted transaction, and saved whenever the emitted transactions
+/// have been handed downstream as well as on `close`.
+#[async_trait]
+pub trait CursorStore: Debug + Send + Sync {
+    /// Loads the saved cursor, `None` if nothing has been saved yet
+    async fn load(&self) -> StreamResult<Option<SuiCursor>>;
+
+    /// Saves the cursor, replacing the previous one
+    async fn save(&self, cursor: &SuiCursor) -> StreamResult<()>;
+}
+
+/// Cursor store keeping the cursor in a JSON file
+#[derive(Clone, Debug)]
+pub struct FileCursorStore {
+    /// Path of the JSON file
+    path: PathBuf,
+}
+
+impl FileCursorStore {
+    /// Creates a new FileCursorStore instance
+    ///
+    /// # Parameters
+    /// * `path` - Path of the JSON file, created on the first save
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl CursorStore for FileCursorStore {
+    async fn load(&self) -> StreamResult<Option<SuiCursor>> {
+        let contents = match tokio::fs::read(&self.path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(StreamError::Runtime(format!(
+                    "Failed to read cursor file {}: {}",
+                    self.path.display(),
+                    e
+                )));
+            }
+        };
+
+        serde_json::from_slice(&contents)
+            .map(Some)
+            .map_err(|e| StreamError::Serialization(e.to_string()))
+    }
+
+    async fn save(&self, cursor: &SuiCursor) -> StreamResult<()> {
+        let contents =
+            serde_json::to_vec(cursor).map_err(|e| StreamError::Serialization(e.to_string()))?;
+
+        // Write to a temporary file first so a crash never leaves a truncated cursor behind
+        let temp_path = self.path.with_extension("tmp");
+        tokio::fs::write(&temp_path, contents).await.map_err(|e| {
+            StreamError::Runtime(format!(
+                "Failed to write cursor file {}: {}",
+                temp_path.display(),
+                e
+            ))
+        })?;
+        tokio::fs::rename(&temp_path, &self.path)
+            .await
+            .map_err(|e| {
+                StreamError::Runtime(format!(
+                    "Failed to write cursor file {}: {}",
+                    self.path.display(),
+                    e
+                ))
+            })
+    }
+}
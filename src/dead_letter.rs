@@ -0,0 +1,47 @@
+use std::fmt;
+use sui_sdk::rpc_types::SuiTransactionBlockResponse;
+use tokio::sync::mpsc;
+
+/// Transaction that could not be converted into a record
+#[derive(Clone, Debug)]
+pub struct SuiDeadLetter {
+    /// Raw transaction as returned by the fullnode
+    pub transaction: SuiTransactionBlockResponse,
+    /// Reason the conversion failed
+    pub error: String,
+}
+
+/// Destination of transactions that could not be converted into records
+///
+/// The source skips dead-lettered transactions and continues with the next one.
+pub trait DeadLetterSink: fmt::Debug + Send + Sync {
+    /// Handles a transaction that could not be converted
+    fn send(&self, dead_letter: SuiDeadLetter);
+}
+
+/// Forwards dead letters to a channel, dropping them once the receiver is gone
+impl DeadLetterSink for mpsc::UnboundedSender<SuiDeadLetter> {
+    fn send(&self, dead_letter: SuiDeadLetter) {
+        if mpsc::UnboundedSender::send(self, dead_letter).is_err() {
+            tracing::warn!("Dead-letter receiver dropped, discarding dead letter");
+        }
+    }
+}
+
+/// Dead-letter sink calling a closure
+pub struct DeadLetterFn<F>(pub F);
+
+impl<F> fmt::Debug for DeadLetterFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DeadLetterFn")
+    }
+}
+
+impl<F> DeadLetterSink for DeadLetterFn<F>
+where
+    F: Fn(SuiDeadLetter) + Send + Sync,
+{
+    fn send(&self, dead_letter: SuiDeadLetter) {
+        (self.0)(dead_letter)
+    }
+}
@@ -0,0 +1,175 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::str::FromStr;
+use sui_sdk::rpc_types::EventFilter;
+use sui_sdk::types::base_types::ObjectID;
+
+use crate::{SuiEventSource, SuiMoveEvent};
+
+/// DeepBook v3 package on Sui Mainnet
+const DEEPBOOK_MAINNET_PACKAGE: &str =
+    "0x2c8d603bc51326b8c13cef9dd07031a408a48dddb541963357661df5d3204809";
+
+/// Kind of order book activity
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DeepBookAction {
+    /// Order was placed on the book
+    OrderPlaced,
+    /// Order was (partially) filled
+    OrderFilled,
+    /// Order was canceled
+    OrderCanceled,
+    /// Pool was created
+    PoolCreated,
+}
+
+/// Side of an order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OrderSide {
+    /// Buy order
+    Bid,
+    /// Sell order
+    Ask,
+}
+
+impl From<bool> for OrderSide {
+    fn from(is_bid: bool) -> Self {
+        if is_bid { Self::Bid } else { Self::Ask }
+    }
+}
+
+/// Decoded DeepBook pool event
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiDeepBookEvent {
+    /// Digest of the emitting transaction
+    pub transaction_digest: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Kind of activity
+    pub action: DeepBookAction,
+    /// Pool ID
+    pub pool_id: String,
+    /// Order ID, the maker order for fills (not set for pool creation)
+    pub order_id: Option<String>,
+    /// Order price (not set for pool creation)
+    pub price: Option<u64>,
+    /// Base asset quantity placed, filled or canceled (not set for pool creation)
+    pub quantity: Option<u64>,
+    /// Order side, the taker side for fills (not set for pool creation)
+    pub side: Option<OrderSide>,
+    /// Trader balance manager owner (if present)
+    pub trader: Option<String>,
+}
+
+impl SuiDeepBookEvent {
+    /// Decodes an order book event of the DeepBook package, `None` for other events
+    pub fn decode(event: &SuiMoveEvent) -> Option<Self> {
+        let (action, order_id, quantity, side) = match event.event_name() {
+            "OrderPlaced" => (
+                DeepBookAction::OrderPlaced,
+                event.field_str("order_id"),
+                event.field_u64("placed_quantity"),
+                event.field_bool("is_bid"),
+            ),
+            "OrderFilled" => (
+                DeepBookAction::OrderFilled,
+                event.field_str("maker_order_id"),
+                event.field_u64("base_quantity"),
+                event.field_bool("taker_is_bid"),
+            ),
+            "OrderCanceled" => (
+                DeepBookAction::OrderCanceled,
+                event.field_str("order_id"),
+                event.field_u64("base_asset_quantity_canceled"),
+                event.field_bool("is_bid"),
+            ),
+            "PoolCreated" => (DeepBookAction::PoolCreated, None, None, None),
+            _ => return None,
+        };
+
+        Some(Self {
+            transaction_digest: event.transaction_digest.clone(),
+            timestamp: event.timestamp,
+            action,
+            pool_id: event.field_str("pool_id")?,
+            order_id,
+            price: event.field_u64("price"),
+            quantity,
+            side: side.map(OrderSide::from),
+            trader: event.field_str("trader"),
+        })
+    }
+}
+
+/// Sui data source emitting decoded DeepBook order book events
+pub struct SuiDeepBookSource {
+    /// Wrapped event source scoped to the DeepBook package
+    source: SuiEventSource,
+    /// Pools to emit events for, all pools when empty
+    pools: HashSet<String>,
+}
+
+impl SuiDeepBookSource {
+    /// Creates a new SuiDeepBookSource for the DeepBook package on Mainnet
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        let package =
+            ObjectID::from_str(DEEPBOOK_MAINNET_PACKAGE).expect("DeepBook package ID is valid");
+        Self::with_package(rpc_url, package, interval_ms)
+    }
+
+    /// Creates a new SuiDeepBookSource for a specific DeepBook package, e.g. on Testnet
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `package` - DeepBook package ID
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn with_package(rpc_url: String, package: ObjectID, interval_ms: u64) -> Self {
+        Self {
+            source: SuiEventSource::new(rpc_url, EventFilter::Package(package), interval_ms, 50),
+            pools: HashSet::new(),
+        }
+    }
+
+    /// Only emits events of the given pools
+    pub fn with_pools(mut self, pools: impl IntoIterator<Item = ObjectID>) -> Self {
+        self.pools = pools.into_iter().map(|pool| pool.to_string()).collect();
+        self
+    }
+}
+
+#[async_trait]
+impl Source<SuiDeepBookEvent> for SuiDeepBookSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiDeepBookEvent>>> {
+        // Skip events of other pools and non order book events
+        while let Some(event) = self.source.next_event().await? {
+            let Some(deepbook_event) = SuiDeepBookEvent::decode(&event) else {
+                continue;
+            };
+            if self.pools.is_empty() || self.pools.contains(&deepbook_event.pool_id) {
+                return Ok(Some(Record::new(deepbook_event)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.source.close().await
+    }
+}
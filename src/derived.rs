@@ -0,0 +1,108 @@
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use std::collections::VecDeque;
+use sui_sdk::rpc_types::SuiTransactionBlockResponse;
+use sui_sdk::types::digests::TransactionDigest;
+
+use crate::SuiSource;
+
+/// Records derived from a transaction of a wrapped [`SuiSource`], waiting to be emitted
+///
+/// The transaction is handed back to the wrapped source on close while some
+/// of its records are still buffered, so it is fetched again instead of
+/// being skipped, and the records emitted before are not repeated.
+pub(crate) struct DerivedBuffer<T> {
+    /// Records waiting to be emitted
+    records: VecDeque<T>,
+    /// Transaction the buffered records were derived from
+    transaction: Option<SuiTransactionBlockResponse>,
+    /// Number of records of the transaction emitted so far
+    emitted: usize,
+    /// Transaction handed back on close and the number of its records emitted before
+    resume: Option<(TransactionDigest, usize)>,
+}
+
+impl<T> DerivedBuffer<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            records: VecDeque::new(),
+            transaction: None,
+            emitted: 0,
+            resume: None,
+        }
+    }
+
+    /// Whether records are waiting to be emitted
+    pub(crate) fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Buffers the records derived from a transaction returned by the wrapped source
+    pub(crate) fn fill(
+        &mut self,
+        transaction: SuiTransactionBlockResponse,
+        records: impl IntoIterator<Item = T>,
+    ) {
+        // Records emitted before the transaction was handed back are skipped
+        let emitted = match self.resume.take() {
+            Some((digest, emitted)) if digest == transaction.digest => emitted,
+            _ => 0,
+        };
+        self.records = records.into_iter().skip(emitted).collect();
+        self.emitted = emitted;
+        self.transaction = (!self.records.is_empty()).then_some(transaction);
+    }
+
+    /// Takes the next record
+    pub(crate) fn pop(&mut self) -> Option<T> {
+        let record = self.records.pop_front()?;
+        self.emitted += 1;
+        if self.records.is_empty() {
+            self.transaction = None;
+        }
+        Some(record)
+    }
+
+    /// Hands a partially emitted transaction back to the wrapped source,
+    /// call before closing it
+    pub(crate) fn close(&mut self, source: &mut SuiSource) {
+        if let Some(transaction) = self.transaction.take() {
+            tracing::info!(
+                "Discarding {} buffered records of transaction {}, call drain() before close() to emit them",
+                self.records.len(),
+                transaction.digest
+            );
+            self.resume = Some((transaction.digest, self.emitted));
+            source.requeue(transaction);
+        }
+        self.records.clear();
+    }
+}
+
+/// Source wrapping a [`SuiSource`] that buffers records on top of it
+pub(crate) trait WrappingSource<T>: Source<T> {
+    /// Wrapped source
+    fn inner(&mut self) -> &mut SuiSource;
+
+    /// Whether records of this source are waiting to be emitted
+    fn has_buffered(&self) -> bool;
+}
+
+/// Emits the records of the transactions fetched but not emitted yet,
+/// without polling for more, see [`SuiSource::drain`]
+pub(crate) async fn drain<T, S: WrappingSource<T>>(source: &mut S) -> StreamResult<Vec<Record<T>>> {
+    source.inner().draining = true;
+    let mut records = Vec::new();
+    let result = loop {
+        match source.next().await {
+            Ok(Some(record)) => records.push(record),
+            Ok(None) if !source.has_buffered() && !source.inner().has_buffered() => {
+                break Ok(records);
+            }
+            Ok(None) => {}
+            Err(e) => break Err(e),
+        }
+    };
+    source.inner().draining = false;
+    result
+}
@@ -0,0 +1,245 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::DynamicFieldInfo;
+use sui_sdk::types::base_types::ObjectID;
+use tokio::time::sleep;
+
+use crate::retry::{connect_client, now_millis};
+
+/// Maximum number of dynamic fields requested per page
+const PAGE_SIZE: usize = 50;
+
+/// Kind of dynamic field change
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DynamicFieldAction {
+    /// Field was added to the parent object
+    Added,
+    /// Value of the field was modified
+    Updated,
+    /// Field was removed from the parent object
+    Removed,
+}
+
+/// Change of a dynamic field of a watched parent object
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiDynamicFieldChange {
+    /// Parent object ID
+    pub parent_id: String,
+    /// Kind of change
+    pub action: DynamicFieldAction,
+    /// Move type of the field name
+    pub name_type: String,
+    /// Field name as JSON
+    pub name: serde_json::Value,
+    /// ID of the field object
+    pub object_id: String,
+    /// Move type of the field object
+    pub object_type: String,
+    /// Version of the field object, the last known version for removals
+    pub version: u64,
+    /// Version before the change, not set for additions
+    pub previous_version: Option<u64>,
+    /// Time the change was observed
+    pub timestamp: u64,
+}
+
+/// Sui data source tracking the dynamic fields of a parent object
+///
+/// Useful for following on-chain tables, bags and registries. The first poll
+/// records the current fields as a baseline. Every later poll emits a record
+/// for each field that was added, updated or removed.
+pub struct SuiDynamicFieldSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Parent object whose fields are tracked
+    parent_id: ObjectID,
+    /// Polling interval
+    interval: Duration,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Fields of the last snapshot by field object ID
+    fields: Option<HashMap<ObjectID, DynamicFieldInfo>>,
+    /// Detected changes waiting to be emitted
+    buffer: VecDeque<SuiDynamicFieldChange>,
+}
+
+impl SuiDynamicFieldSource {
+    /// Creates a new SuiDynamicFieldSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `parent_id` - Parent object whose dynamic fields are tracked
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, parent_id: ObjectID, interval_ms: u64) -> Self {
+        Self {
+            rpc_url,
+            parent_id,
+            interval: Duration::from_millis(interval_ms),
+            client: None,
+            fields: None,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Fetches all dynamic fields of the parent object, following pagination
+    async fn fetch_fields(&self) -> StreamResult<HashMap<ObjectID, DynamicFieldInfo>> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiDynamicFieldSource client not available".to_string())
+        })?;
+
+        let mut fields = HashMap::new();
+        let mut cursor = None;
+        loop {
+            let page = client
+                .read_api()
+                .get_dynamic_fields(self.parent_id, cursor, Some(PAGE_SIZE))
+                .await
+                .map_err(|e| {
+                    tracing::error!(
+                        "Failed to fetch dynamic fields of {}: {}",
+                        self.parent_id,
+                        e
+                    );
+                    StreamError::Runtime(e.to_string())
+                })?;
+
+            fields.extend(page.data.into_iter().map(|field| (field.object_id, field)));
+
+            if !page.has_next_page || page.next_cursor.is_none() {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        Ok(fields)
+    }
+
+    /// Builds a change record for a field
+    fn change(
+        &self,
+        action: DynamicFieldAction,
+        field: &DynamicFieldInfo,
+        previous_version: Option<u64>,
+        timestamp: u64,
+    ) -> SuiDynamicFieldChange {
+        SuiDynamicFieldChange {
+            parent_id: self.parent_id.to_string(),
+            action,
+            name_type: field.name.type_.to_string(),
+            name: field.name.value.clone(),
+            object_id: field.object_id.to_string(),
+            object_type: field.object_type.clone(),
+            version: field.version.value(),
+            previous_version,
+            timestamp,
+        }
+    }
+
+    /// Takes a new snapshot and buffers the differences to the previous one
+    async fn poll(&mut self) -> StreamResult<()> {
+        let current = self.fetch_fields().await?;
+
+        // The first snapshot is the baseline, not a change
+        let Some(previous) = self.fields.take() else {
+            self.fields = Some(current);
+            return Ok(());
+        };
+
+        let timestamp = now_millis();
+
+        for (object_id, field) in &current {
+            match previous.get(object_id) {
+                None => {
+                    let change = self.change(DynamicFieldAction::Added, field, None, timestamp);
+                    self.buffer.push_back(change);
+                }
+                Some(old) if old.version != field.version => {
+                    let change = self.change(
+                        DynamicFieldAction::Updated,
+                        field,
+                        Some(old.version.value()),
+                        timestamp,
+                    );
+                    self.buffer.push_back(change);
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (object_id, field) in &previous {
+            if !current.contains_key(object_id) {
+                let change = self.change(
+                    DynamicFieldAction::Removed,
+                    field,
+                    Some(field.version.value()),
+                    timestamp,
+                );
+                self.buffer.push_back(change);
+            }
+        }
+
+        self.fields = Some(current);
+        Ok(())
+    }
+
+    /// Emits the field changes detected but not emitted yet, without taking a new snapshot
+    ///
+    /// Call before `close` to flush in-flight records on shutdown.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiDynamicFieldChange>>> {
+        Ok(self.buffer.drain(..).map(Record::new).collect())
+    }
+}
+
+#[async_trait]
+impl Source<SuiDynamicFieldChange> for SuiDynamicFieldSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_client(&self.rpc_url).await?;
+
+        self.client = Some(client);
+        tracing::info!(
+            "SuiDynamicFieldSource initialized with RPC URL: {} tracking {}",
+            self.rpc_url,
+            self.parent_id
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiDynamicFieldChange>>> {
+        if self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiDynamicFieldSource not initialized".to_string(),
+            ));
+        }
+
+        if self.buffer.is_empty() {
+            // Take the baseline right away
+            if self.fields.is_some() {
+                sleep(self.interval).await;
+            }
+            self.poll().await?;
+        }
+
+        Ok(self.buffer.pop_front().map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        // Buffered changes are kept, the snapshot they were diffed
+        // against already includes them
+        self.client = None;
+        tracing::info!("SuiDynamicFieldSource closed");
+        Ok(())
+    }
+}
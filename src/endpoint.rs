@@ -0,0 +1,287 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+use sui_sdk::SuiClient;
+
+use crate::circuit_breaker::Circuit;
+use crate::{CircuitBreakerPolicy, CircuitState};
+
+/// Strategy for spreading requests across the RPC endpoints of a [`crate::SuiSource`]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadBalancing {
+    /// Send every request to the primary endpoint, using fallbacks only while it fails
+    #[default]
+    Failover,
+    /// Rotate through the healthy endpoints
+    RoundRobin,
+    /// Prefer the healthy endpoint with the lowest average response time
+    LowestLatency,
+    /// Pick healthy endpoints at random, proportionally to their weight
+    ///
+    /// Weights are given in endpoint order, the primary first.
+    Weighted(Vec<u32>),
+}
+
+/// Health of an RPC endpoint of a [`crate::SuiSource`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EndpointHealth {
+    /// Endpoint URL
+    pub url: String,
+    /// Whether requests are currently sent to this endpoint
+    pub active: bool,
+    /// Whether the endpoint is below the failover threshold
+    pub healthy: bool,
+    /// Average response time in milliseconds, not set until a request succeeded
+    pub latency_ms: Option<u64>,
+    /// Number of failed requests since the last successful one
+    pub consecutive_failures: u32,
+    /// Number of failed requests overall
+    pub total_failures: u64,
+    /// State of the circuit breaker, always closed if none is configured
+    pub circuit: CircuitState,
+}
+
+/// RPC endpoint with its client and failure statistics
+struct Endpoint {
+    /// Endpoint URL
+    url: String,
+    /// Client, built on first use
+    client: Option<SuiClient>,
+    /// Number of failed requests since the last successful one
+    consecutive_failures: u32,
+    /// Number of failed requests overall
+    total_failures: u64,
+    /// Time of the last failed request
+    last_failure: Option<Instant>,
+    /// Exponentially weighted average response time
+    latency: Option<Duration>,
+    /// Circuit breaker state
+    circuit: Circuit,
+}
+
+/// Primary RPC endpoint with optional fallbacks
+///
+/// With [`LoadBalancing::Failover`] requests go to the active endpoint. Once
+/// it fails `failover_threshold` times in a row the source moves on to the
+/// next endpoint, and returns to the primary once it has not failed for
+/// `recovery_interval`. The other strategies pick an endpoint per request
+/// among those below the failover threshold. Endpoints whose circuit breaker
+/// is open are skipped by all strategies.
+pub(crate) struct EndpointPool {
+    /// Endpoints, the primary first
+    endpoints: Vec<Endpoint>,
+    /// Index of the active endpoint
+    active: usize,
+    /// Consecutive failures after which the active endpoint is abandoned
+    pub(crate) failover_threshold: u32,
+    /// Time after its last failure before the primary endpoint is retried
+    pub(crate) recovery_interval: Duration,
+    /// Strategy for picking the endpoint of a request
+    pub(crate) strategy: LoadBalancing,
+    /// Circuit breaker applied to every endpoint, if any
+    pub(crate) circuit_breaker: Option<CircuitBreakerPolicy>,
+    /// Next endpoint in round-robin order
+    next_index: usize,
+}
+
+impl EndpointPool {
+    /// Creates a pool from a primary endpoint and its fallbacks
+    pub(crate) fn new(primary: String, fallbacks: Vec<String>) -> Self {
+        let endpoints = std::iter::once(primary)
+            .chain(fallbacks)
+            .map(|url| Endpoint {
+                url,
+                client: None,
+                consecutive_failures: 0,
+                total_failures: 0,
+                last_failure: None,
+                latency: None,
+                circuit: Circuit::default(),
+            })
+            .collect();
+
+        Self {
+            endpoints,
+            active: 0,
+            failover_threshold: 3,
+            recovery_interval: Duration::from_secs(60),
+            strategy: LoadBalancing::Failover,
+            circuit_breaker: None,
+            next_index: 0,
+        }
+    }
+
+    /// Number of endpoints
+    pub(crate) fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    /// URL of an endpoint
+    pub(crate) fn url(&self, index: usize) -> &str {
+        &self.endpoints[index].url
+    }
+
+    /// Index of the active endpoint
+    pub(crate) fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Client of the active endpoint, if connected
+    pub(crate) fn client(&self) -> Option<&SuiClient> {
+        self.endpoints[self.active].client.as_ref()
+    }
+
+    /// Stores a connected client and makes its endpoint the active one
+    pub(crate) fn activate(&mut self, index: usize, client: SuiClient) {
+        if index != self.active && self.strategy == LoadBalancing::Failover {
+            tracing::warn!(
+                "Switching RPC endpoint from {} to {}",
+                self.endpoints[self.active].url,
+                self.endpoints[index].url
+            );
+        }
+        self.endpoints[index].client = Some(client);
+        self.active = index;
+    }
+
+    /// Whether requests are spread across endpoints rather than failing over
+    pub(crate) fn is_balanced(&self) -> bool {
+        self.strategy != LoadBalancing::Failover && self.endpoints.len() > 1
+    }
+
+    /// Picks the endpoint of the next request, skipping the endpoints already tried
+    ///
+    /// Unhealthy endpoints are only picked once no healthy endpoint is left.
+    /// At least one endpoint must not have been tried yet.
+    pub(crate) fn select(&mut self, tried: &[usize]) -> usize {
+        let untried: Vec<usize> = (0..self.endpoints.len())
+            .filter(|index| !tried.contains(index))
+            .collect();
+        let healthy: Vec<usize> = untried
+            .iter()
+            .copied()
+            .filter(|&index| {
+                self.endpoints[index].consecutive_failures < self.failover_threshold
+                    && self.is_available(index)
+            })
+            .collect();
+        let candidates = if healthy.is_empty() { untried } else { healthy };
+
+        match &self.strategy {
+            LoadBalancing::Failover => candidates[0],
+            LoadBalancing::RoundRobin => {
+                let index = candidates
+                    .iter()
+                    .copied()
+                    .find(|&index| index >= self.next_index)
+                    .unwrap_or(candidates[0]);
+                self.next_index = index + 1;
+                index
+            }
+            // Endpoints without measurements are tried first so every endpoint gets measured
+            LoadBalancing::LowestLatency => candidates
+                .iter()
+                .copied()
+                .min_by_key(|&index| self.endpoints[index].latency.unwrap_or_default())
+                .unwrap_or(candidates[0]),
+            LoadBalancing::Weighted(weights) => {
+                let weight = |index: usize| weights.get(index).copied().unwrap_or(0) as u64;
+                let total: u64 = candidates.iter().map(|&index| weight(index)).sum();
+                if total == 0 {
+                    return candidates[0];
+                }
+
+                let mut pick = rand::thread_rng().gen_range(0..total);
+                for &index in &candidates {
+                    if pick < weight(index) {
+                        return index;
+                    }
+                    pick -= weight(index);
+                }
+                candidates[0]
+            }
+        }
+    }
+
+    /// Client of an endpoint, if connected
+    pub(crate) fn client_at(&self, index: usize) -> Option<SuiClient> {
+        self.endpoints[index].client.clone()
+    }
+
+    /// Drops all clients
+    pub(crate) fn disconnect(&mut self) {
+        for endpoint in &mut self.endpoints {
+            endpoint.client = None;
+        }
+        self.active = 0;
+    }
+
+    /// Records a successful request to the active endpoint and its response time
+    pub(crate) fn record_success(&mut self, latency: Duration) {
+        let endpoint = &mut self.endpoints[self.active];
+        endpoint.consecutive_failures = 0;
+        endpoint.circuit.record_success();
+        endpoint.latency = Some(match endpoint.latency {
+            Some(average) => average.mul_f64(0.8) + latency.mul_f64(0.2),
+            None => latency,
+        });
+    }
+
+    /// Records a failed request to an endpoint, `true` once it crossed the failover threshold
+    pub(crate) fn record_failure(&mut self, index: usize) -> bool {
+        let endpoint = &mut self.endpoints[index];
+        endpoint.consecutive_failures += 1;
+        endpoint.total_failures += 1;
+        endpoint.last_failure = Some(Instant::now());
+        if let Some(policy) = &self.circuit_breaker {
+            endpoint
+                .circuit
+                .record_failure(endpoint.consecutive_failures, policy);
+        }
+        endpoint.consecutive_failures >= self.failover_threshold
+    }
+
+    /// Whether the circuit breaker lets requests through to an endpoint
+    pub(crate) fn is_available(&self, index: usize) -> bool {
+        self.circuit_breaker.is_none()
+            || self.endpoints[index].circuit.state() != CircuitState::Open
+    }
+
+    /// Endpoints to fail over to, in order, starting after the active one
+    pub(crate) fn failover_candidates(&self) -> Vec<usize> {
+        (1..self.endpoints.len())
+            .map(|offset| (self.active + offset) % self.endpoints.len())
+            .filter(|&index| self.is_available(index))
+            .collect()
+    }
+
+    /// Whether the source runs on a fallback and the primary is due for another try
+    pub(crate) fn should_recover(&self) -> bool {
+        self.active != 0
+            && self.is_available(0)
+            && self.endpoints[0]
+                .last_failure
+                .is_none_or(|last_failure| last_failure.elapsed() >= self.recovery_interval)
+    }
+
+    /// Health of every endpoint
+    pub(crate) fn health(&self) -> Vec<EndpointHealth> {
+        self.endpoints
+            .iter()
+            .enumerate()
+            .map(|(index, endpoint)| EndpointHealth {
+                url: endpoint.url.clone(),
+                active: index == self.active,
+                healthy: endpoint.consecutive_failures < self.failover_threshold,
+                latency_ms: endpoint.latency.map(|latency| latency.as_millis() as u64),
+                consecutive_failures: endpoint.consecutive_failures,
+                total_failures: endpoint.total_failures,
+                circuit: match self.circuit_breaker {
+                    Some(_) => endpoint.circuit.state(),
+                    None => CircuitState::Closed,
+                },
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary;
+use tokio::time::sleep;
+
+use crate::retry::connect_client;
+
+/// Sui epoch transition
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiEpoch {
+    /// Epoch number
+    pub epoch: u64,
+    /// Epoch start timestamp
+    pub start_timestamp: u64,
+    /// Configured epoch duration in milliseconds
+    pub duration_ms: u64,
+    /// Reference gas price of the epoch
+    pub reference_gas_price: u64,
+    /// Number of active validators
+    pub validator_count: usize,
+    /// Protocol version of the epoch
+    pub protocol_version: u64,
+    /// Total stake at the start of the epoch
+    pub total_stake: u64,
+}
+
+impl From<&SuiSystemStateSummary> for SuiEpoch {
+    fn from(state: &SuiSystemStateSummary) -> Self {
+        Self {
+            epoch: state.epoch,
+            start_timestamp: state.epoch_start_timestamp_ms,
+            duration_ms: state.epoch_duration_ms,
+            reference_gas_price: state.reference_gas_price,
+            validator_count: state.active_validators.len(),
+            protocol_version: state.protocol_version,
+            total_stake: state.total_stake,
+        }
+    }
+}
+
+/// Sui data source emitting a record at each epoch change
+///
+/// The epoch current at startup is emitted first, followed by every later
+/// epoch as soon as it begins.
+pub struct SuiEpochSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval
+    interval: Duration,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Last emitted epoch
+    last_epoch: Option<u64>,
+}
+
+impl SuiEpochSource {
+    /// Creates a new SuiEpochSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            client: None,
+            last_epoch: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Source<SuiEpoch> for SuiEpochSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_client(&self.rpc_url).await?;
+
+        self.client = Some(client);
+        tracing::info!("SuiEpochSource initialized with RPC URL: {}", self.rpc_url);
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiEpoch>>> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| StreamError::Runtime("SuiEpochSource not initialized".to_string()))?;
+
+        // Emit the current epoch right away, then wait for the next one
+        if self.last_epoch.is_some() {
+            sleep(self.interval).await;
+        }
+
+        let state = client
+            .governance_api()
+            .get_latest_sui_system_state()
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch system state: {}", e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        if self.last_epoch == Some(state.epoch) {
+            return Ok(None);
+        }
+
+        self.last_epoch = Some(state.epoch);
+        tracing::info!("Sui epoch changed to {}", state.epoch);
+
+        Ok(Some(Record::new(SuiEpoch::from(&state))))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.client = None;
+        tracing::info!("SuiEpochSource closed");
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::{EventFilter, SuiEvent as SuiRpcEvent};
+use sui_sdk::types::event::EventID;
+use tokio::time::sleep;
+
+use crate::retry::connect_client;
+
+/// Move event emitted by a Sui transaction
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiMoveEvent {
+    /// Digest of the emitting transaction
+    pub transaction_digest: String,
+    /// Sequence number of the event within its transaction
+    pub event_seq: u64,
+    /// Package that emitted the event
+    pub package_id: String,
+    /// Module that emitted the event
+    pub module: String,
+    /// Fully qualified Move struct type of the event
+    pub event_type: String,
+    /// Sender of the emitting transaction
+    pub sender: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Event fields decoded to JSON
+    pub parsed_json: serde_json::Value,
+}
+
+impl SuiMoveEvent {
+    /// Name of the event struct without its module path and type parameters
+    pub fn event_name(&self) -> &str {
+        let without_params = self.event_type.split('<').next().unwrap_or_default();
+        without_params.rsplit("::").next().unwrap_or(without_params)
+    }
+
+    /// First type parameter of the event struct, e.g. the item type of kiosk events
+    pub fn type_param(&self) -> Option<&str> {
+        let start = self.event_type.find('<')?;
+        let end = self.event_type.rfind('>')?;
+        let params = self.event_type.get(start + 1..end)?;
+
+        // Split on the first top-level comma only
+        let mut depth = 0;
+        for (i, c) in params.char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                ',' if depth == 0 => return Some(params[..i].trim()),
+                _ => {}
+            }
+        }
+        Some(params.trim())
+    }
+
+    /// Reads a string field of the parsed event
+    pub(crate) fn field_str(&self, field: &str) -> Option<String> {
+        self.parsed_json
+            .get(field)
+            .and_then(|value| value.as_str())
+            .map(str::to_string)
+    }
+
+    /// Reads a boolean field of the parsed event
+    pub(crate) fn field_bool(&self, field: &str) -> Option<bool> {
+        self.parsed_json
+            .get(field)
+            .and_then(|value| value.as_bool())
+    }
+
+    /// Reads an integer field of the parsed event, Move u64 and larger are encoded as strings
+    pub(crate) fn field_u64(&self, field: &str) -> Option<u64> {
+        let value = self.parsed_json.get(field)?;
+        value
+            .as_u64()
+            .or_else(|| value.as_str().and_then(|value| value.parse().ok()))
+    }
+}
+
+impl From<SuiRpcEvent> for SuiMoveEvent {
+    fn from(event: SuiRpcEvent) -> Self {
+        Self {
+            transaction_digest: event.id.tx_digest.to_string(),
+            event_seq: event.id.event_seq,
+            package_id: event.package_id.to_string(),
+            module: event.transaction_module.to_string(),
+            event_type: event.type_.to_canonical_string(true),
+            sender: event.sender.to_string(),
+            timestamp: event.timestamp_ms.unwrap_or(0),
+            parsed_json: event.parsed_json,
+        }
+    }
+}
+
+/// Move event emitted by a transaction, embedded in [`crate::SuiEvent`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct MoveEventSummary {
+    /// Fully qualified Move struct type of the event
+    pub event_type: String,
+    /// Package that emitted the event
+    pub package_id: String,
+    /// Module that emitted the event
+    pub module: String,
+    /// Event fields decoded to JSON, `null` if the contents could not be decoded
+    pub parsed_json: serde_json::Value,
+    /// BCS-encoded event contents, set when BCS passthrough is enabled
+    pub bcs: Option<Vec<u8>>,
+}
+
+impl From<&SuiRpcEvent> for MoveEventSummary {
+    fn from(event: &SuiRpcEvent) -> Self {
+        Self {
+            event_type: event.type_.to_canonical_string(true),
+            package_id: event.package_id.to_string(),
+            module: event.transaction_module.to_string(),
+            parsed_json: event.parsed_json.clone(),
+            bcs: None,
+        }
+    }
+}
+
+/// Sui data source streaming Move events matching an [`EventFilter`]
+///
+/// ```rust,no_run
+/// use fluxus_source_sui::SuiEventSource;
+/// use sui_sdk::rpc_types::EventFilter;
+/// use sui_sdk::types::base_types::SuiAddress;
+///
+/// let source = SuiEventSource::new(
+///     "https://fullnode.mainnet.sui.io:443".to_string(),
+///     EventFilter::Sender(SuiAddress::ZERO),
+///     500,
+///     10,
+/// );
+/// ```
+pub struct SuiEventSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Server-side event filter
+    filter: EventFilter,
+    /// Polling interval
+    interval: Duration,
+    /// Maximum number of events to fetch per poll
+    max_events: usize,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Fetched events waiting to be emitted
+    buffer: VecDeque<SuiRpcEvent>,
+    /// Query cursor pointing at the last fetched event
+    cursor: Option<EventID>,
+    /// Last emitted event
+    last_emitted: Option<EventID>,
+    /// Whether more events are available after the cursor
+    has_next_page: bool,
+}
+
+impl SuiEventSource {
+    /// Creates a new SuiEventSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `filter` - Event filter, e.g. by package, module, event type or sender
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `max_events` - Maximum number of events to fetch per poll
+    pub fn new(rpc_url: String, filter: EventFilter, interval_ms: u64, max_events: usize) -> Self {
+        Self {
+            rpc_url,
+            filter,
+            interval: Duration::from_millis(interval_ms),
+            max_events,
+            client: None,
+            buffer: VecDeque::new(),
+            cursor: None,
+            last_emitted: None,
+            has_next_page: false,
+        }
+    }
+
+    /// Fetches the next page of events and buffers them in chronological order
+    async fn fetch_batch(&mut self) -> StreamResult<()> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiEventSource client not available".to_string())
+        })?;
+
+        // Without a cursor start from the newest events, otherwise walk forward
+        let descending_order = self.cursor.is_none();
+        let page = client
+            .event_api()
+            .query_events(
+                self.filter.clone(),
+                self.cursor,
+                Some(self.max_events),
+                descending_order,
+            )
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch events: {}", e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        self.has_next_page = !descending_order && page.has_next_page;
+
+        let mut events = page.data;
+        if descending_order {
+            events.reverse();
+        }
+
+        let Some(latest_event) = events.last() else {
+            tracing::info!("No new events found");
+            return Ok(());
+        };
+        self.cursor = Some(latest_event.id);
+
+        tracing::debug!("Buffered {} new Sui events", events.len());
+        self.buffer.extend(events);
+
+        Ok(())
+    }
+
+    /// Returns the next event, polling the RPC once the buffer is drained
+    pub(crate) async fn next_event(&mut self) -> StreamResult<Option<SuiMoveEvent>> {
+        if self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiEventSource not initialized".to_string(),
+            ));
+        }
+
+        if self.buffer.is_empty() {
+            // Polling interval, skipped while catching up on pending pages
+            if !self.has_next_page {
+                sleep(self.interval).await;
+            }
+            self.fetch_batch().await?;
+        }
+
+        Ok(self.pop())
+    }
+
+    /// Takes the next buffered event
+    fn pop(&mut self) -> Option<SuiMoveEvent> {
+        let event = self.buffer.pop_front()?;
+        self.last_emitted = Some(event.id);
+        Some(SuiMoveEvent::from(event))
+    }
+
+    /// Emits the events fetched but not emitted yet, without polling for more
+    ///
+    /// Call before `close` to flush in-flight records on shutdown.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiMoveEvent>>> {
+        Ok(std::iter::from_fn(|| self.pop()).map(Record::new).collect())
+    }
+}
+
+#[async_trait]
+impl Source<SuiMoveEvent> for SuiEventSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_client(&self.rpc_url).await?;
+
+        self.client = Some(client);
+        tracing::info!("SuiEventSource initialized with RPC URL: {}", self.rpc_url);
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiMoveEvent>>> {
+        Ok(self.next_event().await?.map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.client = None;
+
+        // Rewind to the last emitted event, so a source initialized again
+        // re-fetches what was buffered instead of skipping it
+        if !self.buffer.is_empty() {
+            tracing::info!(
+                "Discarding {} buffered events, call drain() before close() to emit them",
+                self.buffer.len()
+            );
+            self.cursor = self.last_emitted;
+            self.has_next_page = self.cursor.is_some();
+            self.buffer.clear();
+        }
+        tracing::info!("SuiEventSource closed");
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+use crate::SuiEvent;
+
+/// [`SuiEvent`] flattened into scalar columns for loading into data warehouses
+///
+/// Lists and nested structures are JSON-encoded strings and BCS bytes are
+/// hex-encoded, so every field maps to a plain column in ClickHouse, BigQuery
+/// and similar stores.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiEventFlat {
+    /// Transaction ID
+    pub transaction_digest: String,
+    /// Transaction type
+    pub transaction_type: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Sender address
+    pub sender: String,
+    /// Recipient address, see [`SuiEvent::recipient`]
+    pub recipient: Option<String>,
+    /// Label of the sender
+    pub sender_label: Option<String>,
+    /// Label of the recipient
+    pub recipient_label: Option<String>,
+    /// Amount paid to the recipients, see [`SuiEvent::amount`]
+    pub amount: Option<u64>,
+    /// Computation cost in MIST
+    pub computation_cost: u64,
+    /// Storage cost in MIST
+    pub storage_cost: u64,
+    /// Storage rebate in MIST
+    pub storage_rebate: u64,
+    /// Gas fee paid by the sender in MIST, negative if the rebate exceeded the costs
+    pub gas_fee: i64,
+    /// Address paying for gas, the sponsor in sponsored transactions
+    pub gas_owner: String,
+    /// Whether the gas was paid by an address other than the sender
+    pub is_sponsored: bool,
+    /// Signature scheme of the sender, e.g. `zklogin`
+    pub auth_scheme: Option<String>,
+    /// Whether the transaction executed successfully
+    pub success: bool,
+    /// Reason the execution failed
+    pub execution_error: Option<String>,
+    /// Move events as a JSON array
+    pub events: String,
+    /// Balance changes as a JSON array
+    pub balance_changes: String,
+    /// Object changes as a JSON array
+    pub object_changes: String,
+    /// Whether the transaction touched shared objects
+    pub uses_shared_objects: bool,
+    /// Shared object IDs as a JSON array
+    pub shared_objects: String,
+    /// Number of created objects
+    pub created_count: u64,
+    /// Number of mutated objects
+    pub mutated_count: u64,
+    /// Number of deleted objects
+    pub deleted_count: u64,
+    /// Number of Move events emitted
+    pub events_count: u64,
+    /// Kind of the transaction metadata, e.g. `programmable`
+    pub metadata_kind: String,
+    /// Transaction metadata as a JSON object
+    pub metadata: String,
+    /// Hex-encoded BCS of the transaction, set when BCS passthrough is enabled
+    pub raw_transaction: Option<String>,
+    /// Full RPC response as JSON, set when raw JSON passthrough is enabled
+    pub raw: Option<String>,
+}
+
+impl From<SuiEvent> for SuiEventFlat {
+    fn from(event: SuiEvent) -> Self {
+        let metadata = serde_json::to_value(&event.metadata).unwrap_or_default();
+        Self {
+            transaction_digest: event.transaction_digest,
+            transaction_type: event.transaction_type,
+            timestamp: event.timestamp,
+            sender: event.sender,
+            recipient: event.recipient,
+            sender_label: event.sender_label,
+            recipient_label: event.recipient_label,
+            amount: event.amount,
+            computation_cost: event.computation_cost,
+            storage_cost: event.storage_cost,
+            storage_rebate: event.storage_rebate,
+            gas_fee: event.gas_fee,
+            gas_owner: event.gas_owner,
+            is_sponsored: event.is_sponsored,
+            auth_scheme: event.auth_scheme.map(|scheme| scheme.to_string()),
+            success: event.success,
+            execution_error: event.execution_error,
+            events: to_json(&event.events),
+            balance_changes: to_json(&event.balance_changes),
+            object_changes: to_json(&event.object_changes),
+            uses_shared_objects: event.uses_shared_objects,
+            shared_objects: to_json(&event.shared_objects),
+            created_count: event.created_count as u64,
+            mutated_count: event.mutated_count as u64,
+            deleted_count: event.deleted_count as u64,
+            events_count: event.events_count as u64,
+            metadata_kind: metadata["kind"].as_str().unwrap_or_default().to_string(),
+            metadata: metadata.to_string(),
+            raw_transaction: event.raw_transaction.as_deref().map(to_hex),
+            raw: event.raw.map(|raw| raw.to_string()),
+        }
+    }
+}
+
+/// Encodes a list as JSON, the event types always serialize
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Encodes bytes as lowercase hex
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Reaction of a [`crate::SuiSource`] to checkpoints missing between emitted transactions
+///
+/// Gaps are only detected on unfiltered streams, since filters legitimately
+/// skip checkpoints without matching transactions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GapPolicy {
+    /// Fetch the transactions of the missing checkpoints before resuming live polling
+    #[default]
+    Backfill,
+    /// Log the gap and continue with the next transaction
+    Skip,
+    /// Return an error
+    Fail,
+}
@@ -0,0 +1,197 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::{
+    SuiTransactionBlockDataAPI, SuiTransactionBlockResponseOptions,
+    SuiTransactionBlockResponseQuery,
+};
+use tokio::time::sleep;
+
+use crate::retry::{connect_client, now_millis};
+
+/// Gas prices paid by recently executed transactions
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct GasPricePercentiles {
+    /// Number of transactions sampled
+    pub sample_size: usize,
+    /// Median gas price
+    pub p50: u64,
+    /// 75th percentile gas price
+    pub p75: u64,
+    /// 90th percentile gas price
+    pub p90: u64,
+    /// 99th percentile gas price
+    pub p99: u64,
+}
+
+impl GasPricePercentiles {
+    /// Computes percentiles from a set of gas prices, `None` if empty
+    fn from_prices(mut prices: Vec<u64>) -> Option<Self> {
+        if prices.is_empty() {
+            return None;
+        }
+        prices.sort_unstable();
+
+        let percentile = |p: usize| prices[(prices.len() - 1) * p / 100];
+        Some(Self {
+            sample_size: prices.len(),
+            p50: percentile(50),
+            p75: percentile(75),
+            p90: percentile(90),
+            p99: percentile(99),
+        })
+    }
+}
+
+/// Sui gas price observation
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiGasPrice {
+    /// Reference gas price of the current epoch
+    pub reference_gas_price: u64,
+    /// Gas prices of recent transactions (if enabled)
+    pub percentiles: Option<GasPricePercentiles>,
+    /// Time the prices were observed
+    pub timestamp: u64,
+}
+
+/// Sui data source emitting a record whenever gas prices change
+pub struct SuiGasPriceSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval
+    interval: Duration,
+    /// Number of recent transactions sampled for percentiles, disabled when not set
+    sample_size: Option<usize>,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Last emitted prices
+    last_price: Option<(u64, Option<GasPricePercentiles>)>,
+}
+
+impl SuiGasPriceSource {
+    /// Creates a new SuiGasPriceSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            sample_size: None,
+            client: None,
+            last_price: None,
+        }
+    }
+
+    /// Also computes gas price percentiles over the most recent transactions
+    ///
+    /// # Parameters
+    /// * `sample_size` - Number of recent transactions to sample, at most 50
+    pub fn with_percentiles(mut self, sample_size: usize) -> Self {
+        self.sample_size = Some(sample_size.clamp(1, 50));
+        self
+    }
+
+    /// Samples gas prices of the most recent transactions
+    async fn fetch_percentiles(
+        client: &SuiClient,
+        sample_size: usize,
+    ) -> StreamResult<Option<GasPricePercentiles>> {
+        let page = client
+            .read_api()
+            .query_transaction_blocks(
+                SuiTransactionBlockResponseQuery::new(
+                    None,
+                    Some(SuiTransactionBlockResponseOptions::new().with_input()),
+                ),
+                None,
+                Some(sample_size),
+                true,
+            )
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch transactions: {}", e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        let prices = page
+            .data
+            .iter()
+            .filter_map(|transaction| transaction.transaction.as_ref())
+            .map(|transaction| transaction.data.gas_data().price)
+            .collect();
+
+        Ok(GasPricePercentiles::from_prices(prices))
+    }
+}
+
+#[async_trait]
+impl Source<SuiGasPrice> for SuiGasPriceSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_client(&self.rpc_url).await?;
+
+        self.client = Some(client);
+        tracing::info!(
+            "SuiGasPriceSource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiGasPrice>>> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| StreamError::Runtime("SuiGasPriceSource not initialized".to_string()))?;
+
+        // Emit the current prices right away
+        if self.last_price.is_some() {
+            sleep(self.interval).await;
+        }
+
+        let reference_gas_price = client
+            .governance_api()
+            .get_reference_gas_price()
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch reference gas price: {}", e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        let percentiles = match self.sample_size {
+            Some(sample_size) => Self::fetch_percentiles(client, sample_size).await?,
+            None => None,
+        };
+
+        let price = (reference_gas_price, percentiles);
+        if self.last_price.as_ref() == Some(&price) {
+            return Ok(None);
+        }
+        self.last_price = Some(price.clone());
+
+        let timestamp = now_millis();
+
+        Ok(Some(Record::new(SuiGasPrice {
+            reference_gas_price: price.0,
+            percentiles: price.1,
+            timestamp,
+        })))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.client = None;
+        tracing::info!("SuiGasPriceSource closed");
+        Ok(())
+    }
+}
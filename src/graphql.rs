@@ -0,0 +1,793 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use http::header::AUTHORIZATION;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use sui_sdk::rpc_types::TransactionFilter;
+use tokio::time::sleep;
+
+use crate::builder::{bearer, header_map};
+use crate::sui::event_record;
+use crate::tls::http_client_builder;
+use crate::{
+    AuthScheme, BalanceChange, MoveEventSummary, ObjectChangeKind, ObjectChangeSummary,
+    ProgrammableCommand, SuiEvent, SuiNetwork, TransactionMetadata,
+};
+
+/// Sui Mainnet GraphQL RPC endpoint
+const MAINNET_GRAPHQL_URL: &str = "https://sui-mainnet.mystenlabs.com/graphql";
+
+/// Query anchoring the stream at the newest transactions
+const LATEST_TRANSACTIONS_QUERY: &str = r#"
+query ($last: Int, $filter: TransactionBlockFilter) {
+  transactionBlocks(last: $last, filter: $filter) {
+    pageInfo { hasNextPage endCursor }
+    edges { cursor }
+    nodes { ...TransactionFields }
+  }
+}
+"#;
+
+/// Query walking forward from a cursor
+const NEXT_TRANSACTIONS_QUERY: &str = r#"
+query ($first: Int, $after: String, $filter: TransactionBlockFilter) {
+  transactionBlocks(first: $first, after: $after, filter: $filter) {
+    pageInfo { hasNextPage endCursor }
+    edges { cursor }
+    nodes { ...TransactionFields }
+  }
+}
+"#;
+
+/// Transaction fields needed to build a SuiEvent
+const TRANSACTION_FIELDS: &str = r#"
+fragment TransactionFields on TransactionBlock {
+  digest
+  sender { address }
+  signatures
+  gasInput { gasSponsor { address } }
+  kind {
+    __typename
+    ... on ProgrammableTransactionBlock {
+      inputs {
+        nodes { __typename ... on SharedInput { address } }
+      }
+      transactions {
+        nodes {
+          __typename
+          ... on MoveCallTransaction { package module functionName typeArguments { repr } }
+        }
+      }
+    }
+    ... on ConsensusCommitPrologueTransaction { epoch { epochId } round commitTimestamp }
+    ... on ChangeEpochTransaction { epoch { epochId } }
+  }
+  effects {
+    timestamp
+    checkpoint { sequenceNumber }
+    status
+    errors
+    gasEffects { gasSummary { computationCost storageCost storageRebate } }
+    balanceChanges {
+      nodes { owner { address } amount coinType { repr } }
+    }
+    events {
+      nodes { type { repr } sendingModule { package { address } name } json }
+    }
+    objectChanges {
+      nodes {
+        address
+        idCreated
+        idDeleted
+        inputState { version owner { ...OwnerFields } }
+        outputState {
+          version
+          owner { ...OwnerFields }
+          asMoveObject { contents { type { repr } } }
+          asMovePackage { address }
+        }
+      }
+    }
+  }
+}
+
+fragment OwnerFields on ObjectOwner {
+  __typename
+  ... on AddressOwner { owner { address } }
+  ... on Parent { parent { address } }
+  ... on Shared { initialSharedVersion }
+}
+"#;
+
+#[derive(Deserialize)]
+struct GraphqlResponse {
+    data: Option<GraphqlData>,
+    #[serde(default)]
+    errors: Vec<GraphqlError>,
+}
+
+#[derive(Deserialize)]
+struct GraphqlError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphqlData {
+    transaction_blocks: TransactionBlockConnection,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionBlockConnection {
+    page_info: PageInfo,
+    edges: Vec<Edge>,
+    nodes: Vec<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct Edge {
+    cursor: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+/// Sui data source streaming transactions through the GraphQL RPC
+///
+/// Emits the same [`SuiEvent`] records as [`SuiSource`](crate::SuiSource), for
+/// fullnodes that no longer serve JSON-RPC, with these differences:
+///
+/// * `events`, `balance_changes` and `object_changes` hold the first page of
+///   their GraphQL connection, so `events_count` and the object change counts
+///   undercount transactions exceeding the page size of the endpoint.
+/// * `auth_scheme` is read from the first signature, which is the sender's
+///   one by convention for sponsored transactions.
+/// * `shared_objects` lists the shared inputs of programmable transactions
+///   and the shared objects changed by the transaction, but not the ones
+///   system transactions only read.
+/// * The version of deleted and wrapped objects is their version before the
+///   transaction, JSON-RPC reports the version after it.
+/// * `raw_transaction`, `raw`, the BCS contents of events and the sender and
+///   recipient labels are never set.
+pub struct SuiGraphqlSource {
+    /// Sui GraphQL endpoint URL
+    graphql_url: String,
+    /// Polling interval
+    interval: Duration,
+    /// Maximum number of transactions to fetch per poll
+    max_transactions: usize,
+    /// Extra HTTP headers sent with every request
+    headers: HashMap<String, String>,
+    /// GraphQL transaction filter
+    filter: Option<serde_json::Value>,
+    /// HTTP client
+    client: Option<reqwest::Client>,
+    /// Fetched transactions waiting to be emitted, with their connection cursor
+    buffer: VecDeque<(String, SuiEvent)>,
+    /// Connection cursor pointing at the last fetched transaction
+    cursor: Option<String>,
+    /// Connection cursor pointing at the last emitted transaction
+    last_emitted_cursor: Option<String>,
+    /// Whether more transactions are available after the cursor
+    has_next_page: bool,
+}
+
+impl SuiGraphqlSource {
+    /// Creates a new SuiGraphqlSource instance
+    ///
+    /// # Parameters
+    /// * `graphql_url` - Sui GraphQL endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `max_transactions` - Maximum number of transactions to fetch per poll
+    pub fn new(graphql_url: String, interval_ms: u64, max_transactions: usize) -> Self {
+        Self {
+            graphql_url,
+            interval: Duration::from_millis(interval_ms),
+            max_transactions,
+            headers: HashMap::new(),
+            filter: None,
+            client: None,
+            buffer: VecDeque::new(),
+            cursor: None,
+            last_emitted_cursor: None,
+            has_next_page: false,
+        }
+    }
+
+    /// Creates a new SuiGraphqlSource instance for a well-known network
+    ///
+    /// # Parameters
+    /// * `network` - Network whose GraphQL endpoint is used, custom networks are rejected
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `max_transactions` - Maximum number of transactions to fetch per poll
+    pub fn for_network(
+        network: SuiNetwork,
+        interval_ms: u64,
+        max_transactions: usize,
+    ) -> StreamResult<Self> {
+        let graphql_url = network.graphql_url().ok_or_else(|| {
+            StreamError::Config(format!("No known GraphQL endpoint for {}", network))
+        })?;
+        Ok(Self::new(
+            graphql_url.to_string(),
+            interval_ms,
+            max_transactions,
+        ))
+    }
+
+    /// Creates a new SuiGraphqlSource instance using the Sui Mainnet GraphQL endpoint
+    #[deprecated(note = "use `SuiGraphqlSource::for_network(SuiNetwork::Mainnet, ..)` instead")]
+    pub fn new_with_mainnet(interval_ms: u64, max_transactions: usize) -> Self {
+        Self::new(
+            MAINNET_GRAPHQL_URL.to_string(),
+            interval_ms,
+            max_transactions,
+        )
+    }
+
+    /// Adds an HTTP header sent with every request, e.g. the API key of a managed RPC provider
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Authenticates every request with `Authorization: Bearer <token>`
+    pub fn with_bearer_token(self, token: impl AsRef<str>) -> Self {
+        self.with_header(AUTHORIZATION.as_str(), bearer(token.as_ref()))
+    }
+
+    /// Only streams transactions matching a JSON-RPC transaction filter
+    ///
+    /// Filters without a GraphQL counterpart are rejected: `ToAddress`,
+    /// `FromAndToAddress`, `TransactionKind` and `TransactionKindIn`.
+    pub fn with_filter(mut self, filter: TransactionFilter) -> StreamResult<Self> {
+        let filter = match filter {
+            TransactionFilter::Checkpoint(checkpoint) => json!({ "atCheckpoint": checkpoint }),
+            TransactionFilter::MoveFunction {
+                package,
+                module,
+                function,
+            } => {
+                let function = [Some(package.to_string()), module, function]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join("::");
+                json!({ "function": function })
+            }
+            TransactionFilter::InputObject(object) => {
+                json!({ "inputObject": object.to_string() })
+            }
+            TransactionFilter::ChangedObject(object) => {
+                json!({ "changedObject": object.to_string() })
+            }
+            TransactionFilter::FromAddress(address) => {
+                json!({ "sentAddress": address.to_string() })
+            }
+            TransactionFilter::FromOrToAddress { addr } => {
+                json!({ "affectedAddress": addr.to_string() })
+            }
+            other => {
+                return Err(StreamError::Config(format!(
+                    "Transaction filter not supported by Sui GraphQL: {:?}",
+                    other
+                )));
+            }
+        };
+        self.filter = Some(filter);
+        Ok(self)
+    }
+
+    /// Executes a GraphQL query and returns the transaction connection
+    async fn query(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> StreamResult<TransactionBlockConnection> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| StreamError::Runtime("SuiGraphqlSource not initialized".to_string()))?;
+
+        let response: GraphqlResponse = client
+            .post(&self.graphql_url)
+            .json(&json!({
+                "query": format!("{}{}", query, TRANSACTION_FIELDS),
+                "variables": variables,
+            }))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| {
+                tracing::error!("Failed to query Sui GraphQL: {}", e);
+                StreamError::Runtime(e.to_string())
+            })?
+            .json()
+            .await
+            .map_err(|e| StreamError::Serialization(e.to_string()))?;
+
+        if let Some(error) = response.errors.first() {
+            tracing::error!("Sui GraphQL returned an error: {}", error.message);
+            return Err(StreamError::Runtime(error.message.clone()));
+        }
+
+        response
+            .data
+            .map(|data| data.transaction_blocks)
+            .ok_or_else(|| StreamError::Runtime("Sui GraphQL returned no data".to_string()))
+    }
+
+    /// Fetches the next page of transactions and buffers them in chronological order
+    async fn fetch_batch(&mut self) -> StreamResult<()> {
+        let connection = match &self.cursor {
+            None => {
+                self.query(
+                    LATEST_TRANSACTIONS_QUERY,
+                    json!({ "last": self.max_transactions, "filter": self.filter }),
+                )
+                .await?
+            }
+            Some(cursor) => {
+                self.query(
+                    NEXT_TRANSACTIONS_QUERY,
+                    json!({
+                        "first": self.max_transactions,
+                        "after": cursor,
+                        "filter": self.filter,
+                    }),
+                )
+                .await?
+            }
+        };
+
+        // `last` pages end at the newest transaction, so only forward pages can be pending
+        self.has_next_page = self.cursor.is_some() && connection.page_info.has_next_page;
+        if connection.page_info.end_cursor.is_some() {
+            self.cursor = connection.page_info.end_cursor;
+        }
+
+        if connection.nodes.is_empty() {
+            tracing::info!("No new transactions found");
+            return Ok(());
+        }
+
+        tracing::debug!(
+            "Buffered {} new Sui transactions, has next page: {}",
+            connection.nodes.len(),
+            self.has_next_page
+        );
+        self.buffer.extend(
+            connection
+                .edges
+                .into_iter()
+                .map(|edge| edge.cursor)
+                .zip(connection.nodes.into_iter().map(Self::node_to_event)),
+        );
+
+        Ok(())
+    }
+
+    /// Takes the next buffered transaction
+    fn pop(&mut self) -> Option<Record<SuiEvent>> {
+        let (cursor, event) = self.buffer.pop_front()?;
+        self.last_emitted_cursor = Some(cursor);
+        Some(event_record(event))
+    }
+
+    /// Emits the transactions fetched but not emitted yet, without polling for more
+    ///
+    /// Call before `close` to flush in-flight records on shutdown.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiEvent>>> {
+        Ok(std::iter::from_fn(|| self.pop()).collect())
+    }
+
+    /// Converts the GraphQL balance changes of a transaction
+    fn node_balance_changes(changes: &serde_json::Value) -> Vec<BalanceChange> {
+        changes["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|change| BalanceChange {
+                owner: change["owner"]["address"]
+                    .as_str()
+                    .unwrap_or("unknown")
+                    .to_string(),
+                coin_type: change["coinType"]["repr"]
+                    .as_str()
+                    .unwrap_or("unknown")
+                    .to_string(),
+                // BigInt values are returned as strings
+                amount: change["amount"]
+                    .as_str()
+                    .and_then(|amount| amount.parse().ok())
+                    .unwrap_or(0),
+                decimals: None,
+                symbol: None,
+                formatted_amount: None,
+            })
+            .collect()
+    }
+
+    /// Converts the GraphQL events of a transaction
+    fn node_events(events: &serde_json::Value) -> Vec<MoveEventSummary> {
+        events["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|event| MoveEventSummary {
+                event_type: event["type"]["repr"]
+                    .as_str()
+                    .unwrap_or("unknown")
+                    .to_string(),
+                package_id: event["sendingModule"]["package"]["address"]
+                    .as_str()
+                    .unwrap_or("unknown")
+                    .to_string(),
+                module: event["sendingModule"]["name"]
+                    .as_str()
+                    .unwrap_or("unknown")
+                    .to_string(),
+                parsed_json: event["json"].clone(),
+                bcs: None,
+            })
+            .collect()
+    }
+
+    /// Formats a GraphQL object owner like the JSON-RPC `Owner`
+    fn node_owner(owner: &serde_json::Value) -> Option<String> {
+        Some(match owner["__typename"].as_str()? {
+            "AddressOwner" => owner["owner"]["address"].as_str()?.to_string(),
+            "Parent" => owner["parent"]["address"].as_str()?.to_string(),
+            "Shared" => format!(
+                "Shared( {} )",
+                owner["initialSharedVersion"].as_u64().unwrap_or(0)
+            ),
+            other => other.to_string(),
+        })
+    }
+
+    /// Converts the GraphQL object changes of a transaction
+    fn node_object_changes(changes: &serde_json::Value) -> Vec<ObjectChangeSummary> {
+        changes["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|change| {
+                let input = &change["inputState"];
+                let output = &change["outputState"];
+                let input_owner = Self::node_owner(&input["owner"]);
+                let output_owner = Self::node_owner(&output["owner"]);
+                let change_type = if change["idCreated"].as_bool() == Some(true) {
+                    if output["asMovePackage"].is_null() {
+                        ObjectChangeKind::Created
+                    } else {
+                        ObjectChangeKind::Published
+                    }
+                } else if change["idDeleted"].as_bool() == Some(true) {
+                    ObjectChangeKind::Deleted
+                } else if output.is_null() {
+                    ObjectChangeKind::Wrapped
+                } else if input.is_null() {
+                    // Unwrapped objects are reported as created by JSON-RPC
+                    ObjectChangeKind::Created
+                } else if input_owner != output_owner {
+                    ObjectChangeKind::Transferred
+                } else {
+                    ObjectChangeKind::Mutated
+                };
+                ObjectChangeSummary {
+                    change_type,
+                    object_id: change["address"].as_str().unwrap_or("unknown").to_string(),
+                    object_type: output["asMoveObject"]["contents"]["type"]["repr"]
+                        .as_str()
+                        .map(str::to_string),
+                    version: output["version"]
+                        .as_u64()
+                        .or_else(|| input["version"].as_u64())
+                        .unwrap_or(0),
+                    owner: output_owner,
+                }
+            })
+            .collect()
+    }
+
+    /// Lists the shared inputs of a transaction and the shared objects it changed
+    fn node_shared_objects(kind: &serde_json::Value, changes: &serde_json::Value) -> Vec<String> {
+        let inputs = kind["inputs"]["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|input| input["__typename"].as_str() == Some("SharedInput"))
+            .filter_map(|input| input["address"].as_str());
+        let changed = changes["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|change| change["inputState"]["owner"]["__typename"].as_str() == Some("Shared"))
+            .filter_map(|change| change["address"].as_str());
+
+        let mut shared_objects: Vec<String> = Vec::new();
+        for object in inputs.chain(changed) {
+            if !shared_objects.iter().any(|shared| shared == object) {
+                shared_objects.push(object.to_string());
+            }
+        }
+        shared_objects
+    }
+
+    /// Scheme of the first signature of a transaction
+    ///
+    /// Signatures are base64 encoded with a leading flag byte, which is
+    /// carried by the first two characters.
+    fn node_auth_scheme(signatures: &serde_json::Value) -> Option<AuthScheme> {
+        let sextet = |c: u8| match c {
+            b'A'..=b'Z' => Some(c - b'A'),
+            b'a'..=b'z' => Some(c - b'a' + 26),
+            b'0'..=b'9' => Some(c - b'0' + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        };
+        let signature = signatures.as_array()?.first()?.as_str()?.as_bytes();
+        let flag = (sextet(*signature.first()?)? << 2) | (sextet(*signature.get(1)?)? >> 4);
+        AuthScheme::from_flag(flag)
+    }
+
+    /// Recipient and amount of a transaction paying a single address
+    ///
+    /// Mirrors [`SuiCoinTransfer::decode`](crate::SuiCoinTransfer::decode):
+    /// recipients are the addresses besides the sender whose balance grew.
+    fn node_transfer(sender: &str, changes: &[BalanceChange]) -> (Option<String>, Option<u64>) {
+        let transfers: Vec<&BalanceChange> = changes
+            .iter()
+            .filter(|change| change.amount > 0)
+            .filter(|change| change.owner != sender && change.owner != "unknown")
+            .collect();
+        let recipient = match transfers.split_first() {
+            Some((first, rest)) if rest.iter().all(|t| t.owner == first.owner) => {
+                Some(first.owner.clone())
+            }
+            _ => None,
+        };
+        // Amounts of different coin types cannot be added up
+        let amount = match transfers.split_first() {
+            Some((first, rest)) if rest.iter().all(|t| t.coin_type == first.coin_type) => {
+                u64::try_from(transfers.iter().map(|t| t.amount).sum::<i128>()).ok()
+            }
+            _ => None,
+        };
+        (recipient, amount)
+    }
+
+    /// Converts a GraphQL programmable transaction command
+    fn node_command(command: &serde_json::Value) -> Option<ProgrammableCommand> {
+        let field = |name: &str| command[name].as_str().unwrap_or("unknown").to_string();
+        Some(match command["__typename"].as_str()? {
+            "MoveCallTransaction" => ProgrammableCommand::MoveCall {
+                package: field("package"),
+                module: field("module"),
+                function: field("functionName"),
+                type_arguments: command["typeArguments"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|type_argument| type_argument["repr"].as_str())
+                    .map(str::to_string)
+                    .collect(),
+            },
+            "TransferObjectsTransaction" => ProgrammableCommand::TransferObjects,
+            "SplitCoinsTransaction" => ProgrammableCommand::SplitCoins,
+            "MergeCoinsTransaction" => ProgrammableCommand::MergeCoins,
+            "PublishTransaction" => ProgrammableCommand::Publish,
+            "UpgradeTransaction" => ProgrammableCommand::Upgrade,
+            "MakeMoveVecTransaction" => ProgrammableCommand::MakeMoveVec,
+            _ => return None,
+        })
+    }
+
+    /// Summarizes a GraphQL transaction kind
+    fn kind_metadata(kind: &serde_json::Value, transaction_type: &str) -> TransactionMetadata {
+        let epoch = || kind["epoch"]["epochId"].as_u64().unwrap_or(0);
+        match kind["__typename"].as_str() {
+            Some("ProgrammableTransactionBlock") => TransactionMetadata::Programmable {
+                commands: kind["transactions"]["nodes"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Self::node_command)
+                    .collect(),
+            },
+            Some("ConsensusCommitPrologueTransaction") => TransactionMetadata::ConsensusCommit {
+                epoch: epoch(),
+                round: kind["round"].as_u64().unwrap_or(0),
+                commit_timestamp_ms: kind["commitTimestamp"]
+                    .as_str()
+                    .and_then(|timestamp| chrono::DateTime::parse_from_rfc3339(timestamp).ok())
+                    .map(|timestamp| timestamp.timestamp_millis() as u64)
+                    .unwrap_or(0),
+            },
+            Some("ChangeEpochTransaction") => TransactionMetadata::ChangeEpoch { epoch: epoch() },
+            Some(_) => TransactionMetadata::System {
+                kind: transaction_type.to_string(),
+            },
+            None => TransactionMetadata::Unknown,
+        }
+    }
+
+    /// Converts a GraphQL transaction block node to SuiEvent
+    fn node_to_event(node: serde_json::Value) -> SuiEvent {
+        let digest = node["digest"].as_str().unwrap_or("unknown").to_string();
+
+        // GraphQL kind names carry a suffix compared to their JSON-RPC counterparts
+        let transaction_type = node["kind"]["__typename"]
+            .as_str()
+            .map(|kind| match kind {
+                "ProgrammableTransactionBlock" => "ProgrammableTransaction",
+                "ConsensusCommitPrologueTransaction" => "ConsensusCommitPrologue",
+                "ChangeEpochTransaction" => "ChangeEpoch",
+                "GenesisTransaction" => "Genesis",
+                "AuthenticatorStateUpdateTransaction" => "AuthenticatorStateUpdate",
+                "RandomnessStateUpdateTransaction" => "RandomnessStateUpdate",
+                "EndOfEpochTransaction" => "EndOfEpochTransaction",
+                other => other,
+            })
+            .unwrap_or("unknown")
+            .to_string();
+
+        let sender = node["sender"]["address"]
+            .as_str()
+            .unwrap_or("unknown")
+            .to_string();
+
+        // System transactions have no gas sponsor
+        let gas_owner = node["gasInput"]["gasSponsor"]["address"]
+            .as_str()
+            .unwrap_or(&sender)
+            .to_string();
+        let is_sponsored = gas_owner != sender;
+
+        let timestamp = node["effects"]["timestamp"]
+            .as_str()
+            .and_then(|timestamp| chrono::DateTime::parse_from_rfc3339(timestamp).ok())
+            .map(|timestamp| timestamp.timestamp_millis() as u64)
+            .unwrap_or(0);
+
+        // BigInt values are returned as strings
+        let gas = &node["effects"]["gasEffects"]["gasSummary"];
+        let gas_cost = |field: &str| {
+            gas[field]
+                .as_str()
+                .and_then(|cost| cost.parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+        let computation_cost = gas_cost("computationCost");
+        let storage_cost = gas_cost("storageCost");
+        let storage_rebate = gas_cost("storageRebate");
+
+        let success = node["effects"]["status"].as_str() != Some("FAILURE");
+        let execution_error = node["effects"]["errors"].as_str().map(str::to_string);
+
+        let events = Self::node_events(&node["effects"]["events"]);
+        let events_count = events.len();
+        let metadata = Self::kind_metadata(&node["kind"], &transaction_type);
+
+        let balance_changes = Self::node_balance_changes(&node["effects"]["balanceChanges"]);
+        let (recipient, amount) = Self::node_transfer(&sender, &balance_changes);
+        let auth_scheme = Self::node_auth_scheme(&node["signatures"]);
+
+        let object_changes = Self::node_object_changes(&node["effects"]["objectChanges"]);
+        let count = |kinds: &[ObjectChangeKind]| {
+            object_changes
+                .iter()
+                .filter(|change| kinds.contains(&change.change_type))
+                .count()
+        };
+        let created_count = count(&[ObjectChangeKind::Created, ObjectChangeKind::Published]);
+        let mutated_count = count(&[ObjectChangeKind::Mutated, ObjectChangeKind::Transferred]);
+        let deleted_count = count(&[ObjectChangeKind::Deleted]);
+        let shared_objects =
+            Self::node_shared_objects(&node["kind"], &node["effects"]["objectChanges"]);
+
+        SuiEvent {
+            transaction_digest: digest,
+            transaction_type,
+            timestamp,
+            sender,
+            recipient,
+            sender_label: None,
+            recipient_label: None,
+            amount,
+            computation_cost,
+            storage_cost,
+            storage_rebate,
+            gas_fee: computation_cost as i64 + storage_cost as i64 - storage_rebate as i64,
+            is_sponsored,
+            auth_scheme,
+            gas_owner,
+            success,
+            execution_error,
+            events,
+            balance_changes,
+            object_changes,
+            uses_shared_objects: !shared_objects.is_empty(),
+            shared_objects,
+            created_count,
+            mutated_count,
+            deleted_count,
+            events_count,
+            metadata,
+            raw_transaction: None,
+            raw: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Source<SuiEvent> for SuiGraphqlSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = http_client_builder()
+            .default_headers(header_map(&self.headers)?)
+            .build()
+            .map_err(|e| {
+                tracing::error!("Failed to initialize HTTP client: {}", e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        self.client = Some(client);
+        tracing::info!(
+            "SuiGraphqlSource initialized with GraphQL URL: {}",
+            self.graphql_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiEvent>>> {
+        if self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiGraphqlSource not initialized".to_string(),
+            ));
+        }
+
+        // Only hit the endpoint once everything fetched previously has been emitted
+        if self.buffer.is_empty() {
+            // Polling interval, skipped while catching up on pending pages
+            if !self.has_next_page {
+                sleep(self.interval).await;
+            }
+            self.fetch_batch().await?;
+        }
+
+        Ok(self.pop())
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.client = None;
+
+        // Rewind to the last emitted transaction, so a source initialized
+        // again re-fetches what was buffered instead of skipping it
+        if !self.buffer.is_empty() {
+            tracing::info!(
+                "Discarding {} buffered transactions, call drain() before close() to emit them",
+                self.buffer.len()
+            );
+            self.cursor = self.last_emitted_cursor.clone();
+            self.has_next_page = self.cursor.is_some();
+            self.buffer.clear();
+        }
+        tracing::info!("SuiGraphqlSource closed");
+        Ok(())
+    }
+}
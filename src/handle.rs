@@ -0,0 +1,213 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use sui_sdk::rpc_types::TransactionFilter;
+use sui_sdk::types::base_types::SuiAddress;
+use tokio::sync::watch;
+
+/// Configuration changes pushed to a running source, applied at its next poll
+///
+/// Fields left unset keep their current value.
+#[derive(Clone, Debug, Default)]
+pub struct SuiSourceUpdate {
+    /// New polling interval
+    pub interval: Option<Duration>,
+    /// New maximum number of transactions fetched per poll
+    pub max_transactions: Option<usize>,
+    /// New server-side transaction filter, `Some(None)` removes the filter
+    pub filter: Option<Option<TransactionFilter>>,
+    /// New address watchlist of a [`crate::SuiMultiAddressSource`]
+    pub addresses: Option<Vec<SuiAddress>>,
+}
+
+impl SuiSourceUpdate {
+    /// Whether the update changes nothing
+    pub fn is_empty(&self) -> bool {
+        self.interval.is_none()
+            && self.max_transactions.is_none()
+            && self.filter.is_none()
+            && self.addresses.is_none()
+    }
+
+    /// Overrides the fields set in `other`
+    fn merge(&mut self, other: SuiSourceUpdate) {
+        if other.interval.is_some() {
+            self.interval = other.interval;
+        }
+        if other.max_transactions.is_some() {
+            self.max_transactions = other.max_transactions;
+        }
+        if other.filter.is_some() {
+            self.filter = other.filter;
+        }
+        if other.addresses.is_some() {
+            self.addresses = other.addresses;
+        }
+    }
+}
+
+/// Whether a source is catching up on history or tailing the network
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SuiIngestionPhase {
+    /// Emitting history, from a start checkpoint, a saved cursor or a checkpoint range
+    Backfill,
+    /// Caught up with the network, emitting new transactions as they execute
+    #[default]
+    Live,
+}
+
+/// Handle controlling a running [`crate::SuiSource`] from other tasks
+///
+/// Obtained with [`crate::SuiSource::handle`]. Cloning the handle is cheap
+/// and every clone controls the same source.
+#[derive(Clone, Debug)]
+pub struct SuiSourceHandle {
+    /// Whether ingestion is paused
+    paused: Arc<watch::Sender<bool>>,
+    /// Configuration changes not applied yet
+    pending_update: Arc<Mutex<SuiSourceUpdate>>,
+    /// Whether a checkpoint range has been fully emitted
+    finished: Arc<watch::Sender<bool>>,
+    /// Current ingestion phase
+    phase: Arc<watch::Sender<SuiIngestionPhase>>,
+}
+
+impl Default for SuiSourceHandle {
+    fn default() -> Self {
+        Self {
+            paused: Arc::new(watch::Sender::new(false)),
+            pending_update: Arc::new(Mutex::new(SuiSourceUpdate::default())),
+            finished: Arc::new(watch::Sender::new(false)),
+            phase: Arc::new(watch::Sender::new(SuiIngestionPhase::default())),
+        }
+    }
+}
+
+impl SuiSourceHandle {
+    /// Pauses ingestion, `next()` returns `Ok(None)` without polling until resumed
+    ///
+    /// The cursor and buffered transactions are kept.
+    pub fn pause(&self) {
+        if !self.paused.send_replace(true) {
+            tracing::info!("SuiSource paused");
+        }
+    }
+
+    /// Resumes ingestion where it was paused
+    pub fn resume(&self) {
+        if self.paused.send_replace(false) {
+            tracing::info!("SuiSource resumed");
+        }
+    }
+
+    /// Whether ingestion is paused
+    pub fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    /// Whether the configured checkpoint range has been fully emitted
+    ///
+    /// Never true for sources tailing the network.
+    pub fn is_finished(&self) -> bool {
+        *self.finished.borrow()
+    }
+
+    /// Waits until the configured checkpoint range has been fully emitted
+    ///
+    /// Lets the task driving a backfill pipeline shut it down once complete.
+    /// Never returns for sources tailing the network.
+    pub async fn wait_finished(&self) {
+        let mut finished = self.finished.subscribe();
+        // The sender lives as long as the handle, so waiting cannot fail
+        let _ = finished.wait_for(|finished| *finished).await;
+    }
+
+    /// Whether the source is backfilling history or tailing the network
+    pub fn phase(&self) -> SuiIngestionPhase {
+        *self.phase.borrow()
+    }
+
+    /// Waits until the source has caught up with the network
+    ///
+    /// Never returns for checkpoint range sources.
+    pub async fn wait_live(&self) {
+        let mut phase = self.phase.subscribe();
+        // The sender lives as long as the handle, so waiting cannot fail
+        let _ = phase
+            .wait_for(|phase| *phase == SuiIngestionPhase::Live)
+            .await;
+    }
+
+    /// Pushes configuration changes, applied at the next poll
+    ///
+    /// Changes pushed before the next poll are merged, later values winning.
+    pub fn update(&self, update: SuiSourceUpdate) {
+        self.lock_pending_update().merge(update);
+    }
+
+    /// Changes the polling interval at the next poll
+    pub fn set_interval(&self, interval: Duration) {
+        self.update(SuiSourceUpdate {
+            interval: Some(interval),
+            ..SuiSourceUpdate::default()
+        });
+    }
+
+    /// Changes the maximum number of transactions fetched per poll at the next poll
+    pub fn set_max_transactions(&self, max_transactions: usize) {
+        self.update(SuiSourceUpdate {
+            max_transactions: Some(max_transactions),
+            ..SuiSourceUpdate::default()
+        });
+    }
+
+    /// Replaces the server-side transaction filter at the next poll
+    pub fn set_filter(&self, filter: Option<TransactionFilter>) {
+        self.update(SuiSourceUpdate {
+            filter: Some(filter),
+            ..SuiSourceUpdate::default()
+        });
+    }
+
+    /// Replaces the address watchlist of a [`crate::SuiMultiAddressSource`] at the next poll
+    pub fn set_addresses(&self, addresses: Vec<SuiAddress>) {
+        self.update(SuiSourceUpdate {
+            addresses: Some(addresses),
+            ..SuiSourceUpdate::default()
+        });
+    }
+
+    /// Takes the configuration changes pushed since the last call
+    pub(crate) fn take_update(&self) -> Option<SuiSourceUpdate> {
+        let update = std::mem::take(&mut *self.lock_pending_update());
+        (!update.is_empty()).then_some(update)
+    }
+
+    /// Locks the pending update, recovering from a panic of another holder
+    fn lock_pending_update(&self) -> std::sync::MutexGuard<'_, SuiSourceUpdate> {
+        self.pending_update
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Switches the ingestion phase
+    pub(crate) fn set_phase(&self, phase: SuiIngestionPhase) {
+        if self.phase.send_replace(phase) != phase {
+            tracing::info!("SuiSource entered {:?} phase", phase);
+        }
+    }
+
+    /// Signals that the configured checkpoint range has been fully emitted
+    pub(crate) fn mark_finished(&self) {
+        if !self.finished.send_replace(true) {
+            tracing::info!("SuiSource finished its checkpoint range");
+        }
+    }
+
+    /// Waits up to `timeout` for ingestion to be resumed, returns whether it is running
+    pub(crate) async fn wait_resumed(&self, timeout: Duration) -> bool {
+        let mut paused = self.paused.subscribe();
+        tokio::time::timeout(timeout, paused.wait_for(|paused| !paused))
+            .await
+            .is_ok_and(|resumed| resumed.is_ok())
+    }
+}
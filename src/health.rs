@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::EndpointHealth;
+
+/// Health of a [`crate::SuiSource`], for supervisors deciding whether to restart it
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SuiSourceHealth {
+    /// Whether the source is initialized and holds a client
+    pub connected: bool,
+    /// Time of the last successful fetch in milliseconds since the Unix epoch
+    pub last_successful_fetch: Option<u64>,
+    /// Number of failed fetches since the last successful one
+    pub consecutive_errors: u32,
+    /// URL of the endpoint requests are currently sent to
+    pub current_endpoint: String,
+    /// Checkpoints the last emitted transaction is behind the network
+    pub lag: Option<u64>,
+    /// Health of every configured endpoint
+    pub endpoints: Vec<EndpointHealth>,
+}
+
+impl SuiSourceHealth {
+    /// Whether the source is connected and its last fetch succeeded
+    pub fn is_healthy(&self) -> bool {
+        self.connected && self.consecutive_errors == 0
+    }
+}
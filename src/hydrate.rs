@@ -0,0 +1,41 @@
+use fluxus::utils::models::{StreamError, StreamResult};
+use futures::{StreamExt, TryStreamExt, stream};
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::SuiTransactionBlockResponse;
+use sui_sdk::types::digests::TransactionDigest;
+
+use crate::SuiSource;
+
+/// Maximum number of transactions hydrated per request
+pub(crate) const MULTI_GET_LIMIT: usize = 50;
+
+impl SuiSource {
+    /// Fetches the details of the given transactions, keeping their order
+    ///
+    /// Digests are split into requests of at most 50 transactions, up to
+    /// `hydration_concurrency` of which are in flight at once.
+    pub(crate) async fn hydrate_transactions(
+        &self,
+        client: &SuiClient,
+        digests: &[TransactionDigest],
+    ) -> StreamResult<Vec<SuiTransactionBlockResponse>> {
+        let responses: Vec<Vec<SuiTransactionBlockResponse>> =
+            stream::iter(digests.chunks(MULTI_GET_LIMIT))
+                .map(|digests| async move {
+                    self.throttle().await;
+                    client
+                        .read_api()
+                        .multi_get_transactions_with_options(digests.to_vec(), self.options.clone())
+                        .await
+                })
+                .buffered(self.hydration_concurrency)
+                .try_collect()
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to fetch transaction details: {}", e);
+                    StreamError::Runtime(e.to_string())
+                })?;
+
+        Ok(responses.into_iter().flatten().collect())
+    }
+}
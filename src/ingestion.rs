@@ -0,0 +1,392 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use futures::StreamExt;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::Duration;
+use sui_sdk::types::effects::TransactionEffectsAPI;
+use sui_sdk::types::execution_status::ExecutionStatus;
+use sui_sdk::types::full_checkpoint_content::{CheckpointData, CheckpointTransaction};
+use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_sdk::types::storage::blob::Blob;
+use sui_sdk::types::transaction::TransactionDataAPI;
+use tokio::time::sleep;
+
+use crate::sui::event_record;
+use crate::tls::http_client_builder;
+use crate::{
+    AuthScheme, MoveEventDecoder, MoveEventSummary, SuiEvent, SuiNetwork, TransactionMetadata,
+};
+
+/// Sui Mainnet remote checkpoint bucket
+const MAINNET_CHECKPOINT_BUCKET: &str = "https://checkpoints.mainnet.sui.io";
+
+/// Location of checkpoint blob files in the Sui data ingestion format
+#[derive(Clone, Debug)]
+pub enum CheckpointStore {
+    /// Remote object store serving `{sequence_number}.chk` files over HTTP
+    Remote(String),
+    /// Local directory written by a fullnode's checkpoint executor
+    Local(PathBuf),
+}
+
+/// Sui data source reading checkpoint files instead of querying RPC
+///
+/// Every transaction is emitted in strict checkpoint order. Several checkpoint
+/// files are downloaded concurrently, which sustains far higher throughput
+/// than polling a fullnode.
+pub struct SuiCheckpointFileSource {
+    /// Where checkpoint files are read from
+    store: CheckpointStore,
+    /// Delay before retrying when the next checkpoint is not available yet
+    interval: Duration,
+    /// Number of checkpoint files fetched concurrently
+    concurrency: usize,
+    /// HTTP client for remote stores
+    client: Option<reqwest::Client>,
+    /// Next checkpoint to read
+    next_checkpoint: CheckpointSequenceNumber,
+    /// Decoded transactions waiting to be emitted, with their checkpoint and position in it
+    buffer: VecDeque<(CheckpointSequenceNumber, usize, SuiEvent)>,
+    /// Checkpoint and position of the last emitted transaction
+    last_emitted: Option<(CheckpointSequenceNumber, usize)>,
+    /// Decoder of Move event contents, which checkpoint files only carry as BCS
+    event_decoder: Option<MoveEventDecoder>,
+}
+
+impl SuiCheckpointFileSource {
+    /// Creates a new SuiCheckpointFileSource instance
+    ///
+    /// # Parameters
+    /// * `store` - Location of the checkpoint files
+    /// * `start_checkpoint` - First checkpoint to read
+    /// * `interval_ms` - Retry interval in milliseconds when caught up with the store
+    pub fn new(
+        store: CheckpointStore,
+        start_checkpoint: CheckpointSequenceNumber,
+        interval_ms: u64,
+    ) -> Self {
+        Self {
+            store,
+            interval: Duration::from_millis(interval_ms),
+            concurrency: 10,
+            client: None,
+            next_checkpoint: start_checkpoint,
+            buffer: VecDeque::new(),
+            last_emitted: None,
+            event_decoder: None,
+        }
+    }
+
+    /// Creates a new SuiCheckpointFileSource reading a network's remote checkpoint bucket
+    ///
+    /// # Parameters
+    /// * `network` - Network whose checkpoint bucket is read, only Mainnet and Testnet publish one
+    /// * `start_checkpoint` - First checkpoint to read
+    /// * `interval_ms` - Retry interval in milliseconds when caught up with the store
+    pub fn for_network(
+        network: SuiNetwork,
+        start_checkpoint: CheckpointSequenceNumber,
+        interval_ms: u64,
+    ) -> StreamResult<Self> {
+        let bucket_url = network.checkpoint_bucket_url().ok_or_else(|| {
+            StreamError::Config(format!("No known checkpoint bucket for {}", network))
+        })?;
+        Ok(Self::new(
+            CheckpointStore::Remote(bucket_url.to_string()),
+            start_checkpoint,
+            interval_ms,
+        ))
+    }
+
+    /// Creates a new SuiCheckpointFileSource reading the Sui Mainnet checkpoint bucket
+    #[deprecated(
+        note = "use `SuiCheckpointFileSource::for_network(SuiNetwork::Mainnet, ..)` instead"
+    )]
+    pub fn new_with_mainnet(start_checkpoint: CheckpointSequenceNumber, interval_ms: u64) -> Self {
+        Self::new(
+            CheckpointStore::Remote(MAINNET_CHECKPOINT_BUCKET.to_string()),
+            start_checkpoint,
+            interval_ms,
+        )
+    }
+
+    /// Sets the number of checkpoint files fetched concurrently
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Decodes the contents of Move events into JSON using the given decoder
+    ///
+    /// Without a decoder `parsed_json` of every event is `null`.
+    pub fn with_event_decoder(mut self, decoder: MoveEventDecoder) -> Self {
+        self.event_decoder = Some(decoder);
+        self
+    }
+
+    /// Returns the next checkpoint that will be read
+    pub fn next_checkpoint(&self) -> CheckpointSequenceNumber {
+        self.next_checkpoint
+    }
+
+    /// Reads a checkpoint file, returning `None` if it does not exist yet
+    async fn read_checkpoint(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> StreamResult<Option<CheckpointData>> {
+        let bytes = match &self.store {
+            CheckpointStore::Remote(url) => {
+                let client = self.client.as_ref().ok_or_else(|| {
+                    StreamError::Runtime("SuiCheckpointFileSource not initialized".to_string())
+                })?;
+                let response = client
+                    .get(format!(
+                        "{}/{}.chk",
+                        url.trim_end_matches('/'),
+                        sequence_number
+                    ))
+                    .send()
+                    .await
+                    .map_err(|e| StreamError::Runtime(e.to_string()))?;
+                if response.status() == reqwest::StatusCode::NOT_FOUND {
+                    return Ok(None);
+                }
+                response
+                    .error_for_status()
+                    .map_err(|e| StreamError::Runtime(e.to_string()))?
+                    .bytes()
+                    .await
+                    .map_err(|e| StreamError::Runtime(e.to_string()))?
+                    .to_vec()
+            }
+            CheckpointStore::Local(dir) => {
+                match tokio::fs::read(dir.join(format!("{}.chk", sequence_number))).await {
+                    Ok(bytes) => bytes,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                    Err(e) => return Err(StreamError::Runtime(e.to_string())),
+                }
+            }
+        };
+
+        Blob::from_bytes::<CheckpointData>(&bytes)
+            .map(Some)
+            .map_err(|e| {
+                tracing::error!("Failed to decode checkpoint {}: {}", sequence_number, e);
+                StreamError::Serialization(e.to_string())
+            })
+    }
+
+    /// Reads the next run of available checkpoints and buffers their transactions
+    async fn fetch_batch(&mut self) -> StreamResult<()> {
+        let start = self.next_checkpoint;
+        let checkpoints: Vec<StreamResult<Option<CheckpointData>>> =
+            futures::stream::iter(start..start + self.concurrency as u64)
+                .map(|sequence_number| self.read_checkpoint(sequence_number))
+                .buffered(self.concurrency)
+                .collect()
+                .await;
+
+        // Stop at the first missing checkpoint to keep strict ordering
+        for checkpoint in checkpoints {
+            let Some(checkpoint) = checkpoint? else {
+                break;
+            };
+
+            let sequence_number = self.next_checkpoint;
+            let timestamp = checkpoint.checkpoint_summary.timestamp_ms;
+            for (position, transaction) in checkpoint.transactions.iter().enumerate() {
+                // A checkpoint read again after close resumes after the last emitted transaction
+                if self
+                    .last_emitted
+                    .is_some_and(|(emitted_checkpoint, emitted_position)| {
+                        emitted_checkpoint == sequence_number && position <= emitted_position
+                    })
+                {
+                    continue;
+                }
+                let mut event = Self::transaction_to_event(transaction, timestamp);
+                if let Some(decoder) = self.event_decoder.as_mut() {
+                    Self::decode_events(decoder, transaction, &mut event).await;
+                }
+                self.buffer.push_back((sequence_number, position, event));
+            }
+            self.next_checkpoint += 1;
+        }
+
+        if self.next_checkpoint == start {
+            tracing::info!("Checkpoint {} not available yet", start);
+        } else {
+            tracing::debug!("Read checkpoints {}..{}", start, self.next_checkpoint);
+        }
+
+        Ok(())
+    }
+
+    /// Takes the next buffered transaction
+    fn pop(&mut self) -> Option<Record<SuiEvent>> {
+        let (checkpoint, position, event) = self.buffer.pop_front()?;
+        self.last_emitted = Some((checkpoint, position));
+        Some(event_record(event))
+    }
+
+    /// Emits the transactions read but not emitted yet, without reading more checkpoints
+    ///
+    /// Call before `close` to flush in-flight records on shutdown.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiEvent>>> {
+        Ok(std::iter::from_fn(|| self.pop()).collect())
+    }
+
+    /// Fills in the parsed contents of an event's Move events, leaving the ones that fail `null`
+    async fn decode_events(
+        decoder: &mut MoveEventDecoder,
+        transaction: &CheckpointTransaction,
+        event: &mut SuiEvent,
+    ) {
+        let contents = transaction.events.iter().flat_map(|events| &events.data);
+        for (summary, raw_event) in event.events.iter_mut().zip(contents) {
+            match decoder
+                .decode(&summary.event_type, &raw_event.contents)
+                .await
+            {
+                Ok(parsed_json) => summary.parsed_json = parsed_json,
+                Err(e) => tracing::warn!("Failed to decode event {}: {}", summary.event_type, e),
+            }
+        }
+    }
+
+    /// Converts a checkpoint transaction to SuiEvent
+    fn transaction_to_event(transaction: &CheckpointTransaction, timestamp: u64) -> SuiEvent {
+        let data = transaction.transaction.transaction_data();
+        let shared_objects: Vec<String> = data
+            .shared_input_objects()
+            .into_iter()
+            .map(|object| object.id.to_string())
+            .collect();
+        let gas = transaction.effects.gas_cost_summary();
+        let execution_error = match transaction.effects.status() {
+            ExecutionStatus::Success => None,
+            ExecutionStatus::Failure { error, .. } => Some(error.to_string()),
+        };
+
+        SuiEvent {
+            transaction_digest: transaction.transaction.digest().to_string(),
+            transaction_type: data.kind().name().to_string(),
+            timestamp,
+            sender: data.sender().to_string(),
+            recipient: None,
+            sender_label: None,
+            recipient_label: None,
+            amount: None,
+            computation_cost: gas.computation_cost,
+            storage_cost: gas.storage_cost,
+            storage_rebate: gas.storage_rebate,
+            gas_fee: gas.net_gas_usage(),
+            gas_owner: data.gas_owner().to_string(),
+            is_sponsored: data.gas_owner() != data.sender(),
+            auth_scheme: AuthScheme::of_sender(
+                transaction.transaction.data().tx_signatures(),
+                data.sender(),
+            ),
+            success: execution_error.is_none(),
+            execution_error,
+            events: transaction
+                .events
+                .iter()
+                .flat_map(|events| &events.data)
+                .map(|event| MoveEventSummary {
+                    event_type: event.type_.to_canonical_string(true),
+                    package_id: event.package_id.to_string(),
+                    module: event.transaction_module.to_string(),
+                    // Checkpoint files carry BCS contents, decoded later if an
+                    // event decoder is set
+                    parsed_json: serde_json::Value::Null,
+                    bcs: None,
+                })
+                .collect(),
+            balance_changes: Vec::new(),
+            object_changes: Vec::new(),
+            uses_shared_objects: !shared_objects.is_empty(),
+            shared_objects,
+            created_count: transaction.effects.created().len(),
+            mutated_count: transaction.effects.mutated().len(),
+            deleted_count: transaction.effects.deleted().len(),
+            events_count: transaction
+                .events
+                .as_ref()
+                .map_or(0, |events| events.data.len()),
+            metadata: TransactionMetadata::from_checkpoint(data.kind()),
+            raw_transaction: None,
+            raw: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Source<SuiEvent> for SuiCheckpointFileSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        if let CheckpointStore::Local(dir) = &self.store
+            && !dir.is_dir()
+        {
+            return Err(StreamError::Config(format!(
+                "Checkpoint directory {} does not exist",
+                dir.display()
+            )));
+        }
+
+        let client = http_client_builder().build().map_err(|e| {
+            tracing::error!("Failed to initialize HTTP client: {}", e);
+            StreamError::Runtime(e.to_string())
+        })?;
+        self.client = Some(client);
+        tracing::info!(
+            "SuiCheckpointFileSource initialized with store {:?} at checkpoint {}",
+            self.store,
+            self.next_checkpoint
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiEvent>>> {
+        if self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiCheckpointFileSource not initialized".to_string(),
+            ));
+        }
+
+        if self.buffer.is_empty() {
+            let before = self.next_checkpoint;
+            self.fetch_batch().await?;
+
+            // Caught up with the store, wait for new checkpoints
+            if self.next_checkpoint == before {
+                sleep(self.interval).await;
+            }
+        }
+
+        Ok(self.pop())
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.client = None;
+
+        // Rewind to the checkpoint being emitted, so a source initialized
+        // again reads it again instead of skipping the buffered transactions
+        if let Some((checkpoint, _, _)) = self.buffer.front() {
+            tracing::info!(
+                "Discarding {} buffered transactions, call drain() before close() to emit them",
+                self.buffer.len()
+            );
+            self.next_checkpoint = *checkpoint;
+            self.buffer.clear();
+        }
+        tracing::info!("SuiCheckpointFileSource closed");
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+use std::collections::BTreeMap;
+
+use crate::{
+    SuiBalanceChange, SuiBridgeEvent, SuiCheckpoint, SuiCheckpointTransaction, SuiCoinMetadata,
+    SuiCoinTransfer, SuiDeepBookEvent, SuiDynamicFieldChange, SuiEpoch, SuiEvent, SuiGasPrice,
+    SuiKioskEvent, SuiMoveEvent, SuiNameServiceEvent, SuiNftTransfer, SuiObjectChange,
+    SuiOwnedObjectChange, SuiPackagePublish, SuiStakingEvent, SuiSystemStateChange, SuiValidator,
+};
+
+/// Returns the JSON Schema of [`SuiEvent`] as serialized with serde
+pub fn sui_event_json_schema() -> RootSchema {
+    schema_for!(SuiEvent)
+}
+
+/// Returns the JSON Schemas of the records emitted by every source, by type name
+///
+/// Meant for registering the stream contracts with a data catalog.
+pub fn payload_json_schemas() -> BTreeMap<&'static str, RootSchema> {
+    BTreeMap::from([
+        ("SuiBalanceChange", schema_for!(SuiBalanceChange)),
+        ("SuiBridgeEvent", schema_for!(SuiBridgeEvent)),
+        ("SuiCheckpoint", schema_for!(SuiCheckpoint)),
+        (
+            "SuiCheckpointTransaction",
+            schema_for!(SuiCheckpointTransaction),
+        ),
+        ("SuiCoinMetadata", schema_for!(SuiCoinMetadata)),
+        ("SuiCoinTransfer", schema_for!(SuiCoinTransfer)),
+        ("SuiDeepBookEvent", schema_for!(SuiDeepBookEvent)),
+        ("SuiDynamicFieldChange", schema_for!(SuiDynamicFieldChange)),
+        ("SuiEpoch", schema_for!(SuiEpoch)),
+        ("SuiEvent", schema_for!(SuiEvent)),
+        ("SuiGasPrice", schema_for!(SuiGasPrice)),
+        ("SuiKioskEvent", schema_for!(SuiKioskEvent)),
+        ("SuiMoveEvent", schema_for!(SuiMoveEvent)),
+        ("SuiNameServiceEvent", schema_for!(SuiNameServiceEvent)),
+        ("SuiNftTransfer", schema_for!(SuiNftTransfer)),
+        ("SuiObjectChange", schema_for!(SuiObjectChange)),
+        ("SuiOwnedObjectChange", schema_for!(SuiOwnedObjectChange)),
+        ("SuiPackagePublish", schema_for!(SuiPackagePublish)),
+        ("SuiStakingEvent", schema_for!(SuiStakingEvent)),
+        ("SuiSystemStateChange", schema_for!(SuiSystemStateChange)),
+        ("SuiValidator", schema_for!(SuiValidator)),
+    ])
+}
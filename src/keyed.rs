@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{SuiEvent, SuiSource};
+
+/// Event paired with the key it is partitioned by
+#[derive(Clone, Debug)]
+pub struct SuiKeyedEvent {
+    /// Partition key, the sender address unless a key extractor is set
+    pub key: String,
+    /// Transaction event
+    pub event: SuiEvent,
+}
+
+/// Sui data source emitting events keyed for fluxus keyed windows and aggregations
+///
+/// Wraps a [`SuiSource`] so pipelines can group by key without a separate
+/// key-by mapping step. Record timestamps of the wrapped source are kept.
+pub struct SuiKeyedSource {
+    /// Wrapped transaction source
+    source: SuiSource,
+    /// Derives the key of an event
+    key_fn: Arc<dyn Fn(&SuiEvent) -> String + Send + Sync>,
+}
+
+impl SuiKeyedSource {
+    /// Creates a new SuiKeyedSource instance keyed by sender address
+    ///
+    /// # Parameters
+    /// * `source` - Source providing the events
+    pub fn new(source: SuiSource) -> Self {
+        Self::with_key_fn(source, |event| event.sender.clone())
+    }
+
+    /// Creates a new SuiKeyedSource instance with a custom key extractor
+    ///
+    /// # Parameters
+    /// * `source` - Source providing the events
+    /// * `key_fn` - Derives the key of an event, e.g. its recipient or coin type
+    pub fn with_key_fn<F>(source: SuiSource, key_fn: F) -> Self
+    where
+        F: Fn(&SuiEvent) -> String + Send + Sync + 'static,
+    {
+        Self {
+            source,
+            key_fn: Arc::new(key_fn),
+        }
+    }
+}
+
+impl fmt::Debug for SuiKeyedSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SuiKeyedSource").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl Source<SuiKeyedEvent> for SuiKeyedSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiKeyedEvent>>> {
+        let Some(record) = self.source.next().await? else {
+            return Ok(None);
+        };
+
+        let keyed = SuiKeyedEvent {
+            key: (self.key_fn)(&record.data),
+            event: record.data,
+        };
+        Ok(Some(Record::with_timestamp(keyed, record.timestamp)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.source.close().await
+    }
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use sui_sdk::rpc_types::EventFilter;
+use sui_sdk::types::{Identifier, SUI_FRAMEWORK_PACKAGE_ID};
+
+use crate::{SuiEventSource, SuiMoveEvent};
+
+/// Kind of kiosk marketplace activity
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum KioskAction {
+    /// Item was listed for sale
+    Listed,
+    /// Listed item was purchased
+    Purchased,
+    /// Item was taken off sale
+    Delisted,
+}
+
+/// Decoded kiosk marketplace event
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiKioskEvent {
+    /// Digest of the emitting transaction
+    pub transaction_digest: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Kind of activity
+    pub action: KioskAction,
+    /// Kiosk ID
+    pub kiosk_id: String,
+    /// Item object ID
+    pub item_id: String,
+    /// Move type of the item
+    pub item_type: String,
+    /// Listing or purchase price in MIST (not set for delistings)
+    pub price: Option<u64>,
+    /// Sender of the emitting transaction
+    pub sender: String,
+}
+
+impl SuiKioskEvent {
+    /// Decodes an event of the kiosk module, `None` for other events
+    pub fn decode(event: &SuiMoveEvent) -> Option<Self> {
+        let action = match event.event_name() {
+            "ItemListed" => KioskAction::Listed,
+            "ItemPurchased" => KioskAction::Purchased,
+            "ItemDelisted" => KioskAction::Delisted,
+            _ => return None,
+        };
+
+        Some(Self {
+            transaction_digest: event.transaction_digest.clone(),
+            timestamp: event.timestamp,
+            action,
+            kiosk_id: event.field_str("kiosk")?,
+            item_id: event.field_str("id")?,
+            item_type: event.type_param()?.to_string(),
+            price: event.field_u64("price"),
+            sender: event.sender.clone(),
+        })
+    }
+}
+
+/// Sui data source emitting decoded kiosk listings, purchases and delistings
+pub struct SuiKioskSource {
+    /// Wrapped event source scoped to the kiosk module
+    source: SuiEventSource,
+}
+
+impl SuiKioskSource {
+    /// Creates a new SuiKioskSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        let filter = EventFilter::MoveEventModule {
+            package: SUI_FRAMEWORK_PACKAGE_ID,
+            module: Identifier::new("kiosk").expect("kiosk is a valid identifier"),
+        };
+        Self {
+            source: SuiEventSource::new(rpc_url, filter, interval_ms, 50),
+        }
+    }
+}
+
+#[async_trait]
+impl Source<SuiKioskEvent> for SuiKioskSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiKioskEvent>>> {
+        // Skip kiosk events unrelated to trading, e.g. lock or extension events
+        while let Some(event) = self.source.next_event().await? {
+            if let Some(kiosk_event) = SuiKioskEvent::decode(&event) {
+                return Ok(Some(Record::new(kiosk_event)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.source.close().await
+    }
+}
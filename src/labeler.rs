@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use fluxus::utils::models::{StreamError, StreamResult};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use sui_sdk::types::base_types::SuiAddress;
+
+use crate::SuiEvent;
+
+/// Attaches human-readable labels, such as exchange or protocol names, to addresses
+///
+/// Implementations with a synchronous lookup simply return without awaiting.
+#[async_trait]
+pub trait AddressLabeler: fmt::Debug + Send + Sync {
+    /// Returns the label of an address, `None` if it is not known
+    async fn label(&self, address: &str) -> Option<String>;
+}
+
+/// Labels addresses from a fixed map
+#[derive(Clone, Debug, Default)]
+pub struct StaticAddressLabeler {
+    /// Labels by normalized address
+    labels: HashMap<SuiAddress, String>,
+}
+
+impl StaticAddressLabeler {
+    /// Creates a new StaticAddressLabeler instance
+    ///
+    /// # Parameters
+    /// * `labels` - Labels by address, addresses that cannot be parsed are rejected
+    pub fn new(labels: HashMap<String, String>) -> StreamResult<Self> {
+        let labels = labels
+            .into_iter()
+            .map(|(address, label)| {
+                SuiAddress::from_str(&address)
+                    .map(|address| (address, label))
+                    .map_err(|e| {
+                        StreamError::Config(format!("Invalid labeled address {}: {}", address, e))
+                    })
+            })
+            .collect::<StreamResult<_>>()?;
+        Ok(Self { labels })
+    }
+
+    /// Loads labels from a map of address to label, picking the format from the
+    /// `.json` extension, or `.toml`, `.yaml` and `.yml` with the `config` feature
+    pub fn from_file(path: impl AsRef<Path>) -> StreamResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            StreamError::Config(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+
+        let labels = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => serde_json::from_str(&content).map_err(|e| e.to_string()),
+            #[cfg(feature = "config")]
+            Some("toml") => toml::from_str(&content).map_err(|e| e.to_string()),
+            #[cfg(feature = "config")]
+            Some("yaml" | "yml") => serde_yaml::from_str(&content).map_err(|e| e.to_string()),
+            _ => {
+                return Err(StreamError::Config(format!(
+                    "Unsupported address label format: {}",
+                    path.display()
+                )));
+            }
+        }
+        .map_err(|e| {
+            StreamError::Config(format!("Invalid address labels {}: {}", path.display(), e))
+        })?;
+
+        Self::new(labels)
+    }
+
+    /// Number of labeled addresses
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Whether no address is labeled
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+#[async_trait]
+impl AddressLabeler for StaticAddressLabeler {
+    async fn label(&self, address: &str) -> Option<String> {
+        let address = SuiAddress::from_str(address).ok()?;
+        self.labels.get(&address).cloned()
+    }
+}
+
+/// Labels the sender and recipient of an event
+pub(crate) async fn label_event(labeler: &dyn AddressLabeler, event: &mut SuiEvent) {
+    event.sender_label = labeler.label(&event.sender).await;
+    if let Some(recipient) = &event.recipient {
+        event.recipient_label = labeler.label(recipient).await;
+    }
+}
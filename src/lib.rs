@@ -15,13 +15,13 @@
 //!
 //! ### Basic Source Implementation
 //! ```rust,no_run
-//! use fluxus_source_sui::SuiSource;
+//! use fluxus_source_sui::{SuiNetwork, SuiSource};
 //! use fluxus::sources::Source;
 //!
 //! #[tokio::main]
 //! async fn main() {
 //!     // Create a new Sui source with 500ms polling interval and batch size of 10
-//!     let mut source = SuiSource::new_with_mainnet(500, 10);
+//!     let mut source = SuiSource::for_network(SuiNetwork::Mainnet, 500, 10);
 //!     
 //!     // Initialize the source
 //!     source.init().await.unwrap();
@@ -33,6 +33,154 @@
 //! }
 //! ```
 
+#[cfg(not(any(feature = "native-tls", feature = "rustls-tls")))]
+compile_error!(
+    "Sui RPC endpoints are served over HTTPS, enable the `native-tls` or `rustls-tls` feature"
+);
+
+mod adaptive;
+mod auth;
+#[cfg(feature = "avro")]
+mod avro;
+mod balance;
+mod bridge;
+mod builder;
+mod checkpoint;
+mod checkpoint_transaction;
+mod circuit_breaker;
+mod coin_cache;
+mod coin_metadata;
+mod coin_transfer;
+mod config;
+mod conversion;
+mod cursor_store;
+mod dead_letter;
+mod deepbook;
+mod derived;
+mod dynamic_field;
+mod endpoint;
+mod epoch;
+mod event;
+mod flat;
+mod gap;
+mod gas_price;
+#[cfg(feature = "graphql")]
+mod graphql;
+mod handle;
+mod health;
+mod hydrate;
+#[cfg(feature = "ingestion")]
+mod ingestion;
+#[cfg(feature = "json-schema")]
+mod json_schema;
+mod keyed;
+mod kiosk;
+mod labeler;
+mod metadata;
+#[cfg(feature = "test-util")]
+mod mock;
+mod move_decoder;
+mod multi_address;
+mod multi_network;
+mod name_service;
+mod network;
+mod nft;
+mod object_change;
+mod order;
+mod owned_objects;
+mod package;
+mod predicate;
+mod prefetch;
+#[cfg(feature = "proto")]
+mod proto;
+mod rate_limit;
+mod raw;
+#[cfg(feature = "arrow")]
+mod record_batch;
+#[cfg(feature = "redis")]
+mod redis_store;
+mod retry;
+mod shard;
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+mod sql_store;
+mod staking;
+#[cfg(feature = "websocket")]
+mod subscription;
 mod sui;
+mod system_state;
+mod time_range;
+#[cfg(any(feature = "graphql", feature = "ingestion"))]
+mod tls;
+mod transform;
+mod validator;
+mod watermark;
 
+pub use adaptive::*;
+pub use auth::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
+pub use balance::*;
+pub use bridge::*;
+pub use builder::*;
+pub use checkpoint::*;
+pub use checkpoint_transaction::*;
+pub use circuit_breaker::*;
+pub use coin_metadata::*;
+pub use coin_transfer::*;
+pub use config::*;
+pub use conversion::*;
+pub use cursor_store::*;
+pub use dead_letter::*;
+pub use deepbook::*;
+pub use dynamic_field::*;
+pub use endpoint::*;
+pub use epoch::*;
+pub use event::*;
+pub use flat::*;
+pub use gap::*;
+pub use gas_price::*;
+#[cfg(feature = "graphql")]
+pub use graphql::*;
+pub use handle::*;
+pub use health::*;
+#[cfg(feature = "ingestion")]
+pub use ingestion::*;
+#[cfg(feature = "json-schema")]
+pub use json_schema::*;
+pub use keyed::*;
+pub use kiosk::*;
+pub use labeler::*;
+pub use metadata::*;
+#[cfg(feature = "test-util")]
+pub use mock::*;
+pub use move_decoder::*;
+pub use multi_address::*;
+pub use multi_network::*;
+pub use name_service::*;
+pub use network::*;
+pub use nft::*;
+pub use object_change::*;
+pub use order::*;
+pub use owned_objects::*;
+pub use package::*;
+pub use predicate::*;
+#[cfg(feature = "proto")]
+pub use proto::*;
+pub use rate_limit::*;
+pub use raw::*;
+#[cfg(feature = "arrow")]
+pub use record_batch::*;
+#[cfg(feature = "redis")]
+pub use redis_store::*;
+pub use retry::*;
+pub use shard::*;
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+pub use sql_store::*;
+pub use staking::*;
+#[cfg(feature = "websocket")]
+pub use subscription::*;
 pub use sui::*;
+pub use system_state::*;
+pub use transform::*;
+pub use validator::*;
+pub use watermark::*;
@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+use sui_sdk::rpc_types::{SuiCommand, SuiTransactionBlockKind};
+use sui_sdk::types::transaction::{Command, TransactionKind};
+
+/// Command of a programmable transaction block
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ProgrammableCommand {
+    /// Call of a Move function
+    MoveCall {
+        /// Package ID
+        package: String,
+        /// Module name
+        module: String,
+        /// Function name
+        function: String,
+        /// Type arguments of the call
+        type_arguments: Vec<String>,
+    },
+    /// Objects sent to an address
+    TransferObjects,
+    /// Coins split off a coin
+    SplitCoins,
+    /// Coins merged into a coin
+    MergeCoins,
+    /// Package published
+    Publish,
+    /// Package upgraded
+    Upgrade,
+    /// Vector built from arguments
+    MakeMoveVec,
+}
+
+/// Structured summary of what a transaction did, by transaction kind
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TransactionMetadata {
+    /// Programmable transaction block submitted by a user
+    Programmable {
+        /// Commands in execution order
+        commands: Vec<ProgrammableCommand>,
+    },
+    /// Consensus commit prologue opening a consensus commit
+    ConsensusCommit {
+        /// Epoch of the commit
+        epoch: u64,
+        /// Consensus round
+        round: u64,
+        /// Commit timestamp in milliseconds since the Unix epoch
+        commit_timestamp_ms: u64,
+    },
+    /// Epoch change
+    ChangeEpoch {
+        /// Epoch being started
+        epoch: u64,
+    },
+    /// Any other system transaction, e.g. randomness or authenticator state updates
+    System {
+        /// Transaction kind name
+        kind: String,
+    },
+    /// The transaction data was not returned
+    #[default]
+    Unknown,
+}
+
+impl TransactionMetadata {
+    /// Whether the transaction was issued by the system rather than a user,
+    /// e.g. a consensus commit prologue or a randomness state update
+    pub fn is_system(&self) -> bool {
+        matches!(
+            self,
+            Self::ConsensusCommit { .. } | Self::ChangeEpoch { .. } | Self::System { .. }
+        )
+    }
+
+    /// Summarizes a transaction kind returned by the JSON-RPC API
+    pub(crate) fn from_rpc(kind: &SuiTransactionBlockKind) -> Self {
+        match kind {
+            SuiTransactionBlockKind::ProgrammableTransaction(ptb) => Self::Programmable {
+                commands: ptb
+                    .commands
+                    .iter()
+                    .map(|command| match command {
+                        SuiCommand::MoveCall(call) => ProgrammableCommand::MoveCall {
+                            package: call.package.to_string(),
+                            module: call.module.clone(),
+                            function: call.function.clone(),
+                            type_arguments: call.type_arguments.clone(),
+                        },
+                        SuiCommand::TransferObjects(..) => ProgrammableCommand::TransferObjects,
+                        SuiCommand::SplitCoins(..) => ProgrammableCommand::SplitCoins,
+                        SuiCommand::MergeCoins(..) => ProgrammableCommand::MergeCoins,
+                        SuiCommand::Publish(..) => ProgrammableCommand::Publish,
+                        SuiCommand::Upgrade(..) => ProgrammableCommand::Upgrade,
+                        SuiCommand::MakeMoveVec(..) => ProgrammableCommand::MakeMoveVec,
+                    })
+                    .collect(),
+            },
+            SuiTransactionBlockKind::ConsensusCommitPrologue(prologue) => Self::ConsensusCommit {
+                epoch: prologue.epoch,
+                round: prologue.round,
+                commit_timestamp_ms: prologue.commit_timestamp_ms,
+            },
+            SuiTransactionBlockKind::ConsensusCommitPrologueV2(prologue) => Self::ConsensusCommit {
+                epoch: prologue.epoch,
+                round: prologue.round,
+                commit_timestamp_ms: prologue.commit_timestamp_ms,
+            },
+            SuiTransactionBlockKind::ConsensusCommitPrologueV3(prologue) => Self::ConsensusCommit {
+                epoch: prologue.epoch,
+                round: prologue.round,
+                commit_timestamp_ms: prologue.commit_timestamp_ms,
+            },
+            SuiTransactionBlockKind::ChangeEpoch(change) => Self::ChangeEpoch {
+                epoch: change.epoch,
+            },
+            other => Self::System {
+                kind: other.name().to_string(),
+            },
+        }
+    }
+
+    /// Summarizes a transaction kind read from checkpoint data
+    pub(crate) fn from_checkpoint(kind: &TransactionKind) -> Self {
+        match kind {
+            TransactionKind::ProgrammableTransaction(ptb) => Self::Programmable {
+                commands: ptb
+                    .commands
+                    .iter()
+                    .map(|command| match command {
+                        Command::MoveCall(call) => ProgrammableCommand::MoveCall {
+                            package: call.package.to_string(),
+                            module: call.module.to_string(),
+                            function: call.function.to_string(),
+                            type_arguments: call
+                                .type_arguments
+                                .iter()
+                                .map(|type_argument| type_argument.to_canonical_string(true))
+                                .collect(),
+                        },
+                        Command::TransferObjects(..) => ProgrammableCommand::TransferObjects,
+                        Command::SplitCoins(..) => ProgrammableCommand::SplitCoins,
+                        Command::MergeCoins(..) => ProgrammableCommand::MergeCoins,
+                        Command::Publish(..) => ProgrammableCommand::Publish,
+                        Command::Upgrade(..) => ProgrammableCommand::Upgrade,
+                        Command::MakeMoveVec(..) => ProgrammableCommand::MakeMoveVec,
+                    })
+                    .collect(),
+            },
+            TransactionKind::ConsensusCommitPrologue(prologue) => Self::ConsensusCommit {
+                epoch: prologue.epoch,
+                round: prologue.round,
+                commit_timestamp_ms: prologue.commit_timestamp_ms,
+            },
+            TransactionKind::ConsensusCommitPrologueV2(prologue) => Self::ConsensusCommit {
+                epoch: prologue.epoch,
+                round: prologue.round,
+                commit_timestamp_ms: prologue.commit_timestamp_ms,
+            },
+            TransactionKind::ConsensusCommitPrologueV3(prologue) => Self::ConsensusCommit {
+                epoch: prologue.epoch,
+                round: prologue.round,
+                commit_timestamp_ms: prologue.commit_timestamp_ms,
+            },
+            TransactionKind::ChangeEpoch(change) => Self::ChangeEpoch {
+                epoch: change.epoch,
+            },
+            other => Self::System {
+                kind: other.name().to_string(),
+            },
+        }
+    }
+}
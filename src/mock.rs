@@ -0,0 +1,214 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::time::sleep;
+
+use crate::{SuiEvent, TransactionMetadata};
+
+/// Step of a [`MockSuiSource`] script
+#[derive(Clone, Debug)]
+pub enum MockStep {
+    /// Emit an event
+    Event(SuiEvent),
+    /// Fail the call with a runtime error
+    Error(String),
+    /// Return no record, like a poll that found no new transactions
+    Empty,
+    /// Wait before running the next step
+    Delay(Duration),
+}
+
+/// Behavior of a [`MockSuiSource`] once its script is exhausted
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum MockEnd {
+    /// Keep returning no record
+    #[default]
+    Idle,
+    /// Start the script over
+    Repeat,
+    /// Fail every further call with a runtime error
+    Error(String),
+}
+
+/// Scripted stand-in for [`crate::SuiSource`] for testing pipelines offline
+///
+/// Every call to `next` runs the script up to and including the next step
+/// that produces a result.
+///
+/// ```rust
+/// use fluxus_source_sui::{MockEnd, MockSuiSource};
+///
+/// let source = MockSuiSource::new()
+///     .with_event(MockSuiSource::event("digest-1"))
+///     .with_error("node unavailable")
+///     .with_event(MockSuiSource::event("digest-2"))
+///     .with_end(MockEnd::Repeat);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockSuiSource {
+    /// Scripted steps
+    steps: Vec<MockStep>,
+    /// Steps left in the current run of the script
+    remaining: VecDeque<MockStep>,
+    /// Delay before every emitted event
+    record_delay: Duration,
+    /// Behavior once the script is exhausted
+    end: MockEnd,
+    /// Error returned by `init`
+    init_error: Option<String>,
+    /// Whether initialized
+    initialized: bool,
+}
+
+impl MockSuiSource {
+    /// Creates a new MockSuiSource with an empty script
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a transfer event with the given digest and placeholder fields
+    pub fn event(transaction_digest: &str) -> SuiEvent {
+        SuiEvent {
+            transaction_digest: transaction_digest.to_string(),
+            transaction_type: "ProgrammableTransaction".to_string(),
+            timestamp: 0,
+            sender: "0x0".to_string(),
+            recipient: None,
+            sender_label: None,
+            recipient_label: None,
+            amount: None,
+            computation_cost: 0,
+            storage_cost: 0,
+            storage_rebate: 0,
+            gas_fee: 0,
+            gas_owner: "0x0".to_string(),
+            is_sponsored: false,
+            auth_scheme: None,
+            success: true,
+            execution_error: None,
+            events: Vec::new(),
+            balance_changes: Vec::new(),
+            object_changes: Vec::new(),
+            uses_shared_objects: false,
+            shared_objects: Vec::new(),
+            created_count: 0,
+            mutated_count: 0,
+            deleted_count: 0,
+            events_count: 0,
+            metadata: TransactionMetadata::Programmable {
+                commands: Vec::new(),
+            },
+            raw_transaction: None,
+            raw: None,
+        }
+    }
+
+    /// Appends a step to the script
+    pub fn with_step(mut self, step: MockStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Appends an event to the script
+    pub fn with_event(self, event: SuiEvent) -> Self {
+        self.with_step(MockStep::Event(event))
+    }
+
+    /// Appends several events to the script
+    pub fn with_events(mut self, events: impl IntoIterator<Item = SuiEvent>) -> Self {
+        self.steps.extend(events.into_iter().map(MockStep::Event));
+        self
+    }
+
+    /// Appends a runtime error to the script
+    pub fn with_error(self, message: impl Into<String>) -> Self {
+        self.with_step(MockStep::Error(message.into()))
+    }
+
+    /// Appends an empty poll to the script
+    pub fn with_empty(self) -> Self {
+        self.with_step(MockStep::Empty)
+    }
+
+    /// Appends a delay to the script
+    pub fn with_delay(self, delay: Duration) -> Self {
+        self.with_step(MockStep::Delay(delay))
+    }
+
+    /// Waits before every emitted event, simulating the polling interval
+    pub fn with_record_delay(mut self, delay: Duration) -> Self {
+        self.record_delay = delay;
+        self
+    }
+
+    /// Sets the behavior once the script is exhausted
+    pub fn with_end(mut self, end: MockEnd) -> Self {
+        self.end = end;
+        self
+    }
+
+    /// Makes `init` fail with a runtime error
+    pub fn with_init_error(mut self, message: impl Into<String>) -> Self {
+        self.init_error = Some(message.into());
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+}
+
+#[async_trait]
+impl Source<SuiEvent> for MockSuiSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if let Some(message) = &self.init_error {
+            return Err(StreamError::Runtime(message.clone()));
+        }
+
+        self.remaining = self.steps.iter().cloned().collect();
+        self.initialized = true;
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiEvent>>> {
+        if !self.initialized {
+            return Err(StreamError::Runtime(
+                "MockSuiSource not initialized".to_string(),
+            ));
+        }
+
+        loop {
+            let step = match self.remaining.pop_front() {
+                Some(step) => step,
+                None => match &self.end {
+                    MockEnd::Idle => return Ok(None),
+                    MockEnd::Error(message) => return Err(StreamError::Runtime(message.clone())),
+                    // An empty script would repeat forever without producing anything
+                    MockEnd::Repeat if self.steps.is_empty() => return Ok(None),
+                    MockEnd::Repeat => {
+                        self.remaining = self.steps.iter().cloned().collect();
+                        continue;
+                    }
+                },
+            };
+
+            match step {
+                MockStep::Event(event) => {
+                    sleep(self.record_delay).await;
+                    return Ok(Some(Record::new(event)));
+                }
+                MockStep::Error(message) => return Err(StreamError::Runtime(message)),
+                MockStep::Empty => return Ok(None),
+                MockStep::Delay(delay) => sleep(delay).await,
+            }
+        }
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.remaining.clear();
+        Ok(())
+    }
+}
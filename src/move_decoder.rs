@@ -0,0 +1,419 @@
+use fluxus::utils::models::{StreamError, StreamResult};
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+use std::str::FromStr;
+use sui_sdk::SuiClient;
+use sui_sdk::types::base_types::ObjectID;
+
+use crate::SuiNetwork;
+use crate::retry::connect_client;
+
+/// Move type as it appears in normalized module definitions
+///
+/// Deserialized from the JSON-RPC representation of normalized types, which
+/// is stable across SDK versions.
+#[derive(Clone, Debug, Deserialize)]
+enum MoveType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Struct {
+        address: String,
+        module: String,
+        name: String,
+        #[serde(rename = "typeArguments")]
+        type_arguments: Vec<MoveType>,
+    },
+    Vector(Box<MoveType>),
+    TypeParameter(u16),
+    Reference(Box<MoveType>),
+    MutableReference(Box<MoveType>),
+}
+
+/// Field of a normalized Move struct
+#[derive(Clone, Debug, Deserialize)]
+struct MoveField {
+    /// Field name
+    name: String,
+    /// Field type, may refer to the struct's type parameters
+    #[serde(rename = "type")]
+    type_: MoveType,
+}
+
+/// Layout of a normalized Move struct
+#[derive(Clone, Debug, Deserialize)]
+struct MoveStruct {
+    /// Fields in declaration order, which is also their BCS order
+    fields: Vec<MoveField>,
+}
+
+/// Reason a payload could not be decoded yet
+enum DecodeError {
+    /// The layout of a package is needed but not cached
+    MissingPackage(ObjectID),
+    /// The payload or type is malformed
+    Invalid(String),
+}
+
+/// Decoder turning BCS-encoded Move event contents into JSON keyed by field names
+///
+/// Struct layouts are taken from the normalized modules of the defining
+/// package, which are fetched on first use and cached per package. Integers
+/// of 64 bits and more are rendered as strings, like the fullnode does.
+pub struct MoveEventDecoder {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Sui client, built on first use
+    client: Option<SuiClient>,
+    /// Struct layouts by package, module and struct name
+    packages: HashMap<ObjectID, HashMap<String, HashMap<String, MoveStruct>>>,
+}
+
+impl MoveEventDecoder {
+    /// Creates a new MoveEventDecoder instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL used to fetch package layouts
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self {
+            rpc_url: rpc_url.into(),
+            client: None,
+            packages: HashMap::new(),
+        }
+    }
+
+    /// Creates a new MoveEventDecoder fetching package layouts from a network's public fullnode
+    pub fn for_network(network: SuiNetwork) -> Self {
+        Self::new(network.rpc_url())
+    }
+
+    /// Number of packages whose layouts are cached
+    pub fn cached_packages(&self) -> usize {
+        self.packages.len()
+    }
+
+    /// Decodes the BCS contents of an event of the given fully qualified type
+    ///
+    /// # Parameters
+    /// * `event_type` - Event struct type, e.g. `0x3::validator::StakingRequestEvent`
+    /// * `contents` - BCS-encoded event contents
+    pub async fn decode(&mut self, event_type: &str, contents: &[u8]) -> StreamResult<Value> {
+        let type_ = parse_type(event_type).ok_or_else(|| {
+            StreamError::Serialization(format!("Invalid Move type {}", event_type))
+        })?;
+
+        loop {
+            let mut reader = BcsReader {
+                bytes: contents,
+                position: 0,
+            };
+            match self.decode_value(&type_, &mut reader) {
+                Ok(value) if reader.position == contents.len() => return Ok(value),
+                Ok(_) => {
+                    return Err(StreamError::Serialization(format!(
+                        "Trailing bytes after {} contents",
+                        event_type
+                    )));
+                }
+                Err(DecodeError::MissingPackage(package)) => self.load_package(package).await?,
+                Err(DecodeError::Invalid(e)) => {
+                    return Err(StreamError::Serialization(format!(
+                        "Failed to decode {}: {}",
+                        event_type, e
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Fetches and caches the struct layouts of a package
+    async fn load_package(&mut self, package: ObjectID) -> StreamResult<()> {
+        if self.client.is_none() {
+            let client = connect_client(&self.rpc_url).await?;
+            self.client = Some(client);
+        }
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| StreamError::Runtime("Sui client not available".to_string()))?;
+
+        let modules = client
+            .read_api()
+            .get_normalized_move_modules_by_package(package)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch modules of package {}: {}", package, e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        let layouts = modules
+            .into_iter()
+            .map(|(name, module)| {
+                serde_json::to_value(&module.structs)
+                    .and_then(serde_json::from_value)
+                    .map(|structs| (name, structs))
+                    .map_err(|e| StreamError::Serialization(e.to_string()))
+            })
+            .collect::<StreamResult<_>>()?;
+
+        tracing::debug!("Cached Move layouts of package {}", package);
+        self.packages.insert(package, layouts);
+        Ok(())
+    }
+
+    /// Decodes a value of a concrete type
+    fn decode_value(&self, type_: &MoveType, reader: &mut BcsReader) -> Result<Value, DecodeError> {
+        Ok(match type_ {
+            MoveType::Bool => json!(reader.take(1)?[0] != 0),
+            MoveType::U8 => json!(reader.take(1)?[0]),
+            MoveType::U16 => json!(u16::from_le_bytes(reader.array()?)),
+            MoveType::U32 => json!(u32::from_le_bytes(reader.array()?)),
+            MoveType::U64 => json!(u64::from_le_bytes(reader.array()?).to_string()),
+            MoveType::U128 => json!(u128::from_le_bytes(reader.array()?).to_string()),
+            MoveType::U256 => json!(u256_to_string(reader.array()?)),
+            MoveType::Address | MoveType::Signer => json!(reader.address()?),
+            MoveType::Vector(element) => {
+                let length = reader.uleb128()?;
+                let elements = (0..length)
+                    .map(|_| self.decode_value(element, reader))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Value::Array(elements)
+            }
+            MoveType::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => self.decode_struct(address, module, name, type_arguments, reader)?,
+            MoveType::TypeParameter(_) | MoveType::Reference(_) | MoveType::MutableReference(_) => {
+                return Err(DecodeError::Invalid(
+                    "Events cannot hold references or unresolved type parameters".to_string(),
+                ));
+            }
+        })
+    }
+
+    /// Decodes a struct, rendering well-known framework types like the fullnode does
+    fn decode_struct(
+        &self,
+        address: &str,
+        module: &str,
+        name: &str,
+        type_arguments: &[MoveType],
+        reader: &mut BcsReader,
+    ) -> Result<Value, DecodeError> {
+        let package = ObjectID::from_str(address)
+            .map_err(|e| DecodeError::Invalid(format!("Invalid package {}: {}", address, e)))?;
+
+        match (package, module, name) {
+            (package, "string" | "ascii", "String") if package == ObjectID::from_single_byte(1) => {
+                let length = reader.uleb128()?;
+                let bytes = reader.take(length)?;
+                return String::from_utf8(bytes.to_vec())
+                    .map(Value::String)
+                    .map_err(|e| DecodeError::Invalid(e.to_string()));
+            }
+            (package, "option", "Option") if package == ObjectID::from_single_byte(1) => {
+                let element = type_arguments.first().ok_or_else(|| {
+                    DecodeError::Invalid("Option without a type argument".to_string())
+                })?;
+                return match reader.uleb128()? {
+                    0 => Ok(Value::Null),
+                    1 => self.decode_value(element, reader),
+                    _ => Err(DecodeError::Invalid(
+                        "Option with several values".to_string(),
+                    )),
+                };
+            }
+            (package, "object", "ID") if package == ObjectID::from_single_byte(2) => {
+                return Ok(json!(reader.address()?));
+            }
+            _ => {}
+        }
+
+        let layout = self
+            .packages
+            .get(&package)
+            .ok_or(DecodeError::MissingPackage(package))?
+            .get(module)
+            .and_then(|structs| structs.get(name))
+            .ok_or_else(|| {
+                DecodeError::Invalid(format!("Unknown struct {}::{}::{}", address, module, name))
+            })?;
+
+        let mut fields = Map::new();
+        for field in &layout.fields {
+            let type_ = substitute(&field.type_, type_arguments)?;
+            fields.insert(field.name.clone(), self.decode_value(&type_, reader)?);
+        }
+        Ok(Value::Object(fields))
+    }
+}
+
+/// Replaces the type parameters of a field type by the struct's type arguments
+fn substitute(type_: &MoveType, type_arguments: &[MoveType]) -> Result<MoveType, DecodeError> {
+    Ok(match type_ {
+        MoveType::TypeParameter(index) => type_arguments
+            .get(*index as usize)
+            .cloned()
+            .ok_or_else(|| DecodeError::Invalid(format!("Missing type argument {}", index)))?,
+        MoveType::Vector(element) => {
+            MoveType::Vector(Box::new(substitute(element, type_arguments)?))
+        }
+        MoveType::Struct {
+            address,
+            module,
+            name,
+            type_arguments: arguments,
+        } => MoveType::Struct {
+            address: address.clone(),
+            module: module.clone(),
+            name: name.clone(),
+            type_arguments: arguments
+                .iter()
+                .map(|argument| substitute(argument, type_arguments))
+                .collect::<Result<_, _>>()?,
+        },
+        other => other.clone(),
+    })
+}
+
+/// Parses a fully qualified Move type such as `0x2::coin::Coin<0x2::sui::SUI>`
+fn parse_type(type_: &str) -> Option<MoveType> {
+    let type_ = type_.trim();
+    Some(match type_ {
+        "bool" => MoveType::Bool,
+        "u8" => MoveType::U8,
+        "u16" => MoveType::U16,
+        "u32" => MoveType::U32,
+        "u64" => MoveType::U64,
+        "u128" => MoveType::U128,
+        "u256" => MoveType::U256,
+        "address" => MoveType::Address,
+        "signer" => MoveType::Signer,
+        _ => {
+            if let Some(element) = type_
+                .strip_prefix("vector<")
+                .and_then(|rest| rest.strip_suffix('>'))
+            {
+                return Some(MoveType::Vector(Box::new(parse_type(element)?)));
+            }
+
+            let (path, arguments) = match type_.split_once('<') {
+                Some((path, arguments)) => {
+                    (path, split_type_arguments(arguments.strip_suffix('>')?))
+                }
+                None => (type_, Vec::new()),
+            };
+            let mut parts = path.split("::");
+            let (address, module, name) = (parts.next()?, parts.next()?, parts.next()?);
+            if parts.next().is_some() {
+                return None;
+            }
+
+            MoveType::Struct {
+                address: address.to_string(),
+                module: module.to_string(),
+                name: name.to_string(),
+                type_arguments: arguments
+                    .into_iter()
+                    .map(parse_type)
+                    .collect::<Option<_>>()?,
+            }
+        }
+    })
+}
+
+/// Splits type arguments on their top-level commas
+fn split_type_arguments(arguments: &str) -> Vec<&str> {
+    let mut depth = 0;
+    let mut start = 0;
+    let mut split = Vec::new();
+    for (i, c) in arguments.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                split.push(&arguments[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    split.push(&arguments[start..]);
+    split
+}
+
+/// Formats a little-endian 256-bit unsigned integer in decimal
+fn u256_to_string(mut bytes: [u8; 32]) -> String {
+    let mut digits = Vec::new();
+    while bytes.iter().any(|byte| *byte != 0) {
+        // Divide by ten, most significant byte first
+        let mut remainder = 0u32;
+        for byte in bytes.iter_mut().rev() {
+            let current = (remainder << 8) | *byte as u32;
+            *byte = (current / 10) as u8;
+            remainder = current % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+    }
+    if digits.is_empty() {
+        return "0".to_string();
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap_or_default()
+}
+
+/// Cursor over a BCS payload
+struct BcsReader<'a> {
+    /// Payload
+    bytes: &'a [u8],
+    /// Offset of the next unread byte
+    position: usize,
+}
+
+impl<'a> BcsReader<'a> {
+    /// Reads the next `length` bytes
+    fn take(&mut self, length: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| DecodeError::Invalid("Unexpected end of contents".to_string()))?;
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    /// Reads a fixed-size byte array
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    /// Reads a 32-byte address as a hex string
+    fn address(&mut self) -> Result<String, DecodeError> {
+        Ok(ObjectID::new(self.array()?).to_string())
+    }
+
+    /// Reads a ULEB128-encoded length
+    fn uleb128(&mut self) -> Result<usize, DecodeError> {
+        let mut value = 0usize;
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= ((byte & 0x7f) as usize) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(DecodeError::Invalid("Length overflows".to_string()))
+    }
+}
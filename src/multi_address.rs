@@ -0,0 +1,294 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use std::collections::{HashSet, VecDeque};
+use sui_sdk::rpc_types::{SuiTransactionBlockResponse, TransactionFilter};
+use sui_sdk::types::base_types::SuiAddress;
+use sui_sdk::types::digests::TransactionDigest;
+use tokio::time::sleep;
+
+use crate::sui::{event_record, with_next_timeout};
+use crate::{SuiEvent, SuiSource, SuiSourceHandle};
+
+/// Filtered query with its own pagination cursor
+struct AddressQuery {
+    /// Server-side filter
+    filter: TransactionFilter,
+    /// Cursor pointing at the last fetched transaction
+    cursor: Option<TransactionDigest>,
+    /// Cursor the last poll started from, restored on close while transactions are buffered
+    page_start: Option<TransactionDigest>,
+}
+
+/// Sui data source emitting every transaction touching a watchlist of addresses
+///
+/// A transaction is emitted when a watched address sent it or received
+/// objects or coins from it. Internally one query per address and direction
+/// is polled, and transactions matching several queries are emitted once.
+/// Queries go through the retry, failover and rate limiting of the wrapped
+/// source, and events through its enrichment, labeling and filters.
+pub struct SuiMultiAddressSource {
+    /// Source providing the client, query options and event processing
+    source: SuiSource,
+    /// Watched addresses
+    addresses: Vec<SuiAddress>,
+    /// Queries polled on every tick
+    queries: Vec<AddressQuery>,
+    /// Fetched transactions waiting to be emitted
+    buffer: VecDeque<SuiTransactionBlockResponse>,
+    /// Whether a query has more transactions after its cursor
+    has_next_page: bool,
+}
+
+impl SuiMultiAddressSource {
+    /// Creates a new SuiMultiAddressSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `addresses` - Addresses to watch
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `max_transactions` - Maximum number of transactions to fetch per query and poll
+    pub fn new(
+        rpc_url: String,
+        addresses: Vec<SuiAddress>,
+        interval_ms: u64,
+        max_transactions: usize,
+    ) -> Self {
+        Self::wrap(
+            SuiSource::new(rpc_url, interval_ms, max_transactions),
+            addresses,
+        )
+    }
+
+    /// Creates a new SuiMultiAddressSource instance from a configured source
+    ///
+    /// The transaction filter of the source is ignored in favor of the address
+    /// queries, every other option applies. Fails if the source tracks
+    /// watermarks, transactions of different addresses are not emitted in
+    /// checkpoint order.
+    ///
+    /// # Parameters
+    /// * `source` - Source providing the endpoints, query options and event processing
+    /// * `addresses` - Addresses to watch
+    pub fn from_source(source: SuiSource, addresses: Vec<SuiAddress>) -> StreamResult<Self> {
+        if source.watermarks.is_some() {
+            return Err(StreamError::Config(
+                "SuiMultiAddressSource cannot be combined with watermarks".to_string(),
+            ));
+        }
+        Ok(Self::wrap(source, addresses))
+    }
+
+    /// Builds the address queries around a source without validating it
+    fn wrap(source: SuiSource, addresses: Vec<SuiAddress>) -> Self {
+        let queries = address_queries(&addresses).collect();
+
+        Self {
+            source,
+            addresses,
+            queries,
+            buffer: VecDeque::new(),
+            has_next_page: false,
+        }
+    }
+
+    /// Returns a handle pausing and reconfiguring the source, including its watchlist
+    pub fn handle(&self) -> SuiSourceHandle {
+        self.source.handle()
+    }
+
+    /// Replaces the watchlist, keeping the cursors of addresses still watched
+    fn set_addresses(&mut self, addresses: Vec<SuiAddress>) {
+        let mut queries = std::mem::take(&mut self.queries);
+        self.queries = address_queries(&addresses)
+            .map(|new_query| {
+                queries
+                    .iter()
+                    .position(|query| same_filter(&query.filter, &new_query.filter))
+                    .map(|index| queries.swap_remove(index))
+                    .unwrap_or(new_query)
+            })
+            .collect();
+
+        tracing::info!(
+            "SuiMultiAddressSource now watching {} addresses",
+            addresses.len()
+        );
+        self.addresses = addresses;
+    }
+
+    /// Returns the next raw transaction, polling every address once the buffer is drained
+    ///
+    /// Pauses and the `next()` deadline apply like in [`SuiSource`]. Returns
+    /// `Ok(None)` while paused.
+    async fn next_transaction(&mut self) -> StreamResult<Option<SuiTransactionBlockResponse>> {
+        if !self.source.wait_while_paused().await {
+            return Ok(None);
+        }
+
+        let next_timeout = self.source.next_timeout;
+        with_next_timeout(next_timeout, self.poll_transaction()).await
+    }
+
+    /// Takes the next buffered transaction and marks it as emitted
+    fn pop_transaction(&mut self) -> Option<SuiTransactionBlockResponse> {
+        while let Some(transaction) = self.buffer.pop_front() {
+            if self.source.record_emitted(&transaction) {
+                return Some(transaction);
+            }
+        }
+        None
+    }
+
+    /// Emits the transactions fetched but not emitted yet, without polling for more
+    ///
+    /// Call before `close` to flush in-flight records on shutdown.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiEvent>>> {
+        let mut records = Vec::new();
+        while let Some(transaction) = self.pop_transaction() {
+            if let Some(event) = self.source.process_transaction(transaction).await? {
+                records.push(event_record(event));
+            }
+        }
+        Ok(records)
+    }
+
+    /// Returns the next raw transaction without a deadline
+    async fn poll_transaction(&mut self) -> StreamResult<Option<SuiTransactionBlockResponse>> {
+        if self.buffer.is_empty() {
+            if let Some(addresses) = self.source.apply_update() {
+                self.set_addresses(addresses);
+            }
+
+            // Take the first snapshot right away and catch up on pending pages
+            if !self.has_next_page && self.queries.iter().any(|query| query.cursor.is_some()) {
+                sleep(self.source.interval).await;
+            }
+            self.poll().await?;
+        }
+
+        Ok(self.pop_transaction())
+    }
+
+    /// Polls every query once and buffers the new transactions in chronological order
+    ///
+    /// The cursors only advance once every query succeeded, so a failed poll
+    /// is repeated as a whole instead of losing the pages already fetched.
+    async fn poll(&mut self) -> StreamResult<()> {
+        let mut transactions = Vec::new();
+        let mut cursors: Vec<Option<TransactionDigest>> = Vec::with_capacity(self.queries.len());
+        let mut has_next_page = false;
+        for query in &self.queries {
+            // Without a cursor start from the newest transactions, otherwise walk forward
+            let descending_order = query.cursor.is_none();
+            let page = self
+                .source
+                .query_page(Some(query.filter.clone()), query.cursor, descending_order)
+                .await?;
+
+            let mut data = page.data;
+            if descending_order {
+                data.reverse();
+            } else {
+                has_next_page |= page.has_next_page;
+            }
+            cursors.push(
+                data.last()
+                    .map(|latest_transaction| latest_transaction.digest)
+                    .or(query.cursor),
+            );
+            transactions.extend(data);
+        }
+
+        for (query, cursor) in self.queries.iter_mut().zip(cursors) {
+            query.page_start = std::mem::replace(&mut query.cursor, cursor);
+        }
+        self.has_next_page = has_next_page;
+
+        // Queries overlap, e.g. transfers between two watched addresses. Transactions
+        // only count as emitted once popped, so a rewound page skips just those.
+        transactions.sort_by_key(|transaction| (transaction.checkpoint, transaction.timestamp_ms));
+        let mut buffered = HashSet::new();
+        for transaction in transactions {
+            if !self.source.was_emitted(&transaction.digest) && buffered.insert(transaction.digest)
+            {
+                self.buffer.push_back(transaction);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether two address filters watch the same address in the same direction
+fn same_filter(a: &TransactionFilter, b: &TransactionFilter) -> bool {
+    match (a, b) {
+        (TransactionFilter::FromAddress(a), TransactionFilter::FromAddress(b))
+        | (TransactionFilter::ToAddress(a), TransactionFilter::ToAddress(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Builds the queries of both directions for every address
+fn address_queries(addresses: &[SuiAddress]) -> impl Iterator<Item = AddressQuery> + '_ {
+    addresses
+        .iter()
+        .flat_map(|address| {
+            [
+                TransactionFilter::FromAddress(*address),
+                TransactionFilter::ToAddress(*address),
+            ]
+        })
+        .map(|filter| AddressQuery {
+            filter,
+            cursor: None,
+            page_start: None,
+        })
+}
+
+#[async_trait]
+impl Source<SuiEvent> for SuiMultiAddressSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await?;
+        tracing::info!(
+            "SuiMultiAddressSource watching {} addresses",
+            self.addresses.len()
+        );
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiEvent>>> {
+        if !self.source.is_initialized() {
+            return Err(StreamError::Runtime(
+                "SuiMultiAddressSource not initialized".to_string(),
+            ));
+        }
+
+        loop {
+            let Some(transaction) = self.next_transaction().await? else {
+                return Ok(None);
+            };
+            if let Some(event) = self.source.process_transaction(transaction).await? {
+                return Ok(Some(event_record(event)));
+            }
+        }
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        // Rewind every query to where the last poll started, so a source
+        // initialized again re-fetches the buffered transactions instead of
+        // skipping them, those already emitted are deduplicated
+        if !self.buffer.is_empty() {
+            tracing::info!(
+                "Discarding {} buffered transactions, call drain() before close() to emit them",
+                self.buffer.len()
+            );
+            for query in &mut self.queries {
+                query.cursor = query.page_start;
+            }
+            self.has_next_page = true;
+            self.buffer.clear();
+        }
+        self.source.close().await
+    }
+}
@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use std::fmt;
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+
+use crate::{SuiEvent, SuiNetwork, SuiSource};
+
+/// Event tagged with the network it was observed on
+#[derive(Clone, Debug)]
+pub struct SuiNetworkEvent {
+    /// Network the transaction was executed on
+    pub network: SuiNetwork,
+    /// Transaction event
+    pub event: SuiEvent,
+}
+
+/// Result of a poll of one of the merged sources
+type NetworkPoll = StreamResult<Option<Record<SuiNetworkEvent>>>;
+
+/// Sui data source merging the transactions of several networks into one stream
+///
+/// Every network is polled by its own [`SuiSource`] in a background task, so
+/// a slow or failing network does not hold back the others. Records are
+/// emitted in arrival order and keep the timestamps of their source. Errors
+/// of a network are returned by `next` without stopping the other networks.
+pub struct SuiMultiNetworkSource {
+    /// Merged networks
+    networks: Vec<SuiNetwork>,
+    /// Sources waiting to be started, by network
+    sources: Vec<(SuiNetwork, SuiSource)>,
+    /// Background tasks polling the sources, returning them once stopped
+    tasks: Vec<JoinHandle<(SuiNetwork, SuiSource)>>,
+    /// Merged polls of all networks
+    receiver: Option<mpsc::Receiver<NetworkPoll>>,
+    /// Signals the background tasks to stop
+    shutdown: watch::Sender<bool>,
+}
+
+impl SuiMultiNetworkSource {
+    /// Creates a new SuiMultiNetworkSource instance
+    ///
+    /// # Parameters
+    /// * `networks` - Networks to poll, e.g. mainnet and testnet or several custom endpoints
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `max_transactions` - Maximum number of transactions to fetch per poll and network
+    pub fn new(networks: Vec<SuiNetwork>, interval_ms: u64, max_transactions: usize) -> Self {
+        networks
+            .into_iter()
+            .fold(Self::default(), |source, network| {
+                let rpc_url = network.rpc_url().to_string();
+                source.with_source(
+                    network,
+                    SuiSource::new(rpc_url, interval_ms, max_transactions),
+                )
+            })
+    }
+
+    /// Adds a network polled by a configured source
+    ///
+    /// The source should be built for the same network, e.g. with
+    /// [`crate::SuiSourceBuilder::network`].
+    pub fn with_source(mut self, network: SuiNetwork, source: SuiSource) -> Self {
+        self.networks.push(network.clone());
+        self.sources.push((network, source));
+        self
+    }
+
+    /// Networks merged by the source
+    pub fn networks(&self) -> &[SuiNetwork] {
+        &self.networks
+    }
+
+    /// Polls a source until it is finished or shut down, forwarding every poll
+    fn spawn(
+        network: SuiNetwork,
+        mut source: SuiSource,
+        sender: mpsc::Sender<NetworkPoll>,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> JoinHandle<(SuiNetwork, SuiSource)> {
+        tokio::spawn(async move {
+            while !source.is_finished() {
+                let poll = tokio::select! {
+                    _ = shutdown.changed() => break,
+                    poll = source.next() => poll,
+                };
+
+                let poll = poll.map(|record| {
+                    record.map(|record| {
+                        let event = SuiNetworkEvent {
+                            network: network.clone(),
+                            event: record.data,
+                        };
+                        Record::with_timestamp(event, record.timestamp)
+                    })
+                });
+                if let Err(e) = &poll {
+                    tracing::warn!("Failed to poll Sui network {}: {}", network, e);
+                }
+                if sender.send(poll).await.is_err() {
+                    break;
+                }
+            }
+            (network, source)
+        })
+    }
+}
+
+impl Default for SuiMultiNetworkSource {
+    fn default() -> Self {
+        Self {
+            networks: Vec::new(),
+            sources: Vec::new(),
+            tasks: Vec::new(),
+            receiver: None,
+            shutdown: watch::channel(false).0,
+        }
+    }
+}
+
+impl fmt::Debug for SuiMultiNetworkSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SuiMultiNetworkSource")
+            .field("networks", &self.networks())
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl Source<SuiNetworkEvent> for SuiMultiNetworkSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.receiver.is_some() {
+            return Ok(());
+        }
+        if self.sources.is_empty() {
+            return Err(StreamError::Config(
+                "SuiMultiNetworkSource requires at least one network".to_string(),
+            ));
+        }
+
+        for (network, source) in &mut self.sources {
+            source.init().await.inspect_err(|e| {
+                tracing::error!("Failed to initialize Sui network {}: {}", network, e);
+            })?;
+        }
+
+        let (sender, receiver) = mpsc::channel(self.sources.len());
+        self.shutdown.send_replace(false);
+        self.tasks = self
+            .sources
+            .drain(..)
+            .map(|(network, source)| {
+                Self::spawn(network, source, sender.clone(), self.shutdown.subscribe())
+            })
+            .collect();
+        self.receiver = Some(receiver);
+        tracing::info!(
+            "SuiMultiNetworkSource initialized with {} networks",
+            self.tasks.len()
+        );
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiNetworkEvent>>> {
+        let Some(receiver) = &mut self.receiver else {
+            return Err(StreamError::Runtime(
+                "SuiMultiNetworkSource not initialized".to_string(),
+            ));
+        };
+
+        // Every network finished once all senders are dropped
+        receiver.recv().await.unwrap_or(Ok(None))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.shutdown.send_replace(true);
+        self.receiver = None;
+
+        // Sources are kept so the source can be initialized again
+        let mut result = Ok(());
+        for task in std::mem::take(&mut self.tasks) {
+            let (network, mut source) = task
+                .await
+                .map_err(|e| StreamError::Runtime(format!("Network task failed: {}", e)))?;
+            if let Err(e) = source.close().await {
+                result = Err(e);
+            }
+            self.sources.push((network, source));
+        }
+        tracing::info!("SuiMultiNetworkSource closed");
+        result
+    }
+}
@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use sui_sdk::rpc_types::EventFilter;
+use sui_sdk::types::base_types::ObjectID;
+
+use crate::{SuiEventSource, SuiMoveEvent};
+
+/// SuiNS package on Sui Mainnet
+const SUINS_MAINNET_PACKAGE: &str =
+    "0xd22b24490e0bae52676651b4f56660a5ff8022a2576e0089f79b3c88d44e08f0";
+
+/// Kind of SuiNS activity
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum NameServiceAction {
+    /// Name was registered
+    Registered,
+    /// Name registration was renewed
+    Renewed,
+    /// Name was transferred to a new owner
+    Transferred,
+    /// Target address of the name was set
+    TargetAddressSet,
+    /// Any other SuiNS event
+    Other,
+}
+
+/// Decoded SuiNS activity
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiNameServiceEvent {
+    /// Digest of the emitting transaction
+    pub transaction_digest: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Kind of activity
+    pub action: NameServiceAction,
+    /// Fully qualified Move type of the underlying event
+    pub event_type: String,
+    /// Domain name, e.g. `example.sui` (if present)
+    pub name: Option<String>,
+    /// Owner of the name
+    pub owner: String,
+    /// Expiration timestamp in milliseconds (if present)
+    pub expiry: Option<u64>,
+    /// Address the name resolves to (if present)
+    pub target_address: Option<String>,
+}
+
+impl From<SuiMoveEvent> for SuiNameServiceEvent {
+    fn from(event: SuiMoveEvent) -> Self {
+        let event_name = event.event_name();
+        let action = if event_name.contains("Register") {
+            NameServiceAction::Registered
+        } else if event_name.contains("Renew") || event_name.contains("Extend") {
+            NameServiceAction::Renewed
+        } else if event_name.contains("Transfer") {
+            NameServiceAction::Transferred
+        } else if event_name.contains("Target") {
+            NameServiceAction::TargetAddressSet
+        } else {
+            NameServiceAction::Other
+        };
+
+        // Domains are either plain strings or label lists stored TLD first
+        let name = ["domain_name", "domain", "name"]
+            .iter()
+            .find_map(|field| event.parsed_json.get(*field))
+            .and_then(|domain| match domain {
+                serde_json::Value::String(name) => Some(name.clone()),
+                _ => domain.get("labels")?.as_array().map(|labels| {
+                    labels
+                        .iter()
+                        .rev()
+                        .filter_map(|label| label.as_str())
+                        .collect::<Vec<_>>()
+                        .join(".")
+                }),
+            });
+
+        let owner = event
+            .field_str("owner")
+            .unwrap_or_else(|| event.sender.clone());
+        let expiry = ["expiration_timestamp_ms", "expiry"]
+            .iter()
+            .find_map(|field| event.field_u64(field));
+        let target_address = ["target_address", "new_target", "target"]
+            .iter()
+            .find_map(|field| event.field_str(field));
+
+        Self {
+            transaction_digest: event.transaction_digest,
+            timestamp: event.timestamp,
+            action,
+            event_type: event.event_type,
+            name,
+            owner,
+            expiry,
+            target_address,
+        }
+    }
+}
+
+/// Sui data source emitting decoded SuiNS registrations, renewals and transfers
+pub struct SuiNameServiceSource {
+    /// Wrapped event source scoped to the SuiNS package
+    source: SuiEventSource,
+}
+
+impl SuiNameServiceSource {
+    /// Creates a new SuiNameServiceSource for the SuiNS package on Mainnet
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        let package = ObjectID::from_str(SUINS_MAINNET_PACKAGE).expect("SuiNS package ID is valid");
+        Self::with_package(rpc_url, package, interval_ms)
+    }
+
+    /// Creates a new SuiNameServiceSource for a specific SuiNS package, e.g. on Testnet
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `package` - SuiNS package ID
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn with_package(rpc_url: String, package: ObjectID, interval_ms: u64) -> Self {
+        Self {
+            source: SuiEventSource::new(rpc_url, EventFilter::Package(package), interval_ms, 50),
+        }
+    }
+}
+
+#[async_trait]
+impl Source<SuiNameServiceEvent> for SuiNameServiceSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiNameServiceEvent>>> {
+        Ok(self
+            .source
+            .next_event()
+            .await?
+            .map(|event| Record::new(SuiNameServiceEvent::from(event))))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.source.close().await
+    }
+}
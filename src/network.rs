@@ -0,0 +1,100 @@
+use fluxus::utils::models::StreamError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Sui network to connect to
+///
+/// Well-known networks resolve to the public Mysten Labs endpoints, custom
+/// networks to a user-provided fullnode RPC URL.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuiNetwork {
+    /// Sui Mainnet
+    #[default]
+    Mainnet,
+    /// Sui Testnet
+    Testnet,
+    /// Sui Devnet
+    Devnet,
+    /// Local network started with `sui start`
+    Localnet,
+    /// Any other network, identified by its fullnode RPC URL
+    Custom(String),
+}
+
+impl SuiNetwork {
+    /// Fullnode JSON-RPC endpoint URL
+    pub fn rpc_url(&self) -> &str {
+        match self {
+            Self::Mainnet => "https://fullnode.mainnet.sui.io:443",
+            Self::Testnet => "https://fullnode.testnet.sui.io:443",
+            Self::Devnet => "https://fullnode.devnet.sui.io:443",
+            Self::Localnet => "http://127.0.0.1:9000",
+            Self::Custom(rpc_url) => rpc_url,
+        }
+    }
+
+    /// Fullnode WebSocket endpoint URL used for subscriptions
+    pub fn ws_url(&self) -> String {
+        let rpc_url = self.rpc_url();
+        match rpc_url.split_once("://") {
+            Some(("https", rest)) => format!("wss://{}", rest),
+            Some(("http", rest)) => format!("ws://{}", rest),
+            _ => rpc_url.to_string(),
+        }
+    }
+
+    /// GraphQL endpoint URL, not known for custom networks
+    pub fn graphql_url(&self) -> Option<&str> {
+        match self {
+            Self::Mainnet => Some("https://sui-mainnet.mystenlabs.com/graphql"),
+            Self::Testnet => Some("https://sui-testnet.mystenlabs.com/graphql"),
+            Self::Devnet => Some("https://sui-devnet.mystenlabs.com/graphql"),
+            Self::Localnet => Some("http://127.0.0.1:9125/graphql"),
+            Self::Custom(_) => None,
+        }
+    }
+
+    /// Remote checkpoint bucket URL, only published for Mainnet and Testnet
+    pub fn checkpoint_bucket_url(&self) -> Option<&str> {
+        match self {
+            Self::Mainnet => Some("https://checkpoints.mainnet.sui.io"),
+            Self::Testnet => Some("https://checkpoints.testnet.sui.io"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SuiNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mainnet => write!(f, "mainnet"),
+            Self::Testnet => write!(f, "testnet"),
+            Self::Devnet => write!(f, "devnet"),
+            Self::Localnet => write!(f, "localnet"),
+            Self::Custom(rpc_url) => write!(f, "{}", rpc_url),
+        }
+    }
+}
+
+impl FromStr for SuiNetwork {
+    type Err = StreamError;
+
+    /// Parses a network name, or an `http(s)://` URL as a custom network
+    fn from_str(network: &str) -> Result<Self, Self::Err> {
+        match network.to_ascii_lowercase().as_str() {
+            "mainnet" => Ok(Self::Mainnet),
+            "testnet" => Ok(Self::Testnet),
+            "devnet" => Ok(Self::Devnet),
+            "localnet" | "local" => Ok(Self::Localnet),
+            _ if network.starts_with("http://") || network.starts_with("https://") => {
+                Ok(Self::Custom(network.to_string()))
+            }
+            _ => Err(StreamError::Config(format!(
+                "Unknown Sui network: {}",
+                network
+            ))),
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use sui_sdk::rpc_types::{
+    ObjectChange, SuiObjectDataOptions, SuiTransactionBlockDataAPI, SuiTransactionBlockResponse,
+};
+use sui_sdk::types::base_types::{ObjectID, SuiAddress};
+use sui_sdk::types::object::Owner;
+
+use crate::SuiSource;
+use crate::derived::{self, DerivedBuffer, WrappingSource};
+use crate::hydrate::MULTI_GET_LIMIT;
+use crate::retry::rpc_error;
+
+/// Kind of NFT activity
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum NftAction {
+    /// NFT was created
+    Minted,
+    /// NFT moved to a new owner
+    Transferred,
+}
+
+/// NFT mint or transfer enriched with the object's Display metadata
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiNftTransfer {
+    /// Digest of the transaction
+    pub transaction_digest: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Kind of activity
+    pub action: NftAction,
+    /// NFT object ID
+    pub object_id: String,
+    /// Move type of the NFT
+    pub object_type: String,
+    /// Object version after the transfer
+    pub version: u64,
+    /// Sender of the transaction
+    pub sender: String,
+    /// New owner of the NFT
+    pub recipient: String,
+    /// Display name (if set)
+    pub name: Option<String>,
+    /// Display description (if set)
+    pub description: Option<String>,
+    /// Display image URL (if set)
+    pub image_url: Option<String>,
+    /// Collection, the Display `collection` field or the NFT's type without type parameters
+    pub collection: String,
+    /// All rendered Display fields
+    pub display: BTreeMap<String, String>,
+}
+
+/// NFT candidate detected in the object changes of a transaction
+struct NftCandidate {
+    action: NftAction,
+    object_id: ObjectID,
+    object_type: String,
+    collection: String,
+    version: u64,
+    sender: SuiAddress,
+    recipient: SuiAddress,
+}
+
+/// Sui data source emitting NFT mints and transfers
+///
+/// Wraps a [`SuiSource`] and inspects the object changes of its transactions
+/// for address-owned objects that were created or changed hands. Objects
+/// without Display metadata, such as coins, are not considered NFTs and are
+/// skipped.
+///
+/// Display metadata is fetched when the transaction is processed and reflects
+/// the current state of the object, not its state at the transaction's
+/// version, so it may differ from what was rendered at the time when
+/// replaying history. Objects deleted since have no Display and are skipped.
+pub struct SuiNftTransferSource {
+    /// Wrapped transaction source
+    source: SuiSource,
+    /// Transaction whose Display metadata failed to resolve, retried first
+    pending: Option<SuiTransactionBlockResponse>,
+    /// Detected transfers waiting to be emitted
+    buffer: DerivedBuffer<SuiNftTransfer>,
+}
+
+impl SuiNftTransferSource {
+    /// Creates a new SuiNftTransferSource instance
+    ///
+    /// # Parameters
+    /// * `source` - Source providing the RPC endpoint and transaction filters
+    pub fn new(mut source: SuiSource) -> Self {
+        source.options.show_input = true;
+        source.options.show_object_changes = true;
+        Self {
+            source,
+            pending: None,
+            buffer: DerivedBuffer::new(),
+        }
+    }
+
+    /// Emits the transfers of the transactions fetched but not emitted yet,
+    /// without polling for more, see [`SuiSource::drain`]
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiNftTransfer>>> {
+        derived::drain(self).await
+    }
+
+    /// Extracts objects that were minted to or transferred to an address
+    fn candidates(transaction: &SuiTransactionBlockResponse) -> Vec<NftCandidate> {
+        let mut candidates = Vec::new();
+        for change in transaction.object_changes.iter().flatten() {
+            let (action, sender, owner, object_type) = match change {
+                ObjectChange::Created {
+                    sender,
+                    owner,
+                    object_type,
+                    ..
+                } => (NftAction::Minted, sender, owner, object_type),
+                ObjectChange::Transferred {
+                    sender,
+                    recipient,
+                    object_type,
+                    ..
+                } => (NftAction::Transferred, sender, recipient, object_type),
+                // Mutated objects owned by someone other than the sender were sent away
+                ObjectChange::Mutated {
+                    sender,
+                    owner,
+                    object_type,
+                    ..
+                } if !matches!(owner, Owner::AddressOwner(address) if address == sender) => {
+                    (NftAction::Transferred, sender, owner, object_type)
+                }
+                _ => continue,
+            };
+
+            // Coins move with almost every transaction and never carry Display
+            if object_type.module.as_str() == "coin" && object_type.name.as_str() == "Coin" {
+                continue;
+            }
+            let Owner::AddressOwner(recipient) = owner else {
+                continue;
+            };
+
+            let (object_id, version, _) = change.object_ref();
+            candidates.push(NftCandidate {
+                action,
+                object_id,
+                object_type: object_type.to_string(),
+                collection: format!(
+                    "{}::{}::{}",
+                    object_type.address.to_hex_literal(),
+                    object_type.module,
+                    object_type.name
+                ),
+                version: version.value(),
+                sender: *sender,
+                recipient: *recipient,
+            });
+        }
+        candidates
+    }
+
+    /// Resolves the Display metadata of the candidates, skipping objects without one
+    ///
+    /// Nothing is returned unless every request succeeds.
+    async fn resolve(
+        &mut self,
+        transaction: &SuiTransactionBlockResponse,
+        candidates: Vec<NftCandidate>,
+    ) -> StreamResult<Vec<SuiNftTransfer>> {
+        let client = self.source.client().ok_or_else(|| {
+            StreamError::Runtime("SuiNftTransferSource client not available".to_string())
+        })?;
+
+        let object_ids: Vec<ObjectID> = candidates
+            .iter()
+            .map(|candidate| candidate.object_id)
+            .collect();
+        let mut displays: HashMap<ObjectID, BTreeMap<String, String>> = HashMap::new();
+        for object_ids in object_ids.chunks(MULTI_GET_LIMIT) {
+            self.source.throttle().await;
+            let responses = client
+                .read_api()
+                .multi_get_object_with_options(
+                    object_ids.to_vec(),
+                    SuiObjectDataOptions::new().with_display(),
+                )
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to fetch Display metadata: {}", e);
+                    rpc_error(e)
+                })?;
+            displays.extend(
+                responses
+                    .into_iter()
+                    .filter_map(|response| response.data)
+                    .filter_map(|object| Some((object.object_id, object.display?.data?))),
+            );
+        }
+
+        let mut transfers = Vec::new();
+        for candidate in candidates {
+            let Some(display) = displays.remove(&candidate.object_id) else {
+                continue;
+            };
+
+            transfers.push(SuiNftTransfer {
+                transaction_digest: transaction.digest.to_string(),
+                timestamp: transaction.timestamp_ms.unwrap_or(0),
+                action: candidate.action,
+                object_id: candidate.object_id.to_string(),
+                object_type: candidate.object_type,
+                version: candidate.version,
+                sender: candidate.sender.to_string(),
+                recipient: candidate.recipient.to_string(),
+                name: display.get("name").cloned(),
+                description: display.get("description").cloned(),
+                image_url: display.get("image_url").cloned(),
+                collection: display
+                    .get("collection")
+                    .cloned()
+                    .unwrap_or(candidate.collection),
+                display,
+            });
+        }
+
+        Ok(transfers)
+    }
+}
+
+impl WrappingSource<SuiNftTransfer> for SuiNftTransferSource {
+    fn inner(&mut self) -> &mut SuiSource {
+        &mut self.source
+    }
+
+    fn has_buffered(&self) -> bool {
+        !self.buffer.is_empty() || self.pending.is_some()
+    }
+}
+
+#[async_trait]
+impl Source<SuiNftTransfer> for SuiNftTransferSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiNftTransfer>>> {
+        // Most transactions move no NFTs and leave the buffer empty
+        if self.buffer.is_empty() {
+            let transaction = match self.pending.take() {
+                Some(transaction) => transaction,
+                None => match self.source.next_transaction().await? {
+                    Some(transaction) => transaction,
+                    None => return Ok(None),
+                },
+            };
+
+            let candidates = Self::candidates(&transaction);
+            let transfers = if candidates.is_empty() {
+                Vec::new()
+            } else {
+                match self.resolve(&transaction, candidates).await {
+                    Ok(transfers) => transfers,
+                    Err(e) => {
+                        // Already taken from the wrapped source, so keep it for the next call
+                        self.pending = Some(transaction);
+                        return Err(e);
+                    }
+                }
+            };
+            self.buffer.fill(transaction, transfers);
+        }
+
+        Ok(self.buffer.pop().map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        if let Some(transaction) = self.pending.take() {
+            self.source.requeue(transaction);
+        }
+        self.buffer.close(&mut self.source);
+        self.source.close().await
+    }
+}
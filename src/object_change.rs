@@ -0,0 +1,235 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use sui_sdk::rpc_types::{ObjectChange, SuiTransactionBlockResponse};
+use sui_sdk::types::object::Owner;
+
+use crate::SuiSource;
+use crate::derived::{self, DerivedBuffer, WrappingSource};
+
+/// Kind of object state transition
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectChangeKind {
+    /// Object was created
+    Created,
+    /// Object was mutated in place
+    Mutated,
+    /// Object was transferred to a new owner
+    Transferred,
+    /// Object was wrapped into another object
+    Wrapped,
+    /// Object was deleted
+    Deleted,
+    /// Package was published
+    Published,
+}
+
+/// Object state transition extracted from transaction effects
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiObjectChange {
+    /// Digest of the transaction that changed the object
+    pub transaction_digest: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Checkpoint of the transaction (if known)
+    pub checkpoint: Option<u64>,
+    /// Kind of change
+    pub change_type: ObjectChangeKind,
+    /// Object ID
+    pub object_id: String,
+    /// Move type of the object (not set for packages)
+    pub object_type: Option<String>,
+    /// Object version after the change
+    pub version: u64,
+    /// Owner after the change (if applicable)
+    pub owner: Option<String>,
+    /// Sender of the transaction (not set for packages)
+    pub sender: Option<String>,
+}
+
+/// Object state transition embedded in [`crate::SuiEvent`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ObjectChangeSummary {
+    /// Kind of change
+    pub change_type: ObjectChangeKind,
+    /// Object ID
+    pub object_id: String,
+    /// Move type of the object (not set for packages)
+    pub object_type: Option<String>,
+    /// Object version after the change
+    pub version: u64,
+    /// Owner after the change (if applicable)
+    pub owner: Option<String>,
+}
+
+impl From<SuiObjectChange> for ObjectChangeSummary {
+    fn from(change: SuiObjectChange) -> Self {
+        Self {
+            change_type: change.change_type,
+            object_id: change.object_id,
+            object_type: change.object_type,
+            version: change.version,
+            owner: change.owner,
+        }
+    }
+}
+
+/// Formats an owner as its address, or its kind for shared and immutable objects
+pub(crate) fn owner_to_string(owner: &Owner) -> String {
+    owner
+        .get_owner_address()
+        .map(|address| address.to_string())
+        .unwrap_or_else(|_| owner.to_string())
+}
+
+impl SuiObjectChange {
+    /// Builds an object change record from a transaction's object change
+    pub(crate) fn new(transaction: &SuiTransactionBlockResponse, change: &ObjectChange) -> Self {
+        let (change_type, object_type, owner, sender) = match change {
+            ObjectChange::Created {
+                sender,
+                owner,
+                object_type,
+                ..
+            } => (
+                ObjectChangeKind::Created,
+                Some(object_type.to_string()),
+                Some(owner_to_string(owner)),
+                Some(sender.to_string()),
+            ),
+            ObjectChange::Mutated {
+                sender,
+                owner,
+                object_type,
+                ..
+            } => (
+                ObjectChangeKind::Mutated,
+                Some(object_type.to_string()),
+                Some(owner_to_string(owner)),
+                Some(sender.to_string()),
+            ),
+            ObjectChange::Transferred {
+                sender,
+                recipient,
+                object_type,
+                ..
+            } => (
+                ObjectChangeKind::Transferred,
+                Some(object_type.to_string()),
+                Some(owner_to_string(recipient)),
+                Some(sender.to_string()),
+            ),
+            ObjectChange::Wrapped {
+                sender,
+                object_type,
+                ..
+            } => (
+                ObjectChangeKind::Wrapped,
+                Some(object_type.to_string()),
+                None,
+                Some(sender.to_string()),
+            ),
+            ObjectChange::Deleted {
+                sender,
+                object_type,
+                ..
+            } => (
+                ObjectChangeKind::Deleted,
+                Some(object_type.to_string()),
+                None,
+                Some(sender.to_string()),
+            ),
+            ObjectChange::Published { .. } => (ObjectChangeKind::Published, None, None, None),
+        };
+
+        let (object_id, version, _) = change.object_ref();
+        Self {
+            transaction_digest: transaction.digest.to_string(),
+            timestamp: transaction.timestamp_ms.unwrap_or(0),
+            checkpoint: transaction.checkpoint,
+            change_type,
+            object_id: object_id.to_string(),
+            object_type,
+            version: version.value(),
+            owner,
+            sender,
+        }
+    }
+}
+
+/// Sui data source emitting one record per object change
+///
+/// Wraps a [`SuiSource`], whose filters decide which transactions are
+/// inspected, and flattens each transaction's object changes into records.
+pub struct SuiObjectChangeSource {
+    /// Wrapped transaction source
+    source: SuiSource,
+    /// Extracted object changes waiting to be emitted
+    buffer: DerivedBuffer<SuiObjectChange>,
+}
+
+impl SuiObjectChangeSource {
+    /// Creates a new SuiObjectChangeSource instance
+    ///
+    /// # Parameters
+    /// * `source` - Source providing the RPC endpoint and transaction filters
+    pub fn new(mut source: SuiSource) -> Self {
+        source.options.show_object_changes = true;
+        Self {
+            source,
+            buffer: DerivedBuffer::new(),
+        }
+    }
+
+    /// Emits the object changes of the transactions fetched but not emitted
+    /// yet, without polling for more, see [`SuiSource::drain`]
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiObjectChange>>> {
+        derived::drain(self).await
+    }
+}
+
+impl WrappingSource<SuiObjectChange> for SuiObjectChangeSource {
+    fn inner(&mut self) -> &mut SuiSource {
+        &mut self.source
+    }
+
+    fn has_buffered(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+}
+
+#[async_trait]
+impl Source<SuiObjectChange> for SuiObjectChangeSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiObjectChange>>> {
+        // Transactions without object changes leave the buffer empty
+        if self.buffer.is_empty() {
+            let Some(transaction) = self.source.next_transaction().await? else {
+                return Ok(None);
+            };
+
+            let changes: Vec<SuiObjectChange> = transaction
+                .object_changes
+                .iter()
+                .flatten()
+                .map(|change| SuiObjectChange::new(&transaction, change))
+                .collect();
+            self.buffer.fill(transaction, changes);
+        }
+
+        Ok(self.buffer.pop().map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.buffer.close(&mut self.source);
+        self.source.close().await
+    }
+}
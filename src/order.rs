@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// Order in which a [`crate::SuiSource`] walks the transaction history
+///
+/// Checkpoint and time ranges are always ingested in ascending order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuiQueryOrder {
+    /// Start at the newest transactions and follow new ones as they land
+    ///
+    /// Without a cursor or start checkpoint the first page is queried in
+    /// descending order and emitted reversed, every record is chronological.
+    #[default]
+    Latest,
+    /// Walk forward from genesis, or from the cursor or start checkpoint,
+    /// emitting strictly from oldest to newest
+    Ascending,
+    /// Walk backward from the newest transactions, or from the cursor, until
+    /// genesis is reached, emitting from newest to oldest
+    Descending,
+}
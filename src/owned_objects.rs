@@ -0,0 +1,246 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::{SuiObjectData, SuiObjectDataOptions, SuiObjectResponseQuery};
+use sui_sdk::types::base_types::{ObjectID, SuiAddress};
+use tokio::time::sleep;
+
+use crate::retry::{connect_client, now_millis};
+
+/// Maximum number of objects requested per page
+const PAGE_SIZE: usize = 50;
+
+/// Kind of inventory change
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OwnedObjectAction {
+    /// Address started owning the object
+    Gained,
+    /// Address no longer owns the object
+    Lost,
+    /// Object is still owned but its version changed
+    VersionChanged,
+}
+
+/// Change to the objects owned by a watched address
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiOwnedObjectChange {
+    /// Watched address
+    pub owner: String,
+    /// Kind of change
+    pub action: OwnedObjectAction,
+    /// Object ID
+    pub object_id: String,
+    /// Object type (if known)
+    pub object_type: Option<String>,
+    /// Object version, the last known version for lost objects
+    pub version: u64,
+    /// Version before the change, not set for gained objects
+    pub previous_version: Option<u64>,
+    /// Time the change was observed
+    pub timestamp: u64,
+}
+
+/// Sui data source tracking the objects owned by a watchlist of addresses
+///
+/// The first poll records the current inventories as a baseline. Every later
+/// poll emits a record for each object gained, lost or modified.
+pub struct SuiOwnedObjectsSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Watched addresses
+    addresses: Vec<SuiAddress>,
+    /// Polling interval
+    interval: Duration,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Objects of the last snapshot per address
+    inventories: Option<HashMap<SuiAddress, HashMap<ObjectID, SuiObjectData>>>,
+    /// Detected changes waiting to be emitted
+    buffer: VecDeque<SuiOwnedObjectChange>,
+}
+
+impl SuiOwnedObjectsSource {
+    /// Creates a new SuiOwnedObjectsSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `addresses` - Addresses whose objects are tracked
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, addresses: Vec<SuiAddress>, interval_ms: u64) -> Self {
+        Self {
+            rpc_url,
+            addresses,
+            interval: Duration::from_millis(interval_ms),
+            client: None,
+            inventories: None,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Fetches all objects owned by an address, following pagination
+    async fn fetch_objects(
+        &self,
+        address: SuiAddress,
+    ) -> StreamResult<HashMap<ObjectID, SuiObjectData>> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiOwnedObjectsSource client not available".to_string())
+        })?;
+
+        let query =
+            SuiObjectResponseQuery::new_with_options(SuiObjectDataOptions::new().with_type());
+        let mut objects = HashMap::new();
+        let mut cursor = None;
+        loop {
+            let page = client
+                .read_api()
+                .get_owned_objects(address, Some(query.clone()), cursor, Some(PAGE_SIZE))
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to fetch objects owned by {}: {}", address, e);
+                    StreamError::Runtime(e.to_string())
+                })?;
+
+            objects.extend(
+                page.data
+                    .into_iter()
+                    .filter_map(|response| response.data)
+                    .map(|object| (object.object_id, object)),
+            );
+
+            if !page.has_next_page || page.next_cursor.is_none() {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        Ok(objects)
+    }
+
+    /// Takes a new snapshot of every address and buffers the differences
+    async fn poll(&mut self) -> StreamResult<()> {
+        let mut current = HashMap::new();
+        for address in self.addresses.clone() {
+            current.insert(address, self.fetch_objects(address).await?);
+        }
+
+        // The first snapshot is the baseline, not a change
+        let Some(previous) = self.inventories.take() else {
+            self.inventories = Some(current);
+            return Ok(());
+        };
+
+        let timestamp = now_millis();
+        let change = |owner: &SuiAddress,
+                      action: OwnedObjectAction,
+                      object: &SuiObjectData,
+                      previous_version: Option<u64>| SuiOwnedObjectChange {
+            owner: owner.to_string(),
+            action,
+            object_id: object.object_id.to_string(),
+            object_type: object
+                .type_
+                .as_ref()
+                .map(|object_type| object_type.to_string()),
+            version: object.version.value(),
+            previous_version,
+            timestamp,
+        };
+
+        let empty = HashMap::new();
+        for (owner, objects) in &current {
+            let old_objects = previous.get(owner).unwrap_or(&empty);
+
+            for (object_id, object) in objects {
+                match old_objects.get(object_id) {
+                    None => self.buffer.push_back(change(
+                        owner,
+                        OwnedObjectAction::Gained,
+                        object,
+                        None,
+                    )),
+                    Some(old) if old.version != object.version => self.buffer.push_back(change(
+                        owner,
+                        OwnedObjectAction::VersionChanged,
+                        object,
+                        Some(old.version.value()),
+                    )),
+                    Some(_) => {}
+                }
+            }
+
+            for (object_id, object) in old_objects {
+                if !objects.contains_key(object_id) {
+                    self.buffer.push_back(change(
+                        owner,
+                        OwnedObjectAction::Lost,
+                        object,
+                        Some(object.version.value()),
+                    ));
+                }
+            }
+        }
+
+        self.inventories = Some(current);
+        Ok(())
+    }
+
+    /// Emits the object changes detected but not emitted yet, without taking a new snapshot
+    ///
+    /// Call before `close` to flush in-flight records on shutdown.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiOwnedObjectChange>>> {
+        Ok(self.buffer.drain(..).map(Record::new).collect())
+    }
+}
+
+#[async_trait]
+impl Source<SuiOwnedObjectChange> for SuiOwnedObjectsSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_client(&self.rpc_url).await?;
+
+        self.client = Some(client);
+        tracing::info!(
+            "SuiOwnedObjectsSource initialized with RPC URL: {} watching {} addresses",
+            self.rpc_url,
+            self.addresses.len()
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiOwnedObjectChange>>> {
+        if self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiOwnedObjectsSource not initialized".to_string(),
+            ));
+        }
+
+        if self.buffer.is_empty() {
+            // Take the baseline right away
+            if self.inventories.is_some() {
+                sleep(self.interval).await;
+            }
+            self.poll().await?;
+        }
+
+        Ok(self.buffer.pop_front().map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        // Buffered changes are kept, the snapshots they were diffed
+        // against already include them
+        self.client = None;
+        tracing::info!("SuiOwnedObjectsSource closed");
+        Ok(())
+    }
+}
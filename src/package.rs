@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use sui_sdk::rpc_types::{ObjectChange, SuiTransactionBlockDataAPI};
+
+use crate::SuiSource;
+use crate::derived::{self, DerivedBuffer, WrappingSource};
+
+/// Package published or upgraded on chain
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiPackagePublish {
+    /// Digest of the publishing transaction
+    pub transaction_digest: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Package ID
+    pub package_id: String,
+    /// Address that published the package
+    pub publisher: String,
+    /// Names of the modules in the package
+    pub modules: Vec<String>,
+    /// Package version, 1 for fresh publishes
+    pub version: u64,
+    /// Whether this is an upgrade of an existing package
+    pub is_upgrade: bool,
+}
+
+/// Sui data source emitting a record for every package publish or upgrade
+///
+/// Wraps a [`SuiSource`] and inspects the object changes of its transactions
+/// for published packages.
+pub struct SuiPackagePublishSource {
+    /// Wrapped transaction source
+    source: SuiSource,
+    /// Detected packages waiting to be emitted
+    buffer: DerivedBuffer<SuiPackagePublish>,
+}
+
+impl SuiPackagePublishSource {
+    /// Creates a new SuiPackagePublishSource instance
+    ///
+    /// # Parameters
+    /// * `source` - Source providing the RPC endpoint and transaction filters
+    pub fn new(mut source: SuiSource) -> Self {
+        source.options.show_input = true;
+        source.options.show_object_changes = true;
+        Self {
+            source,
+            buffer: DerivedBuffer::new(),
+        }
+    }
+
+    /// Emits the packages of the transactions fetched but not emitted yet,
+    /// without polling for more, see [`SuiSource::drain`]
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiPackagePublish>>> {
+        derived::drain(self).await
+    }
+}
+
+impl WrappingSource<SuiPackagePublish> for SuiPackagePublishSource {
+    fn inner(&mut self) -> &mut SuiSource {
+        &mut self.source
+    }
+
+    fn has_buffered(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+}
+
+#[async_trait]
+impl Source<SuiPackagePublish> for SuiPackagePublishSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiPackagePublish>>> {
+        // Most transactions publish nothing and leave the buffer empty
+        if self.buffer.is_empty() {
+            let Some(transaction) = self.source.next_transaction().await? else {
+                return Ok(None);
+            };
+
+            let publisher = transaction
+                .transaction
+                .as_ref()
+                .map(|tx| tx.data.sender().to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+
+            let mut packages = Vec::new();
+            for change in transaction.object_changes.iter().flatten() {
+                if let ObjectChange::Published {
+                    package_id,
+                    version,
+                    modules,
+                    ..
+                } = change
+                {
+                    tracing::info!("Package {} published by {}", package_id, publisher);
+                    packages.push(SuiPackagePublish {
+                        transaction_digest: transaction.digest.to_string(),
+                        timestamp: transaction.timestamp_ms.unwrap_or(0),
+                        package_id: package_id.to_string(),
+                        publisher: publisher.clone(),
+                        modules: modules.clone(),
+                        version: version.value(),
+                        is_upgrade: version.value() > 1,
+                    });
+                }
+            }
+            self.buffer.fill(transaction, packages);
+        }
+
+        Ok(self.buffer.pop().map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.buffer.close(&mut self.source);
+        self.source.close().await
+    }
+}
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use std::fmt;
+use std::future::Future;
+
+use crate::SuiEvent;
+
+/// Client-side condition events must meet to be emitted
+///
+/// Implementations with a synchronous check simply return without awaiting.
+#[async_trait]
+pub trait EventPredicate: fmt::Debug + Send + Sync {
+    /// Whether the event is emitted
+    async fn matches(&self, event: &SuiEvent) -> bool;
+}
+
+/// Event predicate calling a synchronous closure
+pub struct FilterFn<F>(pub F);
+
+impl<F> fmt::Debug for FilterFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FilterFn")
+    }
+}
+
+#[async_trait]
+impl<F> EventPredicate for FilterFn<F>
+where
+    F: Fn(&SuiEvent) -> bool + Send + Sync,
+{
+    async fn matches(&self, event: &SuiEvent) -> bool {
+        (self.0)(event)
+    }
+}
+
+/// Event predicate calling an asynchronous closure with a copy of the event
+pub struct AsyncFilterFn<F>(pub F);
+
+impl<F> fmt::Debug for AsyncFilterFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AsyncFilterFn")
+    }
+}
+
+#[async_trait]
+impl<F, Fut> EventPredicate for AsyncFilterFn<F>
+where
+    F: Fn(SuiEvent) -> Fut + Send + Sync,
+    Fut: Future<Output = bool> + Send,
+{
+    async fn matches(&self, event: &SuiEvent) -> bool {
+        (self.0)(event.clone()).await
+    }
+}
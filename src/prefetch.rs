@@ -0,0 +1,147 @@
+use fluxus::utils::models::{StreamError, StreamResult};
+use std::time::{Duration, Instant};
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::{SuiTransactionBlockResponseQuery, TransactionBlocksPage};
+use sui_sdk::types::digests::TransactionDigest;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+
+use crate::retry::{RetryStats, rpc_error};
+use crate::{AdaptiveInterval, RetryPolicy, SuiRateLimiter};
+
+/// Page fetched ahead of time by a [`Prefetcher`]
+pub(crate) struct PrefetchedPage {
+    /// Fetched page
+    pub(crate) page: TransactionBlocksPage,
+    /// Response time of the successful request
+    pub(crate) latency: Duration,
+    /// Retries and rate-limit responses of the request
+    pub(crate) stats: RetryStats,
+}
+
+/// Everything the background task needs to walk forward from a cursor
+pub(crate) struct PrefetchQuery {
+    /// Client of the active endpoint
+    pub(crate) client: SuiClient,
+    /// Transaction query
+    pub(crate) query: SuiTransactionBlockResponseQuery,
+    /// Cursor of the first page to fetch
+    pub(crate) cursor: Option<TransactionDigest>,
+    /// Page size
+    pub(crate) limit: usize,
+    /// Polling interval once caught up with the chain
+    pub(crate) interval: Duration,
+    /// Adaptive polling interval bounds
+    pub(crate) adaptive_interval: Option<AdaptiveInterval>,
+    /// Retry policy applied to every request
+    pub(crate) retry_policy: RetryPolicy,
+    /// Rate limiter applied to every request
+    pub(crate) rate_limiter: Option<SuiRateLimiter>,
+}
+
+/// Background task fetching pages ahead of the consumer
+///
+/// Pages depend on the cursor of the previous one, so they are fetched one
+/// after the other into a channel holding up to `depth` pages. The task stops
+/// after the first failed request, or when the prefetcher is dropped.
+pub(crate) struct Prefetcher {
+    /// Pages fetched ahead of time
+    receiver: mpsc::Receiver<StreamResult<PrefetchedPage>>,
+    /// Background task fetching the pages
+    task: JoinHandle<()>,
+}
+
+impl Prefetcher {
+    /// Spawns the background task fetching up to `depth` pages ahead
+    pub(crate) fn spawn(depth: usize, query: PrefetchQuery) -> Self {
+        let (sender, receiver) = mpsc::channel(depth.max(1));
+        let task = tokio::spawn(async move {
+            let PrefetchQuery {
+                client,
+                query,
+                mut cursor,
+                limit,
+                mut interval,
+                adaptive_interval,
+                retry_policy,
+                rate_limiter,
+            } = query;
+
+            loop {
+                let mut stats = RetryStats::default();
+                let started = Instant::now();
+                let result = retry_policy
+                    .run("Transaction prefetch", &mut stats, || {
+                        let (client, query, rate_limiter) = (&client, query.clone(), &rate_limiter);
+                        async move {
+                            if let Some(rate_limiter) = rate_limiter {
+                                rate_limiter.acquire().await;
+                            }
+                            client
+                                .read_api()
+                                .query_transaction_blocks(query, cursor, Some(limit), false)
+                                .await
+                        }
+                    })
+                    .await;
+
+                let page = match result {
+                    Ok(page) => page,
+                    Err(e) => {
+                        tracing::error!("Failed to prefetch transactions: {}", e);
+                        let _ = sender.send(Err(rpc_error(e))).await;
+                        return;
+                    }
+                };
+
+                let (fetched, has_next_page) = (page.data.len(), page.has_next_page);
+                if let Some(latest_transaction) = page.data.last() {
+                    cursor = Some(latest_transaction.digest);
+                }
+                let prefetched = PrefetchedPage {
+                    page,
+                    latency: started.elapsed(),
+                    stats,
+                };
+                if sender.send(Ok(prefetched)).await.is_err() {
+                    return;
+                }
+
+                if let Some(adaptive_interval) = &adaptive_interval {
+                    interval = adaptive_interval.next_interval(interval, fetched, limit);
+                }
+                // Polling interval, skipped while catching up on pending pages
+                if !has_next_page {
+                    sleep(interval).await;
+                }
+            }
+        });
+
+        Self { receiver, task }
+    }
+
+    /// Waits for the next prefetched page
+    pub(crate) async fn next_page(&mut self) -> StreamResult<PrefetchedPage> {
+        self.receiver.recv().await.unwrap_or_else(|| {
+            Err(StreamError::Runtime(
+                "Prefetch task stopped unexpectedly".to_string(),
+            ))
+        })
+    }
+}
+
+impl Prefetcher {
+    /// Stops the background task and waits until it has exited
+    pub(crate) async fn shutdown(mut self) {
+        self.task.abort();
+        // The task only ends by being aborted, its result carries nothing
+        let _ = (&mut self.task).await;
+    }
+}
+
+impl Drop for Prefetcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
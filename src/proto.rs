@@ -0,0 +1,337 @@
+use fluxus::utils::models::{StreamError, StreamResult};
+use prost::Message;
+
+use crate::{BalanceChange, MoveEventSummary, ObjectChangeKind, ObjectChangeSummary, SuiEvent};
+
+/// Protobuf message of [`SuiEvent`], `fluxus.sui.SuiEvent` in `proto/sui_event.proto`
+///
+/// Balance change amounts are decimal strings, transaction metadata, parsed
+/// Move events and the raw response are JSON strings.
+#[derive(Clone, PartialEq, Message)]
+pub struct ProtoSuiEvent {
+    #[prost(string, tag = "1")]
+    pub transaction_digest: String,
+    #[prost(string, tag = "2")]
+    pub transaction_type: String,
+    #[prost(uint64, tag = "3")]
+    pub timestamp: u64,
+    #[prost(string, tag = "4")]
+    pub sender: String,
+    #[prost(string, optional, tag = "5")]
+    pub recipient: Option<String>,
+    #[prost(string, optional, tag = "6")]
+    pub sender_label: Option<String>,
+    #[prost(string, optional, tag = "7")]
+    pub recipient_label: Option<String>,
+    #[prost(uint64, optional, tag = "8")]
+    pub amount: Option<u64>,
+    #[prost(uint64, tag = "9")]
+    pub computation_cost: u64,
+    #[prost(uint64, tag = "10")]
+    pub storage_cost: u64,
+    #[prost(uint64, tag = "11")]
+    pub storage_rebate: u64,
+    #[prost(sint64, tag = "12")]
+    pub gas_fee: i64,
+    #[prost(string, tag = "13")]
+    pub gas_owner: String,
+    #[prost(bool, tag = "14")]
+    pub is_sponsored: bool,
+    #[prost(string, optional, tag = "29")]
+    pub auth_scheme: Option<String>,
+    #[prost(bool, tag = "15")]
+    pub success: bool,
+    #[prost(string, optional, tag = "16")]
+    pub execution_error: Option<String>,
+    #[prost(message, repeated, tag = "17")]
+    pub events: Vec<ProtoMoveEvent>,
+    #[prost(message, repeated, tag = "18")]
+    pub balance_changes: Vec<ProtoBalanceChange>,
+    #[prost(message, repeated, tag = "19")]
+    pub object_changes: Vec<ProtoObjectChange>,
+    #[prost(bool, tag = "20")]
+    pub uses_shared_objects: bool,
+    #[prost(string, repeated, tag = "21")]
+    pub shared_objects: Vec<String>,
+    #[prost(uint64, tag = "22")]
+    pub created_count: u64,
+    #[prost(uint64, tag = "23")]
+    pub mutated_count: u64,
+    #[prost(uint64, tag = "24")]
+    pub deleted_count: u64,
+    #[prost(uint64, tag = "25")]
+    pub events_count: u64,
+    #[prost(string, tag = "26")]
+    pub metadata: String,
+    #[prost(bytes = "vec", optional, tag = "27")]
+    pub raw_transaction: Option<Vec<u8>>,
+    #[prost(string, optional, tag = "28")]
+    pub raw: Option<String>,
+}
+
+/// Protobuf message of [`MoveEventSummary`]
+#[derive(Clone, PartialEq, Message)]
+pub struct ProtoMoveEvent {
+    #[prost(string, tag = "1")]
+    pub event_type: String,
+    #[prost(string, tag = "2")]
+    pub package_id: String,
+    #[prost(string, tag = "3")]
+    pub module: String,
+    #[prost(string, tag = "4")]
+    pub parsed_json: String,
+    #[prost(bytes = "vec", optional, tag = "5")]
+    pub bcs: Option<Vec<u8>>,
+}
+
+/// Protobuf message of [`BalanceChange`]
+#[derive(Clone, PartialEq, Message)]
+pub struct ProtoBalanceChange {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+    #[prost(string, tag = "2")]
+    pub coin_type: String,
+    #[prost(string, tag = "3")]
+    pub amount: String,
+    #[prost(uint32, optional, tag = "4")]
+    pub decimals: Option<u32>,
+    #[prost(string, optional, tag = "5")]
+    pub symbol: Option<String>,
+    #[prost(string, optional, tag = "6")]
+    pub formatted_amount: Option<String>,
+}
+
+/// Protobuf enum of [`ObjectChangeKind`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoObjectChangeKind {
+    Unspecified = 0,
+    Created = 1,
+    Mutated = 2,
+    Transferred = 3,
+    Wrapped = 4,
+    Deleted = 5,
+    Published = 6,
+}
+
+/// Protobuf message of [`ObjectChangeSummary`]
+#[derive(Clone, PartialEq, Message)]
+pub struct ProtoObjectChange {
+    #[prost(enumeration = "ProtoObjectChangeKind", tag = "1")]
+    pub change_type: i32,
+    #[prost(string, tag = "2")]
+    pub object_id: String,
+    #[prost(string, optional, tag = "3")]
+    pub object_type: Option<String>,
+    #[prost(uint64, tag = "4")]
+    pub version: u64,
+    #[prost(string, optional, tag = "5")]
+    pub owner: Option<String>,
+}
+
+impl SuiEvent {
+    /// Encodes the event as a [`ProtoSuiEvent`] message
+    pub fn to_protobuf(&self) -> Vec<u8> {
+        ProtoSuiEvent::from(self.clone()).encode_to_vec()
+    }
+
+    /// Decodes an event encoded with [`SuiEvent::to_protobuf`]
+    pub fn from_protobuf(bytes: &[u8]) -> StreamResult<Self> {
+        ProtoSuiEvent::decode(bytes)
+            .map_err(serialization_error)?
+            .try_into()
+    }
+}
+
+impl From<SuiEvent> for ProtoSuiEvent {
+    fn from(event: SuiEvent) -> Self {
+        Self {
+            transaction_digest: event.transaction_digest,
+            transaction_type: event.transaction_type,
+            timestamp: event.timestamp,
+            sender: event.sender,
+            recipient: event.recipient,
+            sender_label: event.sender_label,
+            recipient_label: event.recipient_label,
+            amount: event.amount,
+            computation_cost: event.computation_cost,
+            storage_cost: event.storage_cost,
+            storage_rebate: event.storage_rebate,
+            gas_fee: event.gas_fee,
+            gas_owner: event.gas_owner,
+            is_sponsored: event.is_sponsored,
+            auth_scheme: event.auth_scheme.map(|scheme| scheme.to_string()),
+            success: event.success,
+            execution_error: event.execution_error,
+            events: event
+                .events
+                .into_iter()
+                .map(|move_event| ProtoMoveEvent {
+                    event_type: move_event.event_type,
+                    package_id: move_event.package_id,
+                    module: move_event.module,
+                    parsed_json: move_event.parsed_json.to_string(),
+                    bcs: move_event.bcs,
+                })
+                .collect(),
+            balance_changes: event
+                .balance_changes
+                .into_iter()
+                .map(|change| ProtoBalanceChange {
+                    owner: change.owner,
+                    coin_type: change.coin_type,
+                    amount: change.amount.to_string(),
+                    decimals: change.decimals.map(u32::from),
+                    symbol: change.symbol,
+                    formatted_amount: change.formatted_amount,
+                })
+                .collect(),
+            object_changes: event
+                .object_changes
+                .into_iter()
+                .map(|change| ProtoObjectChange {
+                    change_type: ProtoObjectChangeKind::from(change.change_type).into(),
+                    object_id: change.object_id,
+                    object_type: change.object_type,
+                    version: change.version,
+                    owner: change.owner,
+                })
+                .collect(),
+            uses_shared_objects: event.uses_shared_objects,
+            shared_objects: event.shared_objects,
+            created_count: event.created_count as u64,
+            mutated_count: event.mutated_count as u64,
+            deleted_count: event.deleted_count as u64,
+            events_count: event.events_count as u64,
+            // Serializing the metadata enum to JSON cannot fail
+            metadata: serde_json::to_string(&event.metadata).unwrap_or_default(),
+            raw_transaction: event.raw_transaction,
+            raw: event.raw.map(|raw| raw.to_string()),
+        }
+    }
+}
+
+impl TryFrom<ProtoSuiEvent> for SuiEvent {
+    type Error = StreamError;
+
+    fn try_from(message: ProtoSuiEvent) -> StreamResult<Self> {
+        Ok(Self {
+            transaction_digest: message.transaction_digest,
+            transaction_type: message.transaction_type,
+            timestamp: message.timestamp,
+            sender: message.sender,
+            recipient: message.recipient,
+            sender_label: message.sender_label,
+            recipient_label: message.recipient_label,
+            amount: message.amount,
+            computation_cost: message.computation_cost,
+            storage_cost: message.storage_cost,
+            storage_rebate: message.storage_rebate,
+            gas_fee: message.gas_fee,
+            gas_owner: message.gas_owner,
+            is_sponsored: message.is_sponsored,
+            auth_scheme: message
+                .auth_scheme
+                .map(|scheme| scheme.parse())
+                .transpose()?,
+            success: message.success,
+            execution_error: message.execution_error,
+            events: message
+                .events
+                .into_iter()
+                .map(|move_event| {
+                    Ok(MoveEventSummary {
+                        event_type: move_event.event_type,
+                        package_id: move_event.package_id,
+                        module: move_event.module,
+                        parsed_json: serde_json::from_str(&move_event.parsed_json)
+                            .map_err(serialization_error)?,
+                        bcs: move_event.bcs,
+                    })
+                })
+                .collect::<StreamResult<_>>()?,
+            balance_changes: message
+                .balance_changes
+                .into_iter()
+                .map(|change| {
+                    Ok(BalanceChange {
+                        owner: change.owner,
+                        coin_type: change.coin_type,
+                        amount: change.amount.parse().map_err(serialization_error)?,
+                        decimals: change
+                            .decimals
+                            .map(u8::try_from)
+                            .transpose()
+                            .map_err(serialization_error)?,
+                        symbol: change.symbol,
+                        formatted_amount: change.formatted_amount,
+                    })
+                })
+                .collect::<StreamResult<_>>()?,
+            object_changes: message
+                .object_changes
+                .into_iter()
+                .map(|change| {
+                    let kind = ProtoObjectChangeKind::try_from(change.change_type)
+                        .map_err(serialization_error)?;
+                    Ok(ObjectChangeSummary {
+                        change_type: kind.try_into()?,
+                        object_id: change.object_id,
+                        object_type: change.object_type,
+                        version: change.version,
+                        owner: change.owner,
+                    })
+                })
+                .collect::<StreamResult<_>>()?,
+            uses_shared_objects: message.uses_shared_objects,
+            shared_objects: message.shared_objects,
+            created_count: message.created_count as usize,
+            mutated_count: message.mutated_count as usize,
+            deleted_count: message.deleted_count as usize,
+            events_count: message.events_count as usize,
+            metadata: serde_json::from_str(&message.metadata).map_err(serialization_error)?,
+            raw_transaction: message.raw_transaction,
+            raw: message
+                .raw
+                .map(|raw| serde_json::from_str(&raw))
+                .transpose()
+                .map_err(serialization_error)?,
+        })
+    }
+}
+
+impl From<ObjectChangeKind> for ProtoObjectChangeKind {
+    fn from(kind: ObjectChangeKind) -> Self {
+        match kind {
+            ObjectChangeKind::Created => Self::Created,
+            ObjectChangeKind::Mutated => Self::Mutated,
+            ObjectChangeKind::Transferred => Self::Transferred,
+            ObjectChangeKind::Wrapped => Self::Wrapped,
+            ObjectChangeKind::Deleted => Self::Deleted,
+            ObjectChangeKind::Published => Self::Published,
+        }
+    }
+}
+
+impl TryFrom<ProtoObjectChangeKind> for ObjectChangeKind {
+    type Error = StreamError;
+
+    fn try_from(kind: ProtoObjectChangeKind) -> StreamResult<Self> {
+        match kind {
+            ProtoObjectChangeKind::Unspecified => Err(StreamError::Serialization(
+                "Object change kind not specified".to_string(),
+            )),
+            ProtoObjectChangeKind::Created => Ok(Self::Created),
+            ProtoObjectChangeKind::Mutated => Ok(Self::Mutated),
+            ProtoObjectChangeKind::Transferred => Ok(Self::Transferred),
+            ProtoObjectChangeKind::Wrapped => Ok(Self::Wrapped),
+            ProtoObjectChangeKind::Deleted => Ok(Self::Deleted),
+            ProtoObjectChangeKind::Published => Ok(Self::Published),
+        }
+    }
+}
+
+/// Wraps a decoding error
+fn serialization_error(e: impl std::fmt::Display) -> StreamError {
+    StreamError::Serialization(e.to_string())
+}
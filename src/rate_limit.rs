@@ -0,0 +1,46 @@
+use fluxus::utils::models::{StreamError, StreamResult};
+use governor::{DefaultDirectRateLimiter, Quota};
+use std::fmt;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+/// Token bucket rate limiter for RPC requests
+///
+/// Clones share the same bucket, so one limiter can throttle several sources
+/// talking to the same fullnode.
+#[derive(Clone)]
+pub struct SuiRateLimiter {
+    /// Shared token bucket
+    limiter: Arc<DefaultDirectRateLimiter>,
+}
+
+impl SuiRateLimiter {
+    /// Creates a new SuiRateLimiter instance
+    ///
+    /// # Parameters
+    /// * `requests_per_second` - Sustained request rate
+    /// * `burst` - Maximum number of requests allowed at once
+    pub fn new(requests_per_second: u32, burst: u32) -> StreamResult<Self> {
+        let requests_per_second = NonZeroU32::new(requests_per_second).ok_or_else(|| {
+            StreamError::Config("requests_per_second must be greater than zero".to_string())
+        })?;
+        let burst = NonZeroU32::new(burst)
+            .ok_or_else(|| StreamError::Config("burst must be greater than zero".to_string()))?;
+
+        let quota = Quota::per_second(requests_per_second).allow_burst(burst);
+        Ok(Self {
+            limiter: Arc::new(DefaultDirectRateLimiter::direct(quota)),
+        })
+    }
+
+    /// Waits until a request may be sent
+    pub async fn acquire(&self) {
+        self.limiter.until_ready().await;
+    }
+}
+
+impl fmt::Debug for SuiRateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SuiRateLimiter").finish_non_exhaustive()
+    }
+}
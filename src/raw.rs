@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use sui_sdk::rpc_types::SuiTransactionBlockResponse;
+
+use crate::SuiSource;
+
+/// Sui data source emitting unmodified RPC transaction responses
+///
+/// Wraps a [`SuiSource`] for callers that need fields [`crate::SuiEvent`]
+/// does not carry. Which parts of the response are populated is controlled
+/// by the wrapped source's query options, see
+/// [`crate::SuiSourceBuilder::query_options`].
+pub struct SuiRawTransactionSource {
+    /// Wrapped transaction source
+    source: SuiSource,
+}
+
+impl SuiRawTransactionSource {
+    /// Creates a new SuiRawTransactionSource instance
+    ///
+    /// # Parameters
+    /// * `source` - Source providing the RPC endpoint, filters and query options
+    pub fn new(source: SuiSource) -> Self {
+        Self { source }
+    }
+}
+
+#[async_trait]
+impl Source<SuiTransactionBlockResponse> for SuiRawTransactionSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiTransactionBlockResponse>>> {
+        Ok(self.source.next_transaction().await?.map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.source.close().await
+    }
+}
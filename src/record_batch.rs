@@ -0,0 +1,166 @@
+use arrow::array::{
+    ArrayRef, BinaryBuilder, BooleanBuilder, Int64Builder, ListBuilder, StringBuilder,
+    UInt64Builder,
+};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use fluxus::utils::models::{StreamError, StreamResult};
+use std::sync::{Arc, LazyLock};
+
+use crate::SuiEvent;
+
+/// Arrow schema of [`sui_events_to_record_batch`]
+///
+/// Nested Move events, balance changes, object changes, transaction metadata
+/// and the raw response are JSON-encoded string columns, so the schema stays
+/// stable as those structures grow.
+static SCHEMA: LazyLock<SchemaRef> = LazyLock::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("transaction_digest", DataType::Utf8, false),
+        Field::new("transaction_type", DataType::Utf8, false),
+        Field::new("timestamp", DataType::UInt64, false),
+        Field::new("sender", DataType::Utf8, false),
+        Field::new("recipient", DataType::Utf8, true),
+        Field::new("sender_label", DataType::Utf8, true),
+        Field::new("recipient_label", DataType::Utf8, true),
+        Field::new("amount", DataType::UInt64, true),
+        Field::new("computation_cost", DataType::UInt64, false),
+        Field::new("storage_cost", DataType::UInt64, false),
+        Field::new("storage_rebate", DataType::UInt64, false),
+        Field::new("gas_fee", DataType::Int64, false),
+        Field::new("gas_owner", DataType::Utf8, false),
+        Field::new("is_sponsored", DataType::Boolean, false),
+        Field::new("auth_scheme", DataType::Utf8, true),
+        Field::new("success", DataType::Boolean, false),
+        Field::new("execution_error", DataType::Utf8, true),
+        Field::new("events", DataType::Utf8, false),
+        Field::new("balance_changes", DataType::Utf8, false),
+        Field::new("object_changes", DataType::Utf8, false),
+        Field::new("uses_shared_objects", DataType::Boolean, false),
+        Field::new(
+            "shared_objects",
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            false,
+        ),
+        Field::new("created_count", DataType::UInt64, false),
+        Field::new("mutated_count", DataType::UInt64, false),
+        Field::new("deleted_count", DataType::UInt64, false),
+        Field::new("events_count", DataType::UInt64, false),
+        Field::new("metadata", DataType::Utf8, false),
+        Field::new("raw_transaction", DataType::Binary, true),
+        Field::new("raw", DataType::Utf8, true),
+    ]))
+});
+
+/// Returns the Arrow schema of the record batches built from [`SuiEvent`]s
+pub fn sui_event_arrow_schema() -> SchemaRef {
+    SCHEMA.clone()
+}
+
+/// Converts events into an Arrow record batch with one row per event
+///
+/// The batch follows [`sui_event_arrow_schema`], ready to be handed to
+/// Parquet writers or DataFusion.
+pub fn sui_events_to_record_batch(events: &[SuiEvent]) -> StreamResult<RecordBatch> {
+    let mut transaction_digest = StringBuilder::new();
+    let mut transaction_type = StringBuilder::new();
+    let mut timestamp = UInt64Builder::new();
+    let mut sender = StringBuilder::new();
+    let mut recipient = StringBuilder::new();
+    let mut sender_label = StringBuilder::new();
+    let mut recipient_label = StringBuilder::new();
+    let mut amount = UInt64Builder::new();
+    let mut computation_cost = UInt64Builder::new();
+    let mut storage_cost = UInt64Builder::new();
+    let mut storage_rebate = UInt64Builder::new();
+    let mut gas_fee = Int64Builder::new();
+    let mut gas_owner = StringBuilder::new();
+    let mut is_sponsored = BooleanBuilder::new();
+    let mut auth_scheme = StringBuilder::new();
+    let mut success = BooleanBuilder::new();
+    let mut execution_error = StringBuilder::new();
+    let mut move_events = StringBuilder::new();
+    let mut balance_changes = StringBuilder::new();
+    let mut object_changes = StringBuilder::new();
+    let mut uses_shared_objects = BooleanBuilder::new();
+    let mut shared_objects = ListBuilder::new(StringBuilder::new());
+    let mut created_count = UInt64Builder::new();
+    let mut mutated_count = UInt64Builder::new();
+    let mut deleted_count = UInt64Builder::new();
+    let mut events_count = UInt64Builder::new();
+    let mut metadata = StringBuilder::new();
+    let mut raw_transaction = BinaryBuilder::new();
+    let mut raw = StringBuilder::new();
+
+    for event in events {
+        transaction_digest.append_value(&event.transaction_digest);
+        transaction_type.append_value(&event.transaction_type);
+        timestamp.append_value(event.timestamp);
+        sender.append_value(&event.sender);
+        recipient.append_option(event.recipient.as_deref());
+        sender_label.append_option(event.sender_label.as_deref());
+        recipient_label.append_option(event.recipient_label.as_deref());
+        amount.append_option(event.amount);
+        computation_cost.append_value(event.computation_cost);
+        storage_cost.append_value(event.storage_cost);
+        storage_rebate.append_value(event.storage_rebate);
+        gas_fee.append_value(event.gas_fee);
+        gas_owner.append_value(&event.gas_owner);
+        is_sponsored.append_value(event.is_sponsored);
+        auth_scheme.append_option(event.auth_scheme.map(|scheme| scheme.as_str()));
+        success.append_value(event.success);
+        execution_error.append_option(event.execution_error.as_deref());
+        move_events.append_value(to_json(&event.events)?);
+        balance_changes.append_value(to_json(&event.balance_changes)?);
+        object_changes.append_value(to_json(&event.object_changes)?);
+        uses_shared_objects.append_value(event.uses_shared_objects);
+        shared_objects.append_value(event.shared_objects.iter().map(Some));
+        created_count.append_value(event.created_count as u64);
+        mutated_count.append_value(event.mutated_count as u64);
+        deleted_count.append_value(event.deleted_count as u64);
+        events_count.append_value(event.events_count as u64);
+        metadata.append_value(to_json(&event.metadata)?);
+        raw_transaction.append_option(event.raw_transaction.as_deref());
+        raw.append_option(event.raw.as_ref().map(|raw| raw.to_string()));
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(transaction_digest.finish()),
+        Arc::new(transaction_type.finish()),
+        Arc::new(timestamp.finish()),
+        Arc::new(sender.finish()),
+        Arc::new(recipient.finish()),
+        Arc::new(sender_label.finish()),
+        Arc::new(recipient_label.finish()),
+        Arc::new(amount.finish()),
+        Arc::new(computation_cost.finish()),
+        Arc::new(storage_cost.finish()),
+        Arc::new(storage_rebate.finish()),
+        Arc::new(gas_fee.finish()),
+        Arc::new(gas_owner.finish()),
+        Arc::new(is_sponsored.finish()),
+        Arc::new(auth_scheme.finish()),
+        Arc::new(success.finish()),
+        Arc::new(execution_error.finish()),
+        Arc::new(move_events.finish()),
+        Arc::new(balance_changes.finish()),
+        Arc::new(object_changes.finish()),
+        Arc::new(uses_shared_objects.finish()),
+        Arc::new(shared_objects.finish()),
+        Arc::new(created_count.finish()),
+        Arc::new(mutated_count.finish()),
+        Arc::new(deleted_count.finish()),
+        Arc::new(events_count.finish()),
+        Arc::new(metadata.finish()),
+        Arc::new(raw_transaction.finish()),
+        Arc::new(raw.finish()),
+    ];
+
+    RecordBatch::try_new(SCHEMA.clone(), columns)
+        .map_err(|e| StreamError::Serialization(e.to_string()))
+}
+
+/// Encodes a nested structure as a JSON column value
+fn to_json(value: &impl serde::Serialize) -> StreamResult<String> {
+    serde_json::to_string(value).map_err(|e| StreamError::Serialization(e.to_string()))
+}
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use fluxus::utils::models::{StreamError, StreamResult};
+use redis::AsyncCommands;
+use redis::aio::MultiplexedConnection;
+use std::fmt;
+use tokio::sync::OnceCell;
+
+use crate::{CursorStore, SuiCursor};
+
+/// Default prefix of the Redis keys holding cursors
+const DEFAULT_KEY_PREFIX: &str = "fluxus:sui:cursor:";
+
+/// Cursor store keeping the cursor as JSON under a Redis key
+///
+/// Every source is identified by a name, so several pipelines can share a
+/// Redis instance. The key is the key prefix followed by the name.
+pub struct RedisCursorStore {
+    /// Redis client
+    client: redis::Client,
+    /// Name identifying the source
+    name: String,
+    /// Prefix of the Redis key
+    key_prefix: String,
+    /// Connection opened on first use
+    connection: OnceCell<MultiplexedConnection>,
+}
+
+impl RedisCursorStore {
+    /// Creates a new RedisCursorStore instance
+    ///
+    /// # Parameters
+    /// * `redis_url` - Redis connection URL, e.g. `redis://127.0.0.1/`
+    /// * `name` - Name identifying the source
+    pub fn new(redis_url: &str, name: impl Into<String>) -> StreamResult<Self> {
+        let client = redis::Client::open(redis_url)
+            .map_err(|e| StreamError::Config(format!("Invalid Redis URL: {}", e)))?;
+        Ok(Self {
+            client,
+            name: name.into(),
+            key_prefix: DEFAULT_KEY_PREFIX.to_string(),
+            connection: OnceCell::new(),
+        })
+    }
+
+    /// Sets the prefix of the Redis key, `fluxus:sui:cursor:` by default
+    pub fn with_key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.key_prefix = key_prefix.into();
+        self
+    }
+
+    /// Returns the Redis key holding the cursor
+    pub fn key(&self) -> String {
+        format!("{}{}", self.key_prefix, self.name)
+    }
+
+    /// Returns the shared connection, opening it on first use
+    async fn connection(&self) -> StreamResult<MultiplexedConnection> {
+        self.connection
+            .get_or_try_init(|| self.client.get_multiplexed_async_connection())
+            .await
+            .cloned()
+            .map_err(|e| {
+                tracing::error!("Failed to connect to Redis: {}", e);
+                StreamError::Runtime(e.to_string())
+            })
+    }
+}
+
+impl fmt::Debug for RedisCursorStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisCursorStore")
+            .field("key", &self.key())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl CursorStore for RedisCursorStore {
+    async fn load(&self) -> StreamResult<Option<SuiCursor>> {
+        let mut connection = self.connection().await?;
+        let value: Option<String> = connection.get(self.key()).await.map_err(|e| {
+            tracing::error!("Failed to load cursor from Redis: {}", e);
+            StreamError::Runtime(e.to_string())
+        })?;
+
+        value
+            .map(|value| serde_json::from_str(&value))
+            .transpose()
+            .map_err(|e| StreamError::Serialization(e.to_string()))
+    }
+
+    async fn save(&self, cursor: &SuiCursor) -> StreamResult<()> {
+        let value =
+            serde_json::to_string(cursor).map_err(|e| StreamError::Serialization(e.to_string()))?;
+
+        let mut connection = self.connection().await?;
+        connection
+            .set::<_, _, ()>(self.key(), value)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to save cursor to Redis: {}", e);
+                StreamError::Runtime(e.to_string())
+            })
+    }
+}
@@ -0,0 +1,213 @@
+use fluxus::utils::models::{StreamError, StreamResult};
+use rand::Rng;
+use std::fmt::Display;
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sui_sdk::{SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+/// Retry policy with exponential backoff applied to RPC requests
+///
+/// The delay before retry `n` is `base_delay * 2^(n - 1)`, capped at
+/// `max_delay` and randomized by up to `jitter` of its value in either
+/// direction so that many sources don't retry in lockstep.
+///
+/// Rate-limit responses (HTTP 429) are retried after the delay the node asks
+/// for in its Retry-After hint, or `rate_limit_cooldown` without one, if that
+/// is longer than the backoff.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound of the delay between attempts
+    pub max_delay: Duration,
+    /// Fraction of the delay that is randomized, between 0 and 1
+    pub jitter: f64,
+    /// Delay after a rate-limit response that carries no Retry-After hint
+    pub rate_limit_cooldown: Duration,
+}
+
+/// Prefix of the runtime error returned when the RPC node keeps rate limiting requests
+const RATE_LIMITED: &str = "Rate limited by RPC node";
+
+/// Counters updated by [`RetryPolicy::run`]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RetryStats {
+    /// Number of retried requests
+    pub(crate) retries: u64,
+    /// Number of rate-limit responses
+    pub(crate) rate_limited: u64,
+}
+
+impl RetryStats {
+    /// Adds the counters of another run
+    pub(crate) fn add(&mut self, other: RetryStats) {
+        self.retries += other.retries;
+        self.rate_limited += other.rate_limited;
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            jitter: 0.2,
+            rate_limit_cooldown: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the delay before the given retry, starting at 1, without jitter
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+
+    /// Returns the delay before the given retry with jitter applied
+    fn jittered_backoff(&self, retry: u32) -> Duration {
+        let backoff = self.backoff(retry);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return backoff;
+        }
+        backoff.mul_f64(1.0 + rand::thread_rng().gen_range(-jitter..=jitter))
+    }
+
+    /// Runs an operation until it succeeds or the attempts are exhausted
+    ///
+    /// Every retry and rate-limit response is added to `stats`. The last
+    /// error is returned once the attempts are exhausted.
+    pub(crate) async fn run<T, E, F, Fut>(
+        &self,
+        operation: &str,
+        stats: &mut RetryStats,
+        mut f: F,
+    ) -> Result<T, E>
+    where
+        E: Display,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match f().await {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    let retry_after = rate_limit_hint(&e.to_string());
+                    if retry_after.is_some() {
+                        stats.rate_limited += 1;
+                    }
+                    if attempt >= self.max_attempts {
+                        return Err(e);
+                    }
+
+                    let mut delay = self.jittered_backoff(attempt);
+                    if let Some(retry_after) = retry_after {
+                        // Hammering a throttling node only extends the throttling
+                        delay = delay.max(retry_after.unwrap_or(self.rate_limit_cooldown));
+                        tracing::warn!("{} rate limited by RPC node", operation);
+                    }
+                    tracing::warn!(
+                        "{} failed (attempt {}/{}), retrying in {:?}: {}",
+                        operation,
+                        attempt,
+                        self.max_attempts,
+                        delay,
+                        e
+                    );
+                    stats.retries += 1;
+                    attempt += 1;
+                    sleep(delay).await;
+                }
+            }
+        }
+    }
+}
+
+/// Converts a failed RPC request into a runtime error, flagging rate-limit responses
+pub(crate) fn rpc_error(e: impl Display) -> StreamError {
+    let message = e.to_string();
+    if rate_limit_hint(&message).is_some() {
+        StreamError::Runtime(format!("{}: {}", RATE_LIMITED, message))
+    } else {
+        StreamError::Runtime(message)
+    }
+}
+
+/// Connects a Sui client with default settings to an RPC endpoint
+///
+/// The client uses the TLS setup of sui-sdk, the `native-tls` and
+/// `rustls-tls` features do not apply to it.
+pub(crate) async fn connect_client(endpoint: &str) -> StreamResult<SuiClient> {
+    SuiClientBuilder::default()
+        .build(endpoint)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to initialize Sui client for {}: {}", endpoint, e);
+            rpc_error(e)
+        })
+}
+
+/// Current wall-clock time in milliseconds since the Unix epoch
+pub(crate) fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Whether the error was returned because the RPC node kept rate limiting requests
+pub fn is_rate_limited(error: &StreamError) -> bool {
+    matches!(error, StreamError::Runtime(message) if message.starts_with(RATE_LIMITED))
+}
+
+/// Ways the HTTP clients report a 429 status in their error messages
+///
+/// Only the status itself is matched, digests, checkpoint numbers and object
+/// IDs quoted in other errors may contain the digits as well.
+const RATE_LIMIT_STATUSES: [&str; 4] = [
+    // jsonrpsee rejecting the response status
+    "rejected `429`",
+    "status code 429",
+    "status code: 429",
+    // Reason phrase, e.g. `HTTP status client error (429 Too Many Requests)`
+    "too many requests",
+];
+
+/// Detects a rate-limit response in an RPC error message
+///
+/// Returns `None` if the request was not rate limited, otherwise the delay
+/// requested by the Retry-After hint if the node sent one, in seconds.
+fn rate_limit_hint(message: &str) -> Option<Option<Duration>> {
+    let message = message.to_ascii_lowercase();
+    if !RATE_LIMIT_STATUSES
+        .iter()
+        .any(|status| message.contains(status))
+    {
+        return None;
+    }
+
+    let retry_after = ["retry-after", "retry after"].iter().find_map(|hint| {
+        let (_, rest) = message.split_once(hint)?;
+        let seconds: String = rest
+            .trim_start_matches(|c: char| c.is_whitespace() || matches!(c, ':' | '=' | '"'))
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        seconds.parse().ok().map(Duration::from_secs)
+    });
+    Some(retry_after)
+}
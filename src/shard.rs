@@ -0,0 +1,55 @@
+use fluxus::utils::models::{StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
+
+/// Share of the checkpoints handled by one of several source instances
+///
+/// Checkpoint `n` belongs to shard `n % count`, so instances configured with
+/// the same count and distinct indexes split the chain without duplicates.
+/// Transactions belong to the shard of their checkpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SuiShard {
+    /// Index of this shard, from 0 to `count - 1`
+    pub index: u64,
+    /// Total number of shards
+    pub count: u64,
+}
+
+impl SuiShard {
+    /// Creates a new SuiShard instance
+    ///
+    /// # Parameters
+    /// * `index` - Index of this shard, from 0 to `count - 1`
+    /// * `count` - Total number of shards
+    pub fn new(index: u64, count: u64) -> StreamResult<Self> {
+        let shard = Self { index, count };
+        shard.validate()?;
+        Ok(shard)
+    }
+
+    /// Checks that the index is within the shard count
+    pub(crate) fn validate(&self) -> StreamResult<()> {
+        if self.index >= self.count {
+            return Err(StreamError::Config(format!(
+                "Invalid shard {} of {}, the index must be below the shard count",
+                self.index, self.count
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether a checkpoint belongs to this shard
+    pub fn contains(&self, checkpoint: CheckpointSequenceNumber) -> bool {
+        checkpoint % self.count == self.index
+    }
+
+    /// First checkpoint of this shard at or after the given one
+    pub(crate) fn next_checkpoint(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> CheckpointSequenceNumber {
+        let offset = (self.index + self.count - checkpoint % self.count) % self.count;
+        checkpoint.saturating_add(offset)
+    }
+}
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use fluxus::utils::models::{StreamError, StreamResult};
+use sqlx::AnyPool;
+use std::str::FromStr;
+use sui_sdk::types::digests::TransactionDigest;
+
+use crate::{CursorStore, SuiCursor};
+
+/// Table holding the cursors of all sources
+const CURSOR_TABLE: &str = "sui_cursors";
+
+/// Cursor store keeping the cursor in a SQLite or Postgres table
+///
+/// Every source is identified by a name and stored as one row of the
+/// `sui_cursors` table with its last checkpoint, last digest and the time of
+/// the update in Unix milliseconds. The table is created if missing.
+#[derive(Clone, Debug)]
+pub struct SqlCursorStore {
+    /// Connection pool
+    pool: AnyPool,
+    /// Name identifying the source
+    source_name: String,
+}
+
+impl SqlCursorStore {
+    /// Connects to the database and creates the cursor table if missing
+    ///
+    /// # Parameters
+    /// * `database_url` - `sqlite://` or `postgres://` connection URL
+    /// * `source_name` - Name identifying the source
+    pub async fn connect(database_url: &str, source_name: impl Into<String>) -> StreamResult<Self> {
+        sqlx::any::install_default_drivers();
+        let pool = AnyPool::connect(database_url).await.map_err(|e| {
+            tracing::error!("Failed to connect to cursor database: {}", e);
+            StreamError::Runtime(e.to_string())
+        })?;
+
+        sqlx::query(&format!(
+            "CREATE TABLE IF NOT EXISTS {} (
+                source_name TEXT PRIMARY KEY,
+                last_checkpoint BIGINT,
+                last_digest TEXT NOT NULL,
+                updated_at BIGINT NOT NULL
+            )",
+            CURSOR_TABLE
+        ))
+        .execute(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to create cursor table: {}", e);
+            StreamError::Runtime(e.to_string())
+        })?;
+
+        Ok(Self {
+            pool,
+            source_name: source_name.into(),
+        })
+    }
+}
+
+#[async_trait]
+impl CursorStore for SqlCursorStore {
+    async fn load(&self) -> StreamResult<Option<SuiCursor>> {
+        let row: Option<(Option<i64>, String)> = sqlx::query_as(&format!(
+            "SELECT last_checkpoint, last_digest FROM {} WHERE source_name = $1",
+            CURSOR_TABLE
+        ))
+        .bind(&self.source_name)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to load cursor from database: {}", e);
+            StreamError::Runtime(e.to_string())
+        })?;
+
+        let Some((checkpoint, digest)) = row else {
+            return Ok(None);
+        };
+
+        Ok(Some(SuiCursor {
+            digest: TransactionDigest::from_str(&digest)
+                .map_err(|e| StreamError::Serialization(e.to_string()))?,
+            checkpoint: checkpoint.map(|checkpoint| checkpoint as u64),
+        }))
+    }
+
+    async fn save(&self, cursor: &SuiCursor) -> StreamResult<()> {
+        let updated_at = chrono::Utc::now().timestamp_millis();
+
+        sqlx::query(&format!(
+            "INSERT INTO {} (source_name, last_checkpoint, last_digest, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (source_name) DO UPDATE SET
+                last_checkpoint = excluded.last_checkpoint,
+                last_digest = excluded.last_digest,
+                updated_at = excluded.updated_at",
+            CURSOR_TABLE
+        ))
+        .bind(&self.source_name)
+        .bind(cursor.checkpoint.map(|checkpoint| checkpoint as i64))
+        .bind(cursor.digest.to_string())
+        .bind(updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to save cursor to database: {}", e);
+            StreamError::Runtime(e.to_string())
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use sui_sdk::rpc_types::EventFilter;
+use sui_sdk::types::{Identifier, SUI_SYSTEM_PACKAGE_ID};
+
+use crate::{SuiEventSource, SuiMoveEvent};
+
+/// Kind of staking activity
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum StakingAction {
+    /// Stake was added to a validator pool
+    StakeAdded,
+    /// Stake was withdrawn from a validator pool
+    StakeWithdrawn,
+}
+
+/// Decoded staking or unstaking request
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiStakingEvent {
+    /// Digest of the emitting transaction
+    pub transaction_digest: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Kind of activity
+    pub action: StakingAction,
+    /// Validator address
+    pub validator_address: String,
+    /// Staking pool ID of the validator
+    pub pool_id: String,
+    /// Staker address
+    pub staker: String,
+    /// Staked or withdrawn principal in MIST
+    pub amount: u64,
+    /// Rewards paid out on withdrawal in MIST
+    pub reward_amount: Option<u64>,
+    /// Epoch the stake was requested in, or the unstaking epoch for withdrawals
+    pub epoch: u64,
+}
+
+impl SuiStakingEvent {
+    /// Decodes a staking event of the system package, `None` for other events
+    pub fn decode(event: &SuiMoveEvent) -> Option<Self> {
+        let (action, amount, reward_amount, epoch) = match event.event_name() {
+            "StakingRequestEvent" => (
+                StakingAction::StakeAdded,
+                event.field_u64("amount")?,
+                None,
+                event.field_u64("epoch")?,
+            ),
+            "UnstakingRequestEvent" => (
+                StakingAction::StakeWithdrawn,
+                event.field_u64("principal_amount")?,
+                event.field_u64("reward_amount"),
+                event.field_u64("unstaking_epoch")?,
+            ),
+            _ => return None,
+        };
+
+        Some(Self {
+            transaction_digest: event.transaction_digest.clone(),
+            timestamp: event.timestamp,
+            action,
+            validator_address: event.field_str("validator_address")?,
+            pool_id: event.field_str("pool_id")?,
+            staker: event.field_str("staker_address")?,
+            amount,
+            reward_amount,
+            epoch,
+        })
+    }
+}
+
+/// Sui data source emitting decoded stake and unstake requests
+pub struct SuiStakingSource {
+    /// Wrapped event source scoped to the validator module
+    source: SuiEventSource,
+}
+
+impl SuiStakingSource {
+    /// Creates a new SuiStakingSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        let filter = EventFilter::MoveEventModule {
+            package: SUI_SYSTEM_PACKAGE_ID,
+            module: Identifier::new("validator").expect("validator is a valid identifier"),
+        };
+        Self {
+            source: SuiEventSource::new(rpc_url, filter, interval_ms, 50),
+        }
+    }
+}
+
+#[async_trait]
+impl Source<SuiStakingEvent> for SuiStakingSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiStakingEvent>>> {
+        // Skip validator events unrelated to staking
+        while let Some(event) = self.source.next_event().await? {
+            if let Some(staking_event) = SuiStakingEvent::decode(&event) {
+                return Ok(Some(Record::new(staking_event)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.source.close().await
+    }
+}
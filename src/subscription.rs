@@ -0,0 +1,284 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use std::time::{Duration, Instant};
+use sui_sdk::error::SuiRpcResult;
+use sui_sdk::rpc_types::{
+    SuiTransactionBlockEffects, SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponse,
+};
+use sui_sdk::{SuiClient, SuiClientBuilder};
+use tokio::time::timeout;
+
+use crate::retry::{now_millis, rpc_error};
+use crate::sui::event_record;
+use crate::{SuiEvent, SuiSource};
+
+/// Push-based Sui data source receiving transactions over a WebSocket subscription
+///
+/// Transactions matching the filter configured on the wrapped [`SuiSource`] are
+/// pushed by the fullnode as soon as they are executed. When the WebSocket is
+/// unreachable, or the subscription drops or stalls, the wrapped source keeps
+/// the stream alive by polling from the last emitted transaction until the
+/// subscription can be re-established, and then up to the chain head before
+/// switching back, so nothing committed in between is lost.
+/// Pushed and polled transactions share deduplication, enrichment, labeling
+/// and the client-side filters of the wrapped source.
+pub struct SuiSubscriptionSource {
+    /// Wrapped source used for hydration and as polling fallback
+    source: SuiSource,
+    /// Sui WebSocket endpoint URL
+    ws_url: String,
+    /// Client connected to the WebSocket endpoint, separate from the polling client
+    ws_client: Option<SuiClient>,
+    /// Active transaction effects subscription
+    subscription: Option<BoxStream<'static, SuiRpcResult<SuiTransactionBlockEffects>>>,
+    /// Delay between attempts to re-establish a dropped subscription
+    resubscribe_interval: Duration,
+    /// Time without pushed transactions after which the subscription is considered stalled
+    stall_timeout: Duration,
+    /// Time of the last subscription attempt
+    last_subscribe_attempt: Option<Instant>,
+    /// Time the subscription was opened while polled transactions may still
+    /// be pending, polling continues up to the chain head before switching over
+    catch_up_since: Option<u64>,
+}
+
+impl SuiSubscriptionSource {
+    /// Creates a new SuiSubscriptionSource instance
+    ///
+    /// Fails if the wrapped source has no transaction filter, fullnodes only
+    /// accept filtered subscriptions, or tracks watermarks, pushed and polled
+    /// transactions are not emitted in checkpoint order.
+    ///
+    /// # Parameters
+    /// * `source` - Source providing the RPC endpoint, transaction filter and polling fallback
+    /// * `ws_url` - Sui WebSocket endpoint URL
+    pub fn new(source: SuiSource, ws_url: impl Into<String>) -> StreamResult<Self> {
+        if source.filter.is_none() {
+            return Err(StreamError::Config(
+                "Transaction subscriptions require a transaction filter".to_string(),
+            ));
+        }
+        if source.watermarks.is_some() {
+            return Err(StreamError::Config(
+                "Transaction subscriptions cannot be combined with watermarks".to_string(),
+            ));
+        }
+        Ok(Self {
+            source,
+            ws_url: ws_url.into(),
+            ws_client: None,
+            subscription: None,
+            resubscribe_interval: Duration::from_secs(30),
+            stall_timeout: Duration::from_secs(60),
+            last_subscribe_attempt: None,
+            catch_up_since: None,
+        })
+    }
+
+    /// Sets the delay between attempts to re-establish a dropped subscription
+    pub fn with_resubscribe_interval(mut self, interval: Duration) -> Self {
+        self.resubscribe_interval = interval;
+        self
+    }
+
+    /// Sets the time without pushed transactions after which the subscription
+    /// is dropped and the source falls back to polling
+    pub fn with_stall_timeout(mut self, stall_timeout: Duration) -> Self {
+        self.stall_timeout = stall_timeout;
+        self
+    }
+
+    /// Whether records are currently pushed by the subscription rather than polled
+    pub fn is_subscribed(&self) -> bool {
+        self.subscription.is_some()
+    }
+
+    /// Emits the polled transactions not emitted yet, see [`SuiSource::drain`]
+    ///
+    /// Pushed transactions still waiting in the subscription are not drained,
+    /// they are polled again after a restart.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiEvent>>> {
+        self.source.drain().await
+    }
+
+    /// Connects the WebSocket client, reusing the connection of a previous attempt
+    async fn ws_client(&mut self) -> StreamResult<SuiClient> {
+        if let Some(client) = &self.ws_client {
+            return Ok(client.clone());
+        }
+
+        // Connected to the active endpoint, whose HTTP transport is unused
+        let rpc_url = self
+            .source
+            .endpoints
+            .url(self.source.endpoints.active())
+            .to_string();
+        let mut builder = SuiClientBuilder::default().ws_url(&self.ws_url);
+        if let Some(timeout) = self.source.request_timeout {
+            builder = builder.request_timeout(timeout);
+        }
+        if !self.source.headers.is_empty() {
+            builder = builder.custom_headers(self.source.headers.clone());
+        }
+        let client = builder.build(rpc_url).await.map_err(|e| {
+            tracing::error!("Failed to connect to {}: {}", self.ws_url, e);
+            rpc_error(e)
+        })?;
+
+        self.ws_client = Some(client.clone());
+        Ok(client)
+    }
+
+    /// Opens the transaction subscription using the filter of the wrapped source
+    async fn subscribe(&mut self) -> StreamResult<()> {
+        self.last_subscribe_attempt = Some(Instant::now());
+
+        let filter = self.source.filter.clone().ok_or_else(|| {
+            StreamError::Config(
+                "Transaction subscriptions require a transaction filter".to_string(),
+            )
+        })?;
+        let client = self.ws_client().await?;
+
+        self.source.throttle().await;
+        let subscription = match client.read_api().subscribe_transaction(filter).await {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                tracing::error!("Failed to subscribe to transactions: {}", e);
+                // The connection may be broken, reconnect on the next attempt
+                self.ws_client = None;
+                return Err(rpc_error(e));
+            }
+        };
+
+        self.subscription = Some(subscription.boxed());
+        tracing::info!("Subscribed to Sui transactions");
+
+        // Transactions committed after the cursor and before the subscription
+        // started are only reachable by polling
+        if self.source.cursor.is_some() {
+            self.catch_up_since = Some(now_millis());
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the full transaction for pushed effects
+    async fn hydrate(
+        &self,
+        effects: SuiTransactionBlockEffects,
+    ) -> StreamResult<SuiTransactionBlockResponse> {
+        let client = self
+            .source
+            .client()
+            .ok_or_else(|| StreamError::Runtime("SuiSource client not available".to_string()))?;
+
+        self.source.throttle().await;
+        client
+            .read_api()
+            .get_transaction_with_options(
+                *effects.transaction_digest(),
+                self.source.options.clone(),
+            )
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch transaction: {}", e);
+                StreamError::Runtime(e.to_string())
+            })
+    }
+
+    /// Emits a pushed transaction through the pipeline of the wrapped source
+    ///
+    /// Polling continues right after it if the subscription drops.
+    async fn emit_pushed(
+        &mut self,
+        effects: SuiTransactionBlockEffects,
+    ) -> StreamResult<Option<Record<SuiEvent>>> {
+        let transaction = self.hydrate(effects).await?;
+        if !self.source.record_emitted(&transaction) {
+            tracing::debug!("Skipping duplicate transaction {}", transaction.digest);
+            return Ok(None);
+        }
+        self.source.resume_after(transaction.digest);
+
+        let event = self.source.process_transaction(transaction).await?;
+        Ok(event.map(event_record))
+    }
+}
+
+#[async_trait]
+impl Source<SuiEvent> for SuiSubscriptionSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await?;
+        if let Err(e) = self.subscribe().await {
+            tracing::warn!("Subscribing failed, falling back to polling: {}", e);
+        }
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiEvent>>> {
+        // Periodically try to re-establish a dropped subscription
+        let resubscribe = self.subscription.is_none()
+            && self
+                .last_subscribe_attempt
+                .is_none_or(|attempt| attempt.elapsed() >= self.resubscribe_interval);
+        if resubscribe && let Err(e) = self.subscribe().await {
+            tracing::warn!("Resubscribing failed, continuing with polling: {}", e);
+        }
+
+        // Pending pages and transactions committed before the subscription
+        // started are polled first, pushed ones wait in the subscription and
+        // are deduplicated against them
+        if let Some(since) = self.catch_up_since {
+            let record = self.source.next().await?;
+            if self.source.is_caught_up(since) {
+                tracing::info!("Caught up with the chain head, switching to the subscription");
+                self.catch_up_since = None;
+            }
+            return Ok(record);
+        }
+
+        // Transactions polled before the subscription dropped are emitted first
+        if self.source.has_buffered() {
+            return self.source.next().await;
+        }
+        let Some(subscription) = self.subscription.as_mut() else {
+            return self.source.next().await;
+        };
+
+        match timeout(self.stall_timeout, subscription.next()).await {
+            Ok(Some(Ok(effects))) => self.emit_pushed(effects).await,
+            Ok(Some(Err(e))) => {
+                tracing::warn!("Subscription error, falling back to polling: {}", e);
+                self.subscription = None;
+                self.ws_client = None;
+                self.source.next().await
+            }
+            Ok(None) => {
+                tracing::warn!("Subscription closed, falling back to polling");
+                self.subscription = None;
+                self.ws_client = None;
+                self.source.next().await
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "No transaction pushed within {:?}, falling back to polling",
+                    self.stall_timeout
+                );
+                self.subscription = None;
+                self.ws_client = None;
+                self.source.next().await
+            }
+        }
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.subscription = None;
+        self.ws_client = None;
+        self.catch_up_since = None;
+        self.source.close().await
+    }
+}
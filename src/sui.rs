@@ -1,16 +1,46 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use fluxus::sources::Source;
 use fluxus::utils::models::{Record, StreamError, StreamResult};
+use lru::LruCache;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
-use sui_sdk::rpc_types::{SuiTransactionBlockDataAPI, SuiTransactionBlockResponseOptions};
-use sui_sdk::rpc_types::{SuiTransactionBlockResponse, SuiTransactionBlockResponseQuery};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use sui_sdk::rpc_types::{
+    CheckpointId, SuiExecutionStatus, SuiTransactionBlockEffects, SuiTransactionBlockResponse,
+    SuiTransactionBlockResponseQuery, TransactionBlocksPage, TransactionFilter,
+};
+use sui_sdk::rpc_types::{
+    SuiTransactionBlockDataAPI, SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponseOptions,
+};
 use sui_sdk::types::base_types::SuiAddress;
+use sui_sdk::types::digests::TransactionDigest;
 use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
 use sui_sdk::{SuiClient, SuiClientBuilder};
-use tokio::time::sleep;
+use tokio::sync::watch;
+use tokio::time::{sleep, timeout};
+
+use crate::builder::MAX_PAGE_SIZE;
+use crate::coin_cache::CoinMetadataCache;
+use crate::endpoint::EndpointPool;
+use crate::labeler::label_event;
+use crate::prefetch::{PrefetchQuery, Prefetcher};
+use crate::retry::{RetryStats, now_millis, rpc_error};
+use crate::watermark::WatermarkTracker;
+use crate::{
+    AddressLabeler, AuthScheme, BalanceChange, ConversionMode, CursorStore, DeadLetterSink,
+    EndpointHealth, EventPredicate, GapPolicy, MoveEventSummary, ObjectChangeSummary, RetryPolicy,
+    SuiCoinTransfer, SuiCursor, SuiDeadLetter, SuiIngestionPhase, SuiNetwork, SuiObjectChange,
+    SuiQueryOrder, SuiRateLimiter, SuiShard, SuiSourceBuilder, SuiSourceHandle, SuiSourceHealth,
+    SuiWatermark, TransactionMetadata,
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct SuiEvent {
     /// Transaction ID
     pub transaction_digest: String,
@@ -20,30 +50,204 @@ pub struct SuiEvent {
     pub timestamp: u64,
     /// Sender address
     pub sender: String,
-    /// Recipient address (if applicable)
+    /// Recipient address, set when the balance changes pay exactly one address besides the sender
     pub recipient: Option<String>,
-    /// Transaction amount (if applicable)
+    /// Label of the sender, set when an address labeler knows it
+    pub sender_label: Option<String>,
+    /// Label of the recipient, set when an address labeler knows it
+    pub recipient_label: Option<String>,
+    /// Amount paid to the recipients in the smallest unit of the coin, e.g. MIST for SUI
+    ///
+    /// Set when every address other than the sender received the same coin
+    /// type. Transactions paying several addresses, such as batched pays,
+    /// report the total paid to all of them, with `recipient` unset.
     pub amount: Option<u64>,
-    /// Transaction metadata
-    pub metadata: String,
+    /// Computation cost in MIST
+    pub computation_cost: u64,
+    /// Storage cost in MIST
+    pub storage_cost: u64,
+    /// Storage rebate in MIST
+    pub storage_rebate: u64,
+    /// Gas fee paid by the sender in MIST, negative if the rebate exceeded the costs
+    pub gas_fee: i64,
+    /// Address paying for gas, the sponsor in sponsored transactions
+    pub gas_owner: String,
+    /// Whether the gas was paid by an address other than the sender
+    pub is_sponsored: bool,
+    /// Signature scheme the sender authorized the transaction with
+    ///
+    /// GraphQL sources read it from the first signature of the transaction.
+    pub auth_scheme: Option<AuthScheme>,
+    /// Whether the transaction executed successfully
+    pub success: bool,
+    /// Reason the execution failed
+    pub execution_error: Option<String>,
+    /// Move events emitted by the transaction
+    pub events: Vec<MoveEventSummary>,
+    /// Balance changes of every owner and coin type
+    ///
+    /// Unlike `recipient` and `amount` these represent swaps and batched
+    /// pays faithfully. Not populated by checkpoint file sources.
+    pub balance_changes: Vec<BalanceChange>,
+    /// Objects created, mutated, transferred or deleted by the transaction
+    ///
+    /// Not populated by checkpoint file sources. GraphQL sources only hold the
+    /// first page of changes of the endpoint.
+    pub object_changes: Vec<ObjectChangeSummary>,
+    /// Whether the transaction touched shared objects and thus went through consensus
+    ///
+    /// Transactions touching only owned objects take the fast path.
+    pub uses_shared_objects: bool,
+    /// IDs of the shared objects the transaction touched
+    ///
+    /// GraphQL sources miss the shared objects system transactions only read.
+    pub shared_objects: Vec<String>,
+    /// Number of objects created, GraphQL sources count the first page of changes
+    pub created_count: usize,
+    /// Number of objects mutated, GraphQL sources count the first page of changes
+    pub mutated_count: usize,
+    /// Number of objects deleted, GraphQL sources count the first page of changes
+    pub deleted_count: usize,
+    /// Number of Move events emitted
+    pub events_count: usize,
+    /// Structured summary of the transaction
+    pub metadata: TransactionMetadata,
+    /// BCS-encoded signed transaction, set when BCS passthrough is enabled
+    pub raw_transaction: Option<Vec<u8>>,
+    /// Full RPC response as JSON, set when raw JSON passthrough is enabled
+    pub raw: Option<serde_json::Value>,
 }
 
+/// Default maximum number of concurrent requests fetching transaction details
+pub(crate) const DEFAULT_HYDRATION_CONCURRENCY: usize = 4;
+
+/// Default interval between checks of the latest network checkpoint
+pub(crate) const DEFAULT_LAG_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Default number of recently emitted digests remembered for deduplication
+pub(crate) const DEFAULT_DEDUP_WINDOW: NonZeroUsize = NonZeroUsize::new(10_000).unwrap();
+
 /// Sui blockchain data source for fetching transaction data from the Sui network
 pub struct SuiSource {
     /// Sui RPC endpoint URL
-    rpc_url: String,
+    pub(crate) rpc_url: String,
     /// Polling interval (milliseconds)
-    interval: Duration,
+    pub(crate) interval: Duration,
+    /// Adaptive polling interval bounds, the interval is fixed if not set
+    pub(crate) adaptive_interval: Option<AdaptiveInterval>,
     /// Whether initialized
     initialized: bool,
-    /// Sui client
-    client: Option<SuiClient>,
-    /// Last processed transaction digest
-    last_processed_digest: Option<String>,
+    /// Primary and fallback RPC endpoints with their clients
+    pub(crate) endpoints: EndpointPool,
+    /// Client supplied by the application for the primary endpoint
+    pub(crate) injected_client: Option<SuiClient>,
+    /// Reaction to checkpoints missing between emitted transactions
+    pub(crate) gap_policy: GapPolicy,
+    /// Checkpoint of the last emitted transaction
+    last_emitted_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Digest of the last emitted transaction
+    last_emitted_digest: Option<TransactionDigest>,
+    /// Last emitted digest and checkpoint before the last transaction was
+    /// emitted, restored when a wrapping source hands that transaction back
+    previous_emitted: (Option<TransactionDigest>, Option<CheckpointSequenceNumber>),
+    /// Handling of transactions with fields that cannot be extracted
+    pub(crate) conversion_mode: ConversionMode,
+    /// Whether transactions that failed to execute are emitted
+    pub(crate) include_failed: bool,
+    /// Whether system transactions are dropped
+    pub(crate) skip_system_transactions: bool,
+    /// Whether only transactions signed with zkLogin are emitted
+    pub(crate) zklogin_only: bool,
+    /// Coin metadata used to enrich balance changes, `None` if enrichment is disabled
+    pub(crate) coin_metadata_cache: Option<CoinMetadataCache>,
+    /// Labeler attaching labels to senders and recipients
+    pub(crate) address_labeler: Option<Arc<dyn AddressLabeler>>,
+    /// Client-side conditions events must all meet to be emitted
+    pub(crate) event_filters: Vec<Arc<dyn EventPredicate>>,
+    /// Whether the BCS encoding of transactions and events is passed through
+    pub(crate) include_bcs: bool,
+    /// Whether the full RPC response is attached to emitted events as JSON
+    pub(crate) include_raw_json: bool,
+    /// Watermarks derived from emitted checkpoints, `None` if tracking is disabled
+    pub(crate) watermarks: Option<WatermarkTracker>,
+    /// Destination of transactions that cannot be converted
+    pub(crate) dead_letter_sink: Option<Arc<dyn DeadLetterSink>>,
+    /// Number of transactions skipped because they could not be converted
+    dead_letters: AtomicU64,
+    /// Handle pausing and resuming ingestion
+    handle: SuiSourceHandle,
+    /// Time of the last successful fetch in milliseconds since the Unix epoch
+    last_successful_fetch: Option<u64>,
+    /// Number of failed fetches since the last successful one
+    consecutive_errors: u32,
+    /// Interval between checks of the latest network checkpoint
+    pub(crate) lag_check_interval: Duration,
+    /// Latest checkpoint on the network as of the last lag check
+    latest_network_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Time of the last lag check
+    last_lag_check: Option<Instant>,
+    /// Storage persisting the progress across restarts
+    pub(crate) cursor_store: Option<Arc<dyn CursorStore>>,
+    /// Whether transactions were emitted since the cursor was last saved
+    cursor_dirty: bool,
+    /// Digests of recently emitted transactions, used to drop duplicates
+    seen_digests: LruCache<TransactionDigest, ()>,
     /// Last processed checkpoint
     last_processed_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Last checkpoint timestamp looked up for transactions returned without one
+    checkpoint_timestamp: Option<(CheckpointSequenceNumber, u64)>,
     /// Maximum number of transactions to fetch
-    max_transactions: usize,
+    pub(crate) max_transactions: usize,
+    /// Transaction query response options
+    pub(crate) options: SuiTransactionBlockResponseOptions,
+    /// Server-side transaction filter
+    pub(crate) filter: Option<TransactionFilter>,
+    /// Per-request RPC timeout
+    pub(crate) request_timeout: Option<Duration>,
+    /// Deadline for producing the next transaction, including polling and retries
+    pub(crate) next_timeout: Option<Duration>,
+    /// Extra HTTP headers sent with every RPC request
+    pub(crate) headers: HashMap<String, String>,
+    /// Retry policy applied to RPC requests
+    pub(crate) retry_policy: RetryPolicy,
+    /// Number of retried RPC requests
+    retry_stats: RetryStats,
+    /// Rate limiter applied to RPC requests, possibly shared with other sources
+    pub(crate) rate_limiter: Option<SuiRateLimiter>,
+    /// Fetched transactions waiting to be emitted
+    buffer: VecDeque<SuiTransactionBlockResponse>,
+    /// Query cursor pointing at the last fetched transaction
+    pub(crate) cursor: Option<TransactionDigest>,
+    /// Whether more transactions are available after the cursor
+    has_next_page: bool,
+    /// Maximum number of concurrent requests fetching transaction details
+    pub(crate) hydration_concurrency: usize,
+    /// Number of pages fetched ahead in the background, prefetching is disabled at 0
+    pub(crate) prefetch_depth: usize,
+    /// Background task fetching pages ahead while walking forward
+    prefetcher: Option<Prefetcher>,
+    /// Checkpoint to resume from on the first fetch instead of the newest transactions
+    pub(crate) start_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Whether to walk forward from the cursor, or from genesis without one
+    pub(crate) walk_forward: bool,
+    /// Cursor and direction the buffered page was fetched with, restored by
+    /// `close` if none of its transactions was emitted
+    page_start: (Option<TransactionDigest>, bool),
+    /// Order in which the transaction history is walked
+    pub(crate) order: SuiQueryOrder,
+    /// Share of the checkpoints emitted by this instance, all if not set
+    pub(crate) shard: Option<SuiShard>,
+    /// Inclusive checkpoint range to ingest before terminating
+    pub(crate) checkpoint_range: Option<(CheckpointSequenceNumber, CheckpointSequenceNumber)>,
+    /// Time range to replay, resolved to a checkpoint range on `init`
+    pub(crate) time_range: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    /// Checkpoint currently being ingested in checkpoint range mode
+    current_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Whether the configured checkpoint range, or the whole history in
+    /// descending order, has been fully ingested
+    finished: bool,
+    /// Whether buffered transactions are being drained, which suspends polling
+    pub(crate) draining: bool,
 }
 
 impl SuiSource {
@@ -54,30 +258,197 @@ impl SuiSource {
     /// * `interval_ms` - Polling interval in milliseconds
     /// * `max_transactions` - Maximum number of transactions to fetch per poll
     pub fn new(rpc_url: String, interval_ms: u64, max_transactions: usize) -> Self {
+        let endpoints = EndpointPool::new(rpc_url.clone(), Vec::new());
         Self {
             rpc_url,
             interval: Duration::from_millis(interval_ms),
+            adaptive_interval: None,
             initialized: false,
-            client: None,
-            last_processed_digest: None,
+            endpoints,
+            injected_client: None,
+            gap_policy: GapPolicy::default(),
+            last_emitted_checkpoint: None,
+            last_emitted_digest: None,
+            previous_emitted: (None, None),
+            conversion_mode: ConversionMode::default(),
+            include_failed: true,
+            skip_system_transactions: false,
+            zklogin_only: false,
+            coin_metadata_cache: None,
+            address_labeler: None,
+            event_filters: Vec::new(),
+            include_bcs: false,
+            include_raw_json: false,
+            watermarks: None,
+            dead_letter_sink: None,
+            dead_letters: AtomicU64::new(0),
+            handle: SuiSourceHandle::default(),
+            last_successful_fetch: None,
+            consecutive_errors: 0,
+            lag_check_interval: DEFAULT_LAG_CHECK_INTERVAL,
+            latest_network_checkpoint: None,
+            last_lag_check: None,
+            cursor_store: None,
+            cursor_dirty: false,
+            seen_digests: LruCache::new(DEFAULT_DEDUP_WINDOW),
             last_processed_checkpoint: None,
+            checkpoint_timestamp: None,
             max_transactions,
+            options: default_query_options(),
+            filter: None,
+            request_timeout: None,
+            next_timeout: None,
+            headers: HashMap::new(),
+            retry_policy: RetryPolicy::default(),
+            retry_stats: RetryStats::default(),
+            rate_limiter: None,
+            buffer: VecDeque::new(),
+            cursor: None,
+            has_next_page: false,
+            hydration_concurrency: DEFAULT_HYDRATION_CONCURRENCY,
+            prefetch_depth: 0,
+            prefetcher: None,
+            start_checkpoint: None,
+            walk_forward: false,
+            page_start: (None, false),
+            order: SuiQueryOrder::default(),
+            shard: None,
+            checkpoint_range: None,
+            time_range: None,
+            current_checkpoint: None,
+            finished: false,
+            draining: false,
         }
     }
 
-    /// Creates a new SuiSource instance using the default Sui Devnet RPC endpoint
+    /// Creates a new SuiSource instance for a well-known or custom network
+    ///
+    /// # Parameters
+    /// * `network` - Network whose fullnode RPC endpoint is used
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `max_transactions` - Maximum number of transactions to fetch per poll
+    pub fn for_network(network: SuiNetwork, interval_ms: u64, max_transactions: usize) -> Self {
+        Self::new(network.rpc_url().to_string(), interval_ms, max_transactions)
+    }
+
+    /// Creates a new SuiSource instance reusing a client built by the application
+    ///
+    /// The client keeps its own settings, e.g. timeouts and connection pool,
+    /// and is reused after the source is closed and initialized again.
+    ///
+    /// # Parameters
+    /// * `client` - Connected Sui client
+    /// * `rpc_url` - RPC endpoint URL the client is connected to, reported in health and logs
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `max_transactions` - Maximum number of transactions to fetch per poll
+    pub fn with_client(
+        client: SuiClient,
+        rpc_url: String,
+        interval_ms: u64,
+        max_transactions: usize,
+    ) -> Self {
+        let mut source = Self::new(rpc_url, interval_ms, max_transactions);
+        source.injected_client = Some(client);
+        source
+    }
+
+    /// Creates a new SuiSource instance using the Sui Mainnet RPC endpoint
+    #[deprecated(note = "use `SuiSource::for_network(SuiNetwork::Mainnet, ..)` instead")]
     pub fn new_with_mainnet(interval_ms: u64, max_transactions: usize) -> Self {
-        Self::new(
-            "https://fullnode.mainnet.sui.io:443".to_string(),
-            interval_ms,
-            max_transactions,
-        )
+        Self::for_network(SuiNetwork::Mainnet, interval_ms, max_transactions)
+    }
+
+    /// Creates a builder for configuring a SuiSource
+    pub fn builder() -> SuiSourceBuilder {
+        SuiSourceBuilder::new()
+    }
+
+    /// Creates a SuiSource configured from environment variables
+    ///
+    /// See [`SuiSourceBuilder::from_env`] for the supported variables.
+    pub fn from_env() -> StreamResult<Self> {
+        SuiSourceBuilder::from_env()?.build()
     }
 
     /// Converts SuiTransactionBlockResponse to SuiEvent
-    fn transaction_to_event(&self, transaction: SuiTransactionBlockResponse) -> SuiEvent {
+    ///
+    /// Transactions that cannot be converted are handed to the dead-letter
+    /// sink and `None` is returned, so callers skip them. In strict mode
+    /// without a dead-letter sink the conversion error is returned instead.
+    /// Failed transactions are skipped too unless they are included, as well
+    /// as system transactions and transactions not signed with zkLogin if
+    /// they are filtered out.
+    pub(crate) fn transaction_to_event(
+        &self,
+        transaction: SuiTransactionBlockResponse,
+    ) -> StreamResult<Option<SuiEvent>> {
+        match self.try_transaction_to_event(&transaction) {
+            Ok(event) if !event.success && !self.include_failed => {
+                tracing::debug!("Skipping failed transaction {}", event.transaction_digest);
+                Ok(None)
+            }
+            Ok(event) if self.skip_system_transactions && event.metadata.is_system() => {
+                tracing::debug!("Skipping system transaction {}", event.transaction_digest);
+                Ok(None)
+            }
+            Ok(event) if self.zklogin_only && event.auth_scheme != Some(AuthScheme::ZkLogin) => {
+                tracing::debug!(
+                    "Skipping transaction {} not signed with zkLogin",
+                    event.transaction_digest
+                );
+                Ok(None)
+            }
+            Ok(event) => Ok(Some(event)),
+            Err(error)
+                if self.conversion_mode == ConversionMode::Strict
+                    && self.dead_letter_sink.is_none() =>
+            {
+                tracing::error!(
+                    "Failed to convert transaction {}: {}",
+                    transaction.digest,
+                    error
+                );
+                Err(StreamError::Serialization(format!(
+                    "Failed to convert transaction {}: {}",
+                    transaction.digest, error
+                )))
+            }
+            Err(error) => {
+                self.dead_letters.fetch_add(1, Ordering::Relaxed);
+                tracing::warn!(
+                    "Skipping transaction {} that cannot be converted: {}",
+                    transaction.digest,
+                    error
+                );
+                if let Some(dead_letter_sink) = &self.dead_letter_sink {
+                    dead_letter_sink.send(SuiDeadLetter { transaction, error });
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    /// Converts a transaction into an event, failing if the response carries no transaction data
+    ///
+    /// In strict mode a missing timestamp, type or sender fails the conversion too.
+    fn try_transaction_to_event(
+        &self,
+        transaction: &SuiTransactionBlockResponse,
+    ) -> Result<SuiEvent, String> {
+        if transaction.transaction.is_none() {
+            return Err("Response carries no transaction data".to_string());
+        }
+        let strict = self.conversion_mode == ConversionMode::Strict;
+
         let digest = transaction.digest.to_string();
-        let timestamp = transaction.timestamp_ms.unwrap_or(0);
+        let timestamp = match transaction.timestamp_ms {
+            Some(timestamp) => timestamp,
+            // Filled in from the checkpoint timestamp before the event is emitted
+            None if strict && transaction.checkpoint.is_none() => {
+                return Err("Response carries no timestamp".to_string());
+            }
+            None => 0,
+        };
 
         // Determine transaction type
         let transaction_type = if let Some(kind) = transaction
@@ -86,6 +457,8 @@ impl SuiSource {
             .map(|tx| tx.data.transaction().name())
         {
             kind.to_string()
+        } else if strict {
+            return Err("Transaction type cannot be determined".to_string());
         } else {
             "unknown".to_string()
         };
@@ -95,141 +468,1281 @@ impl SuiSource {
             .transaction
             .as_ref()
             .map(|tx| tx.data.sender().as_ref())
-            .map(|addr| {
-                SuiAddress::try_from(addr)
-                    .map_err(|_| "Invalid sender address format")
-                    .ok()
-                    .map(|addr| addr.to_string())
-                    .unwrap_or_else(|| "unknown".to_string())
-            })
+            .and_then(|addr| SuiAddress::try_from(addr).ok())
+            .map(|addr| addr.to_string());
+        let sender = match sender {
+            Some(sender) => sender,
+            None if strict => return Err("Invalid sender address format".to_string()),
+            None => "unknown".to_string(),
+        };
+
+        let gas_owner = transaction
+            .transaction
+            .as_ref()
+            .map(|tx| tx.data.gas_data().owner.to_string())
             .unwrap_or_else(|| "unknown".to_string());
+        let is_sponsored = gas_owner != sender;
+
+        let auth_scheme = transaction
+            .transaction
+            .as_ref()
+            .and_then(|tx| AuthScheme::of_sender(&tx.tx_signatures, *tx.data.sender()));
 
         let metadata = transaction
             .transaction
             .as_ref()
-            .map(|tx| format!("{:?}", tx.data))
-            .unwrap_or_else(|| "unknown".to_string());
+            .map(|tx| TransactionMetadata::from_rpc(tx.data.transaction()))
+            .unwrap_or_default();
+
+        let raw = if self.include_raw_json {
+            let raw = serde_json::to_value(transaction)
+                .map_err(|e| format!("Response cannot be serialized: {}", e))?;
+            Some(raw)
+        } else {
+            None
+        };
+
+        // Simple transfers pay exactly one address besides the sender
+        let transfers = SuiCoinTransfer::decode(transaction);
+        let recipient = match transfers.split_first() {
+            Some((first, rest)) if rest.iter().all(|t| t.recipient == first.recipient) => {
+                Some(first.recipient.clone())
+            }
+            _ => None,
+        };
+        // Amounts of different coin types cannot be added up
+        let amount = match transfers.split_first() {
+            Some((first, rest)) if rest.iter().all(|t| t.coin_type == first.coin_type) => {
+                u64::try_from(transfers.iter().map(|t| t.amount).sum::<u128>()).ok()
+            }
+            _ => None,
+        };
+
+        let gas = transaction
+            .effects
+            .as_ref()
+            .map(|effects| effects.gas_cost_summary().clone())
+            .unwrap_or_default();
 
-        // Try to extract recipient and amount (if applicable)
-        let (recipient, amount) = (None, None);
+        let shared_objects: Vec<String> = transaction
+            .effects
+            .iter()
+            .flat_map(|effects| effects.shared_objects())
+            .map(|object| object.object_id.to_string())
+            .collect();
 
-        SuiEvent {
+        let effects_count = |count: fn(&SuiTransactionBlockEffects) -> usize| {
+            transaction.effects.as_ref().map_or(0, count)
+        };
+
+        // Without effects the transaction is assumed to have succeeded
+        let execution_error = match transaction.effects.as_ref().map(|effects| effects.status()) {
+            Some(SuiExecutionStatus::Failure { error }) => Some(error.clone()),
+            _ => None,
+        };
+
+        Ok(SuiEvent {
             transaction_digest: digest,
             transaction_type,
             timestamp,
             sender,
             recipient,
+            sender_label: None,
+            recipient_label: None,
             amount,
+            computation_cost: gas.computation_cost,
+            storage_cost: gas.storage_cost,
+            storage_rebate: gas.storage_rebate,
+            gas_fee: gas.net_gas_usage(),
+            is_sponsored,
+            auth_scheme,
+            gas_owner,
+            success: execution_error.is_none(),
+            execution_error,
+            events: transaction
+                .events
+                .iter()
+                .flat_map(|events| &events.data)
+                .map(|event| MoveEventSummary {
+                    bcs: self.include_bcs.then(|| event.bcs.bytes().to_vec()),
+                    ..MoveEventSummary::from(event)
+                })
+                .collect(),
+            balance_changes: transaction
+                .balance_changes
+                .iter()
+                .flatten()
+                .map(BalanceChange::from)
+                .collect(),
+            object_changes: transaction
+                .object_changes
+                .iter()
+                .flatten()
+                .map(|change| SuiObjectChange::new(transaction, change).into())
+                .collect(),
+            uses_shared_objects: !shared_objects.is_empty(),
+            shared_objects,
+            created_count: effects_count(|effects| effects.created().len()),
+            mutated_count: effects_count(|effects| effects.mutated().len()),
+            deleted_count: effects_count(|effects| effects.deleted().len()),
+            events_count: transaction
+                .events
+                .as_ref()
+                .map_or(0, |events| events.data.len()),
             metadata,
+            raw_transaction: (self.include_bcs && !transaction.raw_transaction.is_empty())
+                .then(|| transaction.raw_transaction.clone()),
+            raw,
+        })
+    }
+
+    /// Returns the number of transactions skipped because they could not be converted
+    pub fn dead_letter_count(&self) -> u64 {
+        self.dead_letters.load(Ordering::Relaxed)
+    }
+
+    /// Builds the client of an endpoint, retrying failed attempts
+    async fn connect(&mut self, index: usize) -> StreamResult<SuiClient> {
+        if let Some(client) = self.endpoints.client_at(index) {
+            return Ok(client);
+        }
+        if index == 0
+            && let Some(client) = &self.injected_client
+        {
+            return Ok(client.clone());
         }
+
+        // The builder is consumed by every attempt
+        let rpc_url = self.endpoints.url(index).to_string();
+        let rate_limiter = self.rate_limiter.as_ref();
+        self.retry_policy
+            .run("Sui client initialization", &mut self.retry_stats, || {
+                let mut builder = SuiClientBuilder::default();
+                if let Some(timeout) = self.request_timeout {
+                    builder = builder.request_timeout(timeout);
+                }
+                if !self.headers.is_empty() {
+                    builder = builder.custom_headers(self.headers.clone());
+                }
+                let rpc_url = rpc_url.as_str();
+                async move {
+                    if let Some(rate_limiter) = rate_limiter {
+                        rate_limiter.acquire().await;
+                    }
+                    builder.build(rpc_url).await
+                }
+            })
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to initialize Sui client for {}: {}", rpc_url, e);
+                rpc_error(e)
+            })
     }
 
-    pub fn is_initialized(&self) -> bool {
-        self.initialized
+    /// Switches to the next reachable fallback endpoint, `false` if there is none
+    async fn fail_over(&mut self) -> bool {
+        for index in self.endpoints.failover_candidates() {
+            match self.connect(index).await {
+                Ok(client) => {
+                    self.endpoints.activate(index, client);
+                    return true;
+                }
+                Err(_) => {
+                    self.endpoints.record_failure(index);
+                }
+            }
+        }
+        false
     }
-}
 
-#[async_trait]
-impl Source<SuiEvent> for SuiSource {
-    async fn init(&mut self) -> StreamResult<()> {
-        if self.initialized {
+    /// Fails over if the circuit breaker of the active endpoint is open
+    ///
+    /// Returns an error without sending a request if every circuit is open.
+    async fn ensure_available(&mut self) -> StreamResult<()> {
+        let active = self.endpoints.active();
+        if self.endpoints.is_available(active) || self.fail_over().await {
             return Ok(());
         }
+        Err(StreamError::Runtime(format!(
+            "Circuit breaker open for RPC endpoint {}",
+            self.endpoints.url(active)
+        )))
+    }
+
+    /// Queries the page of transactions following the cursor
+    ///
+    /// Failed requests are retried according to the retry policy. Depending
+    /// on the load balancing strategy the query then fails over to the
+    /// fallback endpoints, or is spread across all endpoints.
+    pub(crate) async fn query_page(
+        &mut self,
+        filter: Option<TransactionFilter>,
+        cursor: Option<TransactionDigest>,
+        descending_order: bool,
+    ) -> StreamResult<TransactionBlocksPage> {
+        if self.endpoints.is_balanced() {
+            return self.query_balanced(filter, cursor, descending_order).await;
+        }
+
+        // Give the primary endpoint another chance once it had time to recover
+        if self.endpoints.should_recover() {
+            match self.connect(0).await {
+                Ok(client) => {
+                    tracing::info!("Returning to primary RPC endpoint {}", self.rpc_url);
+                    self.endpoints.activate(0, client);
+                }
+                Err(_) => {
+                    self.endpoints.record_failure(0);
+                }
+            }
+        }
+
+        let mut attempts = 0;
+        loop {
+            self.ensure_available().await?;
+            let started = Instant::now();
+            match self
+                .query_active(filter.clone(), cursor, descending_order)
+                .await
+            {
+                Ok(page) => {
+                    self.endpoints.record_success(started.elapsed());
+                    return Ok(page);
+                }
+                Err(e) => {
+                    attempts += 1;
+                    let active = self.endpoints.active();
+                    if !self.endpoints.record_failure(active)
+                        || attempts >= self.endpoints.len()
+                        || !self.fail_over().await
+                    {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+    }
 
-        // Initialize Sui client
-        let client = SuiClientBuilder::default()
-            .build(self.rpc_url.as_str())
+    /// Queries the page of transactions following the cursor on the endpoint
+    /// picked by the load balancing strategy, moving on to the next pick on failure
+    async fn query_balanced(
+        &mut self,
+        filter: Option<TransactionFilter>,
+        cursor: Option<TransactionDigest>,
+        descending_order: bool,
+    ) -> StreamResult<TransactionBlocksPage> {
+        let mut tried = Vec::new();
+        loop {
+            let index = self.endpoints.select(&tried);
+            tried.push(index);
+            // Only picked once every endpoint left is unavailable
+            if !self.endpoints.is_available(index) {
+                return Err(StreamError::Runtime(
+                    "Circuit breaker open for every RPC endpoint".to_string(),
+                ));
+            }
+
+            let started = Instant::now();
+            let result = match self.connect(index).await {
+                Ok(client) => {
+                    self.endpoints.activate(index, client);
+                    self.query_active(filter.clone(), cursor, descending_order)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(page) => {
+                    self.endpoints.record_success(started.elapsed());
+                    return Ok(page);
+                }
+                Err(e) => {
+                    self.endpoints.record_failure(index);
+                    if tried.len() >= self.endpoints.len() {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Queries the page of transactions following the cursor on the active endpoint
+    async fn query_active(
+        &mut self,
+        filter: Option<TransactionFilter>,
+        cursor: Option<TransactionDigest>,
+        descending_order: bool,
+    ) -> StreamResult<TransactionBlocksPage> {
+        let client = self
+            .endpoints
+            .client()
+            .ok_or_else(|| StreamError::Runtime("SuiSource client not available".to_string()))?;
+
+        let query = SuiTransactionBlockResponseQuery::new(filter, Some(self.options.clone()));
+        let limit = Some(self.max_transactions);
+        let rate_limiter = self.rate_limiter.as_ref();
+        self.retry_policy
+            .run("Transaction query", &mut self.retry_stats, move || {
+                let query = query.clone();
+                async move {
+                    if let Some(rate_limiter) = rate_limiter {
+                        rate_limiter.acquire().await;
+                    }
+                    client
+                        .read_api()
+                        .query_transaction_blocks(query, cursor, limit, descending_order)
+                        .await
+                }
+            })
             .await
             .map_err(|e| {
-                tracing::error!("Failed to initialize Sui client: {}", e);
-                StreamError::Runtime(e.to_string())
-            })?;
+                tracing::error!("Failed to fetch transactions: {}", e);
+                rpc_error(e)
+            })
+    }
 
-        self.client = Some(client);
-        self.initialized = true;
-        tracing::info!("SuiSource initialized with RPC URL: {}", self.rpc_url);
+    /// Takes the next page from the prefetcher, spawning it on the active endpoint if needed
+    ///
+    /// A failed prefetch stops the prefetcher, and the next call starts over
+    /// from the cursor, on a fallback endpoint if the failover threshold was crossed.
+    async fn prefetch_page(&mut self) -> StreamResult<TransactionBlocksPage> {
+        let mut prefetcher = match self.prefetcher.take() {
+            Some(prefetcher) => prefetcher,
+            None => {
+                self.ensure_available().await?;
+                self.spawn_prefetcher()?
+            }
+        };
+
+        match prefetcher.next_page().await {
+            Ok(prefetched) => {
+                self.prefetcher = Some(prefetcher);
+                self.retry_stats.add(prefetched.stats);
+                self.endpoints.record_success(prefetched.latency);
+                Ok(prefetched.page)
+            }
+            Err(e) => {
+                if self.endpoints.record_failure(self.endpoints.active()) {
+                    self.fail_over().await;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Spawns a prefetcher walking forward from the cursor on the active endpoint
+    fn spawn_prefetcher(&self) -> StreamResult<Prefetcher> {
+        let client = self
+            .client()
+            .cloned()
+            .ok_or_else(|| StreamError::Runtime("SuiSource client not available".to_string()))?;
+
+        let query = PrefetchQuery {
+            client,
+            query: SuiTransactionBlockResponseQuery::new(
+                self.filter.clone(),
+                Some(self.options.clone()),
+            ),
+            cursor: self.cursor,
+            limit: self.max_transactions,
+            interval: self.interval,
+            adaptive_interval: self.adaptive_interval.clone(),
+            retry_policy: self.retry_policy.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+        };
+        Ok(Prefetcher::spawn(self.prefetch_depth, query))
+    }
+
+    /// Fetches the next page of transactions and buffers them in chronological order
+    ///
+    /// The first fetch anchors the cursor at the newest transactions on the
+    /// network, or at the start checkpoint if one is set. Every following
+    /// fetch walks forward from the persisted cursor, so no transaction is
+    /// skipped no matter how many land between polls. Other orders query every
+    /// page in the configured direction, see [`SuiQueryOrder`].
+    async fn fetch_batch(&mut self) -> StreamResult<()> {
+        if let Some((start, end)) = self.checkpoint_range {
+            return self.fetch_checkpoint_range(start, end).await;
+        }
+
+        if let Some(start_checkpoint) = self.start_checkpoint {
+            self.cursor = self.checkpoint_cursor(start_checkpoint).await?;
+            self.walk_forward = true;
+            self.start_checkpoint = None;
+            tracing::info!("Resuming from checkpoint {}", start_checkpoint);
+        }
+
+        // Without a cursor start from the newest transactions, otherwise walk forward
+        let anchoring =
+            self.order == SuiQueryOrder::Latest && self.cursor.is_none() && !self.walk_forward;
+        let descending_order = anchoring || self.order == SuiQueryOrder::Descending;
+        self.page_start = (self.cursor, self.walk_forward);
+        let page = if self.prefetch_depth > 0 && !descending_order {
+            self.prefetch_page().await?
+        } else {
+            self.query_page(self.filter.clone(), self.cursor, descending_order)
+                .await?
+        };
+
+        self.has_next_page = !anchoring && page.has_next_page;
+        if self.order == SuiQueryOrder::Descending {
+            // Walking backward ends at genesis
+            if !self.has_next_page {
+                tracing::info!("Reached the oldest transaction, history fully ingested");
+                self.finished = true;
+            }
+        } else if !self.has_next_page {
+            // Walking forward with a single cursor, checkpoints produced while
+            // backfilling are picked up by later pages, so the switch to live
+            // tailing has neither gaps nor duplicates
+            self.handle.set_phase(SuiIngestionPhase::Live);
+        }
+
+        let mut transactions = page.data;
+        if anchoring {
+            transactions.reverse();
+        }
+
+        if let Some(adaptive_interval) = &self.adaptive_interval {
+            self.interval = adaptive_interval.next_interval(
+                self.interval,
+                transactions.len(),
+                self.max_transactions,
+            );
+        }
+
+        // Nothing to buffer if no new transactions
+        let Some(latest_transaction) = transactions.last() else {
+            tracing::info!("No new transactions found");
+            return Ok(());
+        };
+
+        // Persist the cursor so the next page continues right after this one
+        self.cursor = Some(latest_transaction.digest);
+        self.last_processed_checkpoint = latest_transaction.checkpoint;
+
+        tracing::debug!(
+            "Buffered {} new Sui transactions, has next page: {}",
+            transactions.len(),
+            self.has_next_page
+        );
+        self.buffer.extend(transactions);
 
         Ok(())
     }
 
-    async fn next(&mut self) -> StreamResult<Option<Record<SuiEvent>>> {
+    /// Fetches the next page of the checkpoint currently being ingested and
+    /// moves on to the following checkpoint once it is exhausted
+    async fn fetch_checkpoint_range(
+        &mut self,
+        start: CheckpointSequenceNumber,
+        end: CheckpointSequenceNumber,
+    ) -> StreamResult<()> {
+        let mut checkpoint = *self.current_checkpoint.get_or_insert(start);
+        // Checkpoints of other shards are not fetched at all
+        if let Some(shard) = &self.shard
+            && self.cursor.is_none()
+        {
+            checkpoint = shard.next_checkpoint(checkpoint);
+            self.current_checkpoint = Some(checkpoint);
+            if checkpoint > end {
+                tracing::info!("Checkpoint range {}..={} fully ingested", start, end);
+                self.finished = true;
+                self.has_next_page = false;
+                return Ok(());
+            }
+        }
+
+        let page = self
+            .query_page(
+                Some(TransactionFilter::Checkpoint(checkpoint)),
+                self.cursor,
+                false,
+            )
+            .await?;
+
+        // Every checkpoint holds at least one transaction, an empty first page
+        // means the checkpoint has not been produced yet. An empty page after
+        // a cursor, e.g. after rewinding to the last transaction of a
+        // checkpoint, means the checkpoint is exhausted.
+        let Some(latest_transaction) = page.data.last() else {
+            if self.cursor.take().is_some() {
+                if checkpoint >= end {
+                    tracing::info!("Checkpoint range {}..={} fully ingested", start, end);
+                    self.finished = true;
+                } else {
+                    self.current_checkpoint = Some(checkpoint + 1);
+                }
+                self.has_next_page = !self.finished;
+            } else {
+                tracing::info!("Checkpoint {} not available yet", checkpoint);
+                self.has_next_page = false;
+            }
+            return Ok(());
+        };
+
+        self.last_processed_checkpoint = Some(checkpoint);
+
+        if page.has_next_page {
+            self.cursor = Some(latest_transaction.digest);
+        } else if checkpoint >= end {
+            tracing::info!("Checkpoint range {}..={} fully ingested", start, end);
+            self.cursor = None;
+            self.finished = true;
+        } else {
+            self.cursor = None;
+            self.current_checkpoint = Some(checkpoint + 1);
+        }
+        self.has_next_page = !self.finished;
+
+        tracing::debug!(
+            "Buffered {} transactions from checkpoint {}",
+            page.data.len(),
+            checkpoint
+        );
+        self.buffer.extend(page.data);
+
+        Ok(())
+    }
+
+    /// Latest watermark, `None` until a checkpoint was fully emitted or if
+    /// watermarks are disabled, see [`crate::SuiSourceBuilder::watermarks`]
+    pub fn watermark(&self) -> Option<SuiWatermark> {
+        self.watermarks.as_ref()?.current()
+    }
+
+    /// Subscribes to watermark updates from other tasks, `None` if watermarks are disabled
+    pub fn watermark_receiver(&self) -> Option<watch::Receiver<Option<SuiWatermark>>> {
+        Some(self.watermarks.as_ref()?.subscribe())
+    }
+
+    /// Whether the configured checkpoint range, or the whole history when
+    /// walking in descending order, has been fully emitted
+    pub fn is_finished(&self) -> bool {
+        self.finished && self.buffer.is_empty()
+    }
+
+    /// Returns the next raw transaction, polling the RPC once the buffer is drained
+    ///
+    /// Fails if the transaction is not available within the `next()` deadline,
+    /// so a hung fullnode cannot stall the pipeline. Returns `Ok(None)` while paused.
+    pub(crate) async fn next_transaction(
+        &mut self,
+    ) -> StreamResult<Option<SuiTransactionBlockResponse>> {
+        if !self.wait_while_paused().await {
+            return Ok(None);
+        }
+
+        let next_timeout = self.next_timeout;
+        with_next_timeout(next_timeout, self.poll_transaction()).await
+    }
+
+    /// Idles while paused, giving the caller a chance to shut down every interval
+    ///
+    /// Returns `false` if the source is still paused after one polling interval.
+    pub(crate) async fn wait_while_paused(&self) -> bool {
+        self.draining || !self.handle.is_paused() || self.handle.wait_resumed(self.interval).await
+    }
+
+    /// Returns the next raw transaction without a deadline
+    async fn poll_transaction(&mut self) -> StreamResult<Option<SuiTransactionBlockResponse>> {
         // Ensure initialized
-        if !self.initialized || self.client.is_none() {
+        if !self.initialized || self.endpoints.client().is_none() {
             return Err(StreamError::Runtime(
                 "SuiSource not initialized".to_string(),
             ));
         }
 
-        // Polling interval
-        sleep(self.interval).await;
+        loop {
+            // Only hit the RPC once everything fetched previously has been emitted
+            if self.buffer.is_empty() && !self.finished && !self.draining {
+                self.save_cursor().await?;
+
+                if self.apply_update().is_some() {
+                    tracing::warn!("Address watchlists only apply to SuiMultiAddressSource");
+                }
+
+                // Polling interval, skipped while catching up on pending pages
+                // and left to the prefetcher while it is running
+                if !self.has_next_page && self.prefetcher.is_none() {
+                    sleep(self.interval).await;
+                }
+                if let Err(e) = self.fetch_batch().await {
+                    self.consecutive_errors += 1;
+                    return Err(e);
+                }
+                self.consecutive_errors = 0;
+                self.last_successful_fetch = Some(now_millis());
 
+                if self
+                    .last_lag_check
+                    .is_none_or(|checked| checked.elapsed() >= self.lag_check_interval)
+                {
+                    // The lag is informational, failing to measure it must not stop ingestion
+                    if let Err(e) = self.refresh_lag().await {
+                        tracing::warn!("Failed to measure consumer lag: {}", e);
+                    }
+                }
+            }
+
+            let transaction = self.buffer.pop_front();
+            if self.is_finished() {
+                self.handle.mark_finished();
+            }
+            let Some(transaction) = transaction else {
+                return Ok(None);
+            };
+
+            // Re-fetched or re-ordered pages may contain transactions emitted before
+            if self.seen_digests.contains(&transaction.digest) {
+                tracing::debug!("Skipping duplicate transaction {}", transaction.digest);
+                continue;
+            }
+
+            // Transactions without checkpoint are left to the first shard
+            if let Some(shard) = &self.shard
+                && !shard.contains(transaction.checkpoint.unwrap_or(shard.count))
+            {
+                continue;
+            }
+
+            if let Some(missing) = self.missing_checkpoint(&transaction) {
+                match self.gap_policy {
+                    GapPolicy::Fail => {
+                        return Err(StreamError::Runtime(format!(
+                            "Checkpoint gap detected, checkpoint {} is missing",
+                            missing
+                        )));
+                    }
+                    GapPolicy::Skip => {
+                        tracing::warn!(
+                            "Checkpoint gap detected, skipping checkpoints {}..{}",
+                            missing,
+                            transaction.checkpoint.unwrap_or(missing)
+                        );
+                    }
+                    // Backfill one checkpoint at a time, the next iteration
+                    // detects whatever is still missing
+                    GapPolicy::Backfill => {
+                        tracing::warn!(
+                            "Checkpoint gap detected, backfilling checkpoint {}",
+                            missing
+                        );
+                        let backfilled = self.fetch_checkpoint_transactions(missing).await?;
+                        self.buffer.push_front(transaction);
+                        for backfilled in backfilled.into_iter().rev() {
+                            self.buffer.push_front(backfilled);
+                        }
+                        self.last_emitted_checkpoint = Some(missing);
+                        continue;
+                    }
+                }
+            }
+
+            self.previous_emitted = (self.last_emitted_digest, self.last_emitted_checkpoint);
+            if let Some(checkpoint) = transaction.checkpoint {
+                self.last_emitted_checkpoint = Some(checkpoint);
+            }
+            self.last_emitted_digest = Some(transaction.digest);
+            self.cursor_dirty = true;
+            self.seen_digests.put(transaction.digest, ());
+            return Ok(Some(transaction));
+        }
+    }
+
+    /// Returns how many checkpoints the last emitted transaction is behind the network
+    ///
+    /// Based on the latest network checkpoint seen by the last lag check,
+    /// `None` until a transaction was emitted and the lag was measured.
+    pub fn lag(&self) -> Option<u64> {
+        let latest = self.latest_network_checkpoint?;
+        let processed = self.last_emitted_checkpoint?;
+        Some(latest.saturating_sub(processed))
+    }
+
+    /// Fetches the latest network checkpoint and returns the updated lag
+    ///
+    /// Called automatically every lag check interval while polling.
+    pub async fn refresh_lag(&mut self) -> StreamResult<Option<u64>> {
         let client = self
-            .client
-            .as_ref()
+            .client()
+            .cloned()
+            .ok_or_else(|| StreamError::Runtime("SuiSource not initialized".to_string()))?;
+
+        self.last_lag_check = Some(Instant::now());
+        self.throttle().await;
+        let latest = client
+            .read_api()
+            .get_latest_checkpoint_sequence_number()
+            .await
+            .map_err(rpc_error)?;
+        self.latest_network_checkpoint = Some(latest);
+
+        let lag = self.lag();
+        if let Some(lag) = lag {
+            tracing::debug!(
+                lag,
+                latest_checkpoint = latest,
+                processed_checkpoint = self.last_emitted_checkpoint,
+                "Consumer lag"
+            );
+        }
+        Ok(lag)
+    }
+
+    /// Saves the last emitted transaction to the cursor store, if any
+    async fn save_cursor(&mut self) -> StreamResult<()> {
+        let (Some(cursor_store), Some(digest)) = (&self.cursor_store, self.last_emitted_digest)
+        else {
+            return Ok(());
+        };
+        if !self.cursor_dirty {
+            return Ok(());
+        }
+
+        cursor_store
+            .save(&SuiCursor {
+                digest,
+                checkpoint: self.last_emitted_checkpoint,
+            })
+            .await?;
+        self.cursor_dirty = false;
+        tracing::debug!("Saved cursor at transaction {}", digest);
+
+        Ok(())
+    }
+
+    /// Returns the first checkpoint missing between the last emitted transaction and this one
+    fn missing_checkpoint(
+        &self,
+        transaction: &SuiTransactionBlockResponse,
+    ) -> Option<CheckpointSequenceNumber> {
+        // Filters and shards legitimately skip checkpoints and checkpoint
+        // ranges are walked without gaps
+        if self.filter.is_some() || self.shard.is_some() || self.checkpoint_range.is_some() {
+            return None;
+        }
+
+        let last = self.last_emitted_checkpoint?;
+        let checkpoint = transaction.checkpoint?;
+        (checkpoint > last + 1).then_some(last + 1)
+    }
+
+    /// Returns the query cursor right before the first transaction of a checkpoint
+    ///
+    /// The cursor is exclusive, so it points at the last transaction of the
+    /// previous checkpoint. Checkpoint 0 starts without a cursor.
+    async fn checkpoint_cursor(
+        &mut self,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> StreamResult<Option<TransactionDigest>> {
+        let Some(previous) = checkpoint.checked_sub(1) else {
+            return Ok(None);
+        };
+
+        let client = self
+            .client()
+            .cloned()
             .ok_or_else(|| StreamError::Runtime("SuiSource client not available".to_string()))?;
 
-        // Set transaction query options
-        let options = SuiTransactionBlockResponseOptions::new()
-            .with_input()
-            .with_effects()
-            .with_events()
-            .with_balance_changes();
+        self.throttle().await;
+        let summary = client
+            .read_api()
+            .get_checkpoint(CheckpointId::SequenceNumber(previous))
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch checkpoint {}: {}", previous, e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        Ok(summary.transactions.last().copied())
+    }
 
-        // Get recent transactions
-        let transactions = client
+    /// Fetches all transactions of a checkpoint in execution order
+    async fn fetch_checkpoint_transactions(
+        &mut self,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> StreamResult<Vec<SuiTransactionBlockResponse>> {
+        let client = self
+            .client()
+            .cloned()
+            .ok_or_else(|| StreamError::Runtime("SuiSource client not available".to_string()))?;
+
+        self.throttle().await;
+        let summary = client
             .read_api()
-            .query_transaction_blocks(
-                SuiTransactionBlockResponseQuery::new(None, Some(options)),
-                None,
-                Some(self.max_transactions),
-                true,
-            )
+            .get_checkpoint(CheckpointId::SequenceNumber(checkpoint))
             .await
             .map_err(|e| {
-                tracing::error!("Failed to fetch transactions: {}", e);
+                tracing::error!("Failed to fetch checkpoint {}: {}", checkpoint, e);
                 StreamError::Runtime(e.to_string())
             })?;
 
-        // Return None if no new transactions
-        if transactions.data.is_empty() {
-            tracing::info!("No new transactions found");
+        let transactions = self
+            .hydrate_transactions(&client, &summary.transactions)
+            .await?;
+
+        tracing::debug!(
+            "Backfilled {} transactions from checkpoint {}",
+            transactions.len(),
+            checkpoint
+        );
+        Ok(transactions)
+    }
+
+    /// Sets the timestamp of an event without one to that of its checkpoint
+    ///
+    /// The lookup is cached per checkpoint. Events whose checkpoint cannot be
+    /// fetched keep a zero timestamp.
+    async fn fill_timestamp(
+        &mut self,
+        event: &mut SuiEvent,
+        checkpoint: Option<CheckpointSequenceNumber>,
+    ) {
+        let Some(checkpoint) = checkpoint.filter(|_| event.timestamp == 0) else {
+            return;
+        };
+        if let Some((cached, timestamp)) = self.checkpoint_timestamp
+            && cached == checkpoint
+        {
+            event.timestamp = timestamp;
+            return;
+        }
+
+        let Some(client) = self.client().cloned() else {
+            return;
+        };
+        self.throttle().await;
+        match client
+            .read_api()
+            .get_checkpoint(CheckpointId::SequenceNumber(checkpoint))
+            .await
+        {
+            Ok(summary) => {
+                self.checkpoint_timestamp = Some((checkpoint, summary.timestamp_ms));
+                event.timestamp = summary.timestamp_ms;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to fetch timestamp of checkpoint {}: {}",
+                    checkpoint,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Converts a transaction and runs the event through timestamping,
+    /// watermarks, enrichment, labeling and the client-side filters
+    ///
+    /// Returns `None` if the transaction was skipped, dead-lettered or filtered out.
+    pub(crate) async fn process_transaction(
+        &mut self,
+        transaction: SuiTransactionBlockResponse,
+    ) -> StreamResult<Option<SuiEvent>> {
+        let digest = transaction.digest.to_string();
+        let checkpoint = transaction.checkpoint;
+        let Some(mut event) = self.transaction_to_event(transaction)? else {
+            return Ok(None);
+        };
+        self.fill_timestamp(&mut event, checkpoint).await;
+        if let Some(watermarks) = &mut self.watermarks {
+            if let Some(checkpoint) = checkpoint {
+                watermarks.observe(checkpoint, event.timestamp);
+            }
+            if self.finished && self.buffer.is_empty() {
+                watermarks.finish();
+            }
+        }
+        self.enrich_coin_metadata(&mut event).await;
+        if let Some(labeler) = &self.address_labeler {
+            label_event(labeler.as_ref(), &mut event).await;
+        }
+        if !self.matches_filters(&event).await {
+            tracing::debug!("Filtered out Sui transaction: {}", digest);
             return Ok(None);
         }
+        tracing::info!(
+            "Processed Sui transaction: {} checkpoint: {:?}",
+            digest,
+            checkpoint
+        );
 
-        // Get latest transaction
-        let latest_transaction = transactions
-            .data
-            .first()
-            .ok_or_else(|| StreamError::Runtime("Failed to get first transaction".to_string()))?;
-        let latest_digest = latest_transaction.digest.to_string();
+        Ok(Some(event))
+    }
 
-        // Return None if transaction already processed
-        if let Some(last_digest) = &self.last_processed_digest {
-            if last_digest == &latest_digest {
-                tracing::info!("No new transactions since last check");
-                return Ok(None);
+    /// Records a transaction fetched on behalf of a wrapping source for
+    /// deduplication and lag, `false` if it was emitted before
+    pub(crate) fn record_emitted(&mut self, transaction: &SuiTransactionBlockResponse) -> bool {
+        if self.seen_digests.put(transaction.digest, ()).is_some() {
+            return false;
+        }
+        if let Some(checkpoint) = transaction.checkpoint {
+            self.last_emitted_checkpoint = Some(checkpoint);
+        }
+        self.last_emitted_digest = Some(transaction.digest);
+        true
+    }
+
+    /// Whether a transaction was emitted recently, by this source or a wrapping one
+    pub(crate) fn was_emitted(&self, digest: &TransactionDigest) -> bool {
+        self.seen_digests.contains(digest)
+    }
+
+    /// Hands back the last transaction returned by `next_transaction`, which a
+    /// wrapping source did not finish emitting
+    ///
+    /// The transaction no longer counts as emitted, so `close` rewinds to
+    /// before it and the cursor saved on close does not skip it.
+    pub(crate) fn requeue(&mut self, transaction: SuiTransactionBlockResponse) {
+        if self.last_emitted_digest == Some(transaction.digest) {
+            (self.last_emitted_digest, self.last_emitted_checkpoint) = self.previous_emitted;
+        }
+        self.seen_digests.pop(&transaction.digest);
+        self.buffer.push_front(transaction);
+    }
+
+    /// Makes polling continue right after a transaction emitted by a wrapping source
+    pub(crate) fn resume_after(&mut self, digest: TransactionDigest) {
+        // Pages prefetched from the previous cursor are stale, dropping the prefetcher aborts it
+        self.prefetcher = None;
+        self.cursor = Some(digest);
+        self.walk_forward = true;
+        self.cursor_dirty = true;
+    }
+
+    /// Whether polled transactions are waiting to be emitted
+    pub(crate) fn has_buffered(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+
+    /// Whether polling reached the chain head in a fetch made at or after
+    /// the given time and everything fetched has been emitted
+    pub(crate) fn is_caught_up(&self, since_ms: u64) -> bool {
+        self.buffer.is_empty()
+            && !self.has_next_page
+            && self
+                .last_successful_fetch
+                .is_some_and(|fetched| fetched >= since_ms)
+    }
+
+    /// Whether an event meets every client-side filter
+    async fn matches_filters(&self, event: &SuiEvent) -> bool {
+        for filter in &self.event_filters {
+            if !filter.matches(event).await {
+                return false;
             }
         }
+        true
+    }
 
-        // Update last processed digest
-        self.last_processed_digest = Some(latest_digest.clone());
-        self.last_processed_checkpoint = latest_transaction.checkpoint;
+    /// Sets the number of recently emitted digests remembered for deduplication
+    pub(crate) fn set_dedup_window(&mut self, window: NonZeroUsize) {
+        self.seen_digests.resize(window);
+    }
+
+    /// Waits for the rate limiter before an RPC request made on behalf of this source
+    pub(crate) async fn throttle(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+    }
+
+    /// Returns the current polling interval, which changes over time in adaptive mode
+    pub fn current_interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns the number of RPC requests retried so far
+    pub fn retry_count(&self) -> u64 {
+        self.retry_stats.retries
+    }
 
-        // Convert to event and return
-        let event = self.transaction_to_event(latest_transaction.clone());
+    /// Returns the number of rate-limit responses received from RPC nodes so far
+    pub fn rate_limited_count(&self) -> u64 {
+        self.retry_stats.rate_limited
+    }
+
+    /// Applies the configuration changes pushed through the handle
+    ///
+    /// Returns the new address watchlist, which is left to the wrapping source.
+    pub(crate) fn apply_update(&mut self) -> Option<Vec<SuiAddress>> {
+        let update = self.handle.take_update()?;
+
+        if let Some(interval) = update.interval {
+            tracing::info!("Polling interval changed to {:?}", interval);
+            self.interval = interval;
+        }
+        if let Some(max_transactions) = update.max_transactions {
+            if (1..=MAX_PAGE_SIZE).contains(&max_transactions) {
+                tracing::info!(
+                    "Maximum transactions per poll changed to {}",
+                    max_transactions
+                );
+                self.max_transactions = max_transactions;
+            } else {
+                tracing::warn!(
+                    "Ignoring max_transactions {}, must be between 1 and {}",
+                    max_transactions,
+                    MAX_PAGE_SIZE
+                );
+            }
+        }
+        if let Some(filter) = update.filter {
+            if self.checkpoint_range.is_some() {
+                tracing::warn!("Ignoring filter update, checkpoint ranges cannot be filtered");
+            } else {
+                // The cursor stays valid across filters, so no transaction is replayed
+                tracing::info!("Transaction filter changed to {:?}", filter);
+                self.filter = filter;
+            }
+        }
+
+        // The prefetcher captured the previous configuration
+        self.prefetcher = None;
+
+        update.addresses
+    }
+
+    /// Emits the transactions fetched but not emitted yet, without polling for more
+    ///
+    /// Call before `close` to flush in-flight records on shutdown. Events are
+    /// converted, filtered and enriched like in `next`, and the cursor saved
+    /// on close moves past them. Pages prefetched in the background are not
+    /// drained, they are fetched again after a restart.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiEvent>>> {
+        self.draining = true;
+        let mut records = Vec::new();
+        let result = loop {
+            match self.next().await {
+                Ok(Some(record)) => records.push(record),
+                Ok(None) if self.buffer.is_empty() => break Ok(records),
+                Ok(None) => {}
+                Err(e) => break Err(e),
+            }
+        };
+        self.draining = false;
+        result
+    }
+
+    /// Whether the source is backfilling history or tailing the network
+    pub fn phase(&self) -> SuiIngestionPhase {
+        self.handle.phase()
+    }
+
+    /// Returns a handle pausing, resuming and reconfiguring the source from other tasks
+    pub fn handle(&self) -> SuiSourceHandle {
+        self.handle.clone()
+    }
+
+    /// Pauses ingestion, see [`SuiSourceHandle::pause`]
+    pub fn pause(&self) {
+        self.handle.pause();
+    }
+
+    /// Resumes ingestion, see [`SuiSourceHandle::resume`]
+    pub fn resume(&self) {
+        self.handle.resume();
+    }
+
+    /// Whether ingestion is paused
+    pub fn is_paused(&self) -> bool {
+        self.handle.is_paused()
+    }
+
+    /// Returns the health of the source
+    pub fn health(&self) -> SuiSourceHealth {
+        SuiSourceHealth {
+            connected: self.initialized && self.client().is_some(),
+            last_successful_fetch: self.last_successful_fetch,
+            consecutive_errors: self.consecutive_errors,
+            current_endpoint: self.endpoints.url(self.endpoints.active()).to_string(),
+            lag: self.lag(),
+            endpoints: self.endpoints.health(),
+        }
+    }
+
+    /// Returns the health of the primary and fallback RPC endpoints
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.endpoints.health()
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Returns the Sui client once the source is initialized
+    pub(crate) fn client(&self) -> Option<&SuiClient> {
+        self.endpoints.client()
+    }
+}
+
+#[async_trait]
+impl Source<SuiEvent> for SuiSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        // Start on the primary endpoint, falling back in order if it is unreachable
+        let mut last_error = None;
+        for index in 0..self.endpoints.len() {
+            match self.connect(index).await {
+                Ok(client) => {
+                    self.endpoints.activate(index, client);
+                    last_error = None;
+                    break;
+                }
+                Err(e) => {
+                    self.endpoints.record_failure(index);
+                    last_error = Some(e);
+                }
+            }
+        }
+        if let Some(e) = last_error {
+            return Err(e);
+        }
+
+        if let Some((from, to)) = self.time_range.take() {
+            match self.resolve_time_range(from, to).await? {
+                Some(range) => self.checkpoint_range = Some(range),
+                None => {
+                    tracing::info!("No checkpoint in time range {}..{}", from, to);
+                    self.finished = true;
+                }
+            }
+        }
+
+        // A saved cursor takes precedence over the configured start position
+        if let Some(cursor_store) = &self.cursor_store
+            && self.checkpoint_range.is_none()
+            && !self.finished
+            && let Some(cursor) = cursor_store.load().await?
+        {
+            tracing::info!("Resuming after saved transaction {}", cursor.digest);
+            self.cursor = Some(cursor.digest);
+            self.walk_forward = true;
+            self.start_checkpoint = None;
+            self.last_emitted_digest = Some(cursor.digest);
+            self.last_emitted_checkpoint = cursor.checkpoint;
+        }
+
+        if self.checkpoint_range.is_some()
+            || self.start_checkpoint.is_some()
+            || self.cursor.is_some()
+            || self.finished
+        {
+            self.handle.set_phase(SuiIngestionPhase::Backfill);
+        }
+
+        self.initialized = true;
         tracing::info!(
-            "Processed Sui transaction: {} checkpoint: {:?}",
-            latest_digest,
-            latest_transaction.checkpoint
+            "SuiSource initialized with RPC URL: {}",
+            self.endpoints.url(self.endpoints.active())
         );
 
-        Ok(Some(Record::new(event)))
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiEvent>>> {
+        loop {
+            let Some(transaction) = self.next_transaction().await? else {
+                return Ok(None);
+            };
+            if let Some(event) = self.process_transaction(transaction).await? {
+                return Ok(Some(event_record(event)));
+            }
+        }
     }
 
     async fn close(&mut self) -> StreamResult<()> {
+        if let Some(prefetcher) = self.prefetcher.take() {
+            prefetcher.shutdown().await;
+        }
+        self.save_cursor().await?;
+
+        // Rewind to the last emitted transaction, so a source initialized
+        // again re-fetches what was buffered instead of skipping it
+        if !self.buffer.is_empty() {
+            tracing::info!(
+                "Discarding {} buffered transactions, call drain() before close() to emit them",
+                self.buffer.len()
+            );
+            match (self.checkpoint_range, self.last_emitted_digest) {
+                // Continue within the checkpoint of the last emitted transaction
+                (Some((start, _)), Some(digest))
+                    if self
+                        .last_emitted_checkpoint
+                        .is_some_and(|checkpoint| checkpoint >= start) =>
+                {
+                    self.current_checkpoint = self.last_emitted_checkpoint;
+                    self.cursor = Some(digest);
+                }
+                (Some(_), _) => {
+                    self.current_checkpoint = None;
+                    self.cursor = None;
+                }
+                (None, Some(digest)) => {
+                    self.cursor = Some(digest);
+                    self.walk_forward = true;
+                }
+                (None, None) => {
+                    (self.cursor, self.walk_forward) = self.page_start;
+                }
+            }
+            self.finished = false;
+            self.buffer.clear();
+        }
+
         self.initialized = false;
-        self.client = None;
+        self.endpoints.disconnect();
         tracing::info!("SuiSource closed");
         Ok(())
     }
 }
+
+/// Response options requesting everything [`SuiEvent`] is built from
+pub(crate) fn default_query_options() -> SuiTransactionBlockResponseOptions {
+    SuiTransactionBlockResponseOptions::new()
+        .with_input()
+        .with_effects()
+        .with_events()
+        .with_object_changes()
+        .with_balance_changes()
+}
+
+/// Fails a poll that does not finish within the `next()` deadline, if one is set
+pub(crate) async fn with_next_timeout<T>(
+    next_timeout: Option<Duration>,
+    poll: impl Future<Output = StreamResult<Option<T>>>,
+) -> StreamResult<Option<T>> {
+    let Some(next_timeout) = next_timeout else {
+        return poll.await;
+    };
+
+    timeout(next_timeout, poll).await.unwrap_or_else(|_| {
+        tracing::error!("No transaction within the deadline of {:?}", next_timeout);
+        Err(StreamError::Runtime(format!(
+            "SuiSource next() timed out after {:?}",
+            next_timeout
+        )))
+    })
+}
+
+/// Wraps an event in a record timestamped with its on-chain execution time
+///
+/// Events without a known execution time are timestamped at ingestion.
+pub(crate) fn event_record(event: SuiEvent) -> Record<SuiEvent> {
+    let timestamp = event.timestamp;
+    timestamped_record(event, timestamp)
+}
+
+/// Wraps data derived from a transaction in a record timestamped with the
+/// transaction's execution time, or at ingestion if it is unknown
+pub(crate) fn timestamped_record<T>(data: T, timestamp: u64) -> Record<T> {
+    match timestamp {
+        0 => Record::new(data),
+        timestamp => Record::with_timestamp(data, timestamp as i64),
+    }
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary;
+use tokio::time::sleep;
+
+use crate::retry::{connect_client, now_millis};
+
+/// Snapshot of the Sui system state
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiSystemState {
+    /// Current epoch
+    pub epoch: u64,
+    /// Protocol version
+    pub protocol_version: u64,
+    /// Version of the system state object
+    pub system_state_version: u64,
+    /// Total SUI staked
+    pub total_stake: u64,
+    /// Storage fund balance refundable as storage rebates
+    pub storage_fund_total_object_storage_rebates: u64,
+    /// Non-refundable storage fund balance
+    pub storage_fund_non_refundable_balance: u64,
+    /// Reference gas price
+    pub reference_gas_price: u64,
+    /// Whether the network is running in safe mode
+    pub safe_mode: bool,
+    /// Number of active validators
+    pub validator_count: usize,
+}
+
+impl From<&SuiSystemStateSummary> for SuiSystemState {
+    fn from(state: &SuiSystemStateSummary) -> Self {
+        Self {
+            epoch: state.epoch,
+            protocol_version: state.protocol_version,
+            system_state_version: state.system_state_version,
+            total_stake: state.total_stake,
+            storage_fund_total_object_storage_rebates: state
+                .storage_fund_total_object_storage_rebates,
+            storage_fund_non_refundable_balance: state.storage_fund_non_refundable_balance,
+            reference_gas_price: state.reference_gas_price,
+            safe_mode: state.safe_mode,
+            validator_count: state.active_validators.len(),
+        }
+    }
+}
+
+/// System state change observed by [`SuiSystemStateSource`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiSystemStateChange {
+    /// New system state
+    pub state: SuiSystemState,
+    /// Previous system state, not set for the initial snapshot
+    pub previous: Option<SuiSystemState>,
+    /// Time the change was observed
+    pub timestamp: u64,
+}
+
+/// Sui data source emitting the system state whenever any of its fields change
+///
+/// The state at startup is emitted first.
+pub struct SuiSystemStateSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval
+    interval: Duration,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Last emitted state
+    last_state: Option<SuiSystemState>,
+}
+
+impl SuiSystemStateSource {
+    /// Creates a new SuiSystemStateSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            client: None,
+            last_state: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Source<SuiSystemStateChange> for SuiSystemStateSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_client(&self.rpc_url).await?;
+
+        self.client = Some(client);
+        tracing::info!(
+            "SuiSystemStateSource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiSystemStateChange>>> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiSystemStateSource not initialized".to_string())
+        })?;
+
+        // Emit the initial snapshot right away
+        if self.last_state.is_some() {
+            sleep(self.interval).await;
+        }
+
+        let summary = client
+            .governance_api()
+            .get_latest_sui_system_state()
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch system state: {}", e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        let state = SuiSystemState::from(&summary);
+        if self.last_state.as_ref() == Some(&state) {
+            return Ok(None);
+        }
+
+        if state.safe_mode {
+            tracing::warn!("Sui network is running in safe mode");
+        }
+
+        let timestamp = now_millis();
+        let previous = self.last_state.replace(state.clone());
+
+        Ok(Some(Record::new(SuiSystemStateChange {
+            state,
+            previous,
+            timestamp,
+        })))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.client = None;
+        tracing::info!("SuiSystemStateSource closed");
+        Ok(())
+    }
+}
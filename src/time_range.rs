@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use fluxus::utils::models::{StreamError, StreamResult};
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::CheckpointId;
+use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
+
+use crate::SuiSource;
+
+impl SuiSource {
+    /// Resolves a time range to the checkpoints executed within `from..to`
+    ///
+    /// Binary searches the checkpoint timestamps, which every transaction of a
+    /// checkpoint shares. Returns `None` if no checkpoint falls in the range.
+    pub(crate) async fn resolve_time_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StreamResult<Option<(CheckpointSequenceNumber, CheckpointSequenceNumber)>> {
+        let client = self
+            .client()
+            .cloned()
+            .ok_or_else(|| StreamError::Runtime("SuiSource client not available".to_string()))?;
+
+        self.throttle().await;
+        let latest = client
+            .read_api()
+            .get_latest_checkpoint_sequence_number()
+            .await
+            .map_err(|e| StreamError::Runtime(e.to_string()))?;
+        let latest_timestamp = self.checkpoint_timestamp_ms(&client, latest).await?;
+        let to_ms = to.timestamp_millis().max(0) as u64;
+        if to_ms > latest_timestamp {
+            return Err(StreamError::Config(format!(
+                "Time range ends at {}, after the latest checkpoint {}",
+                to, latest
+            )));
+        }
+
+        let from_ms = from.timestamp_millis().max(0) as u64;
+        let start = self.first_checkpoint_at(&client, from_ms, latest).await?;
+        let end = self.first_checkpoint_at(&client, to_ms, latest).await?;
+        tracing::info!(
+            "Resolved time range {}..{} to checkpoints {}..{}",
+            from,
+            to,
+            start,
+            end
+        );
+        Ok((start < end).then(|| (start, end - 1)))
+    }
+
+    /// Returns the first checkpoint executed at or after `timestamp_ms`, `latest + 1` if none
+    async fn first_checkpoint_at(
+        &self,
+        client: &SuiClient,
+        timestamp_ms: u64,
+        latest: CheckpointSequenceNumber,
+    ) -> StreamResult<CheckpointSequenceNumber> {
+        let (mut low, mut high) = (0, latest + 1);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.checkpoint_timestamp_ms(client, middle).await? < timestamp_ms {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        Ok(low)
+    }
+
+    /// Fetches the timestamp of a checkpoint
+    async fn checkpoint_timestamp_ms(
+        &self,
+        client: &SuiClient,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> StreamResult<u64> {
+        self.throttle().await;
+        let summary = client
+            .read_api()
+            .get_checkpoint(CheckpointId::SequenceNumber(checkpoint))
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch checkpoint {}: {}", checkpoint, e);
+                StreamError::Runtime(e.to_string())
+            })?;
+        Ok(summary.timestamp_ms)
+    }
+}
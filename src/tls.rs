@@ -0,0 +1,12 @@
+/// HTTP client builder using the TLS backend selected by the cargo features
+///
+/// `rustls-tls` takes precedence when both `rustls-tls` and `native-tls`
+/// are enabled, e.g. through feature unification in a larger workspace.
+pub(crate) fn http_client_builder() -> reqwest::ClientBuilder {
+    let builder = reqwest::Client::builder();
+    #[cfg(feature = "rustls-tls")]
+    let builder = builder.use_rustls_tls();
+    #[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+    let builder = builder.use_native_tls();
+    builder
+}
@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use std::fmt;
+use sui_sdk::rpc_types::SuiTransactionBlockResponse;
+
+use crate::SuiSource;
+
+/// Sui data source emitting records extracted by a user-supplied transform
+///
+/// Created with [`SuiSource::with_transform`]. Transactions for which the
+/// transform returns `None` are skipped. Records are timestamped with the
+/// execution time of their transaction.
+pub struct SuiTransformSource<T> {
+    /// Wrapped transaction source
+    source: SuiSource,
+    /// Extracts a record from a transaction
+    transform: Box<dyn Fn(SuiTransactionBlockResponse) -> Option<T> + Send + Sync>,
+}
+
+impl SuiSource {
+    /// Emits records extracted from raw RPC transactions by `transform` instead of [`crate::SuiEvent`]s
+    ///
+    /// Which parts of the response are populated is controlled by the query
+    /// options, see [`crate::SuiSourceBuilder::query_options`].
+    ///
+    /// # Parameters
+    /// * `transform` - Extracts a record from a transaction, `None` skips the transaction
+    pub fn with_transform<T, F>(self, transform: F) -> SuiTransformSource<T>
+    where
+        F: Fn(SuiTransactionBlockResponse) -> Option<T> + Send + Sync + 'static,
+    {
+        SuiTransformSource {
+            source: self,
+            transform: Box::new(transform),
+        }
+    }
+}
+
+impl<T> fmt::Debug for SuiTransformSource<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SuiTransformSource").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl<T: Send + 'static> Source<T> for SuiTransformSource<T> {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.source.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<T>>> {
+        loop {
+            let Some(transaction) = self.source.next_transaction().await? else {
+                return Ok(None);
+            };
+            let timestamp = transaction.timestamp_ms;
+            if let Some(data) = (self.transform)(transaction) {
+                return Ok(Some(match timestamp {
+                    Some(timestamp) => Record::with_timestamp(data, timestamp as i64),
+                    None => Record::new(data),
+                }));
+            }
+        }
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.source.close().await
+    }
+}
@@ -0,0 +1,210 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use tokio::time::sleep;
+
+use crate::retry::connect_client;
+
+/// Validator status within the active set
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ValidatorStatus {
+    /// Validator is active with sufficient stake
+    Active,
+    /// Validator is below the stake threshold and may be removed
+    AtRisk,
+}
+
+/// When the validator set is snapshotted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidatorSnapshotTrigger {
+    /// Snapshot on every poll
+    Interval,
+    /// Snapshot only when a new epoch begins
+    EpochChange,
+}
+
+/// Snapshot of a single validator
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SuiValidator {
+    /// Epoch of the snapshot
+    pub epoch: u64,
+    /// Validator address
+    pub address: String,
+    /// Validator name
+    pub name: String,
+    /// SUI staked in the validator's pool
+    pub stake: u64,
+    /// Stake effective in the next epoch
+    pub next_epoch_stake: u64,
+    /// Commission rate in basis points
+    pub commission_rate: u64,
+    /// Voting power in basis points of the total
+    pub voting_power: u64,
+    /// Gas price quote of the validator
+    pub gas_price: u64,
+    /// Annual percentage yield (if reported)
+    pub apy: Option<f64>,
+    /// Status within the active set
+    pub status: ValidatorStatus,
+}
+
+/// Sui data source emitting one record per active validator on each snapshot
+pub struct SuiValidatorSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval
+    interval: Duration,
+    /// When snapshots are emitted
+    trigger: ValidatorSnapshotTrigger,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Epoch of the last snapshot
+    last_epoch: Option<u64>,
+    /// Validator records waiting to be emitted
+    buffer: VecDeque<SuiValidator>,
+}
+
+impl SuiValidatorSource {
+    /// Creates a new SuiValidatorSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `trigger` - Whether to snapshot on every poll or only on epoch changes
+    pub fn new(rpc_url: String, interval_ms: u64, trigger: ValidatorSnapshotTrigger) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            trigger,
+            client: None,
+            last_epoch: None,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Fetches the validator set and buffers a snapshot if one is due
+    async fn snapshot(&mut self) -> StreamResult<()> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiValidatorSource client not available".to_string())
+        })?;
+
+        let state = client
+            .governance_api()
+            .get_latest_sui_system_state()
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch system state: {}", e);
+                StreamError::Runtime(e.to_string())
+            })?;
+
+        if self.trigger == ValidatorSnapshotTrigger::EpochChange
+            && self.last_epoch == Some(state.epoch)
+        {
+            return Ok(());
+        }
+
+        // APYs are informational, a failure should not drop the snapshot
+        let apys: HashMap<_, _> = match client.governance_api().get_validators_apy().await {
+            Ok(apys) => apys
+                .apys
+                .into_iter()
+                .map(|apy| (apy.address, apy.apy))
+                .collect(),
+            Err(e) => {
+                tracing::warn!("Failed to fetch validator APYs: {}", e);
+                HashMap::new()
+            }
+        };
+
+        self.buffer
+            .extend(state.active_validators.iter().map(|validator| {
+                SuiValidator {
+                    epoch: state.epoch,
+                    address: validator.sui_address.to_string(),
+                    name: validator.name.clone(),
+                    stake: validator.staking_pool_sui_balance,
+                    next_epoch_stake: validator.next_epoch_stake,
+                    commission_rate: validator.commission_rate,
+                    voting_power: validator.voting_power,
+                    gas_price: validator.gas_price,
+                    apy: apys.get(&validator.sui_address).copied(),
+                    status: if state
+                        .at_risk_validators
+                        .iter()
+                        .any(|(address, _)| address == &validator.sui_address)
+                    {
+                        ValidatorStatus::AtRisk
+                    } else {
+                        ValidatorStatus::Active
+                    },
+                }
+            }));
+        self.last_epoch = Some(state.epoch);
+
+        tracing::debug!(
+            "Snapshotted {} validators in epoch {}",
+            state.active_validators.len(),
+            state.epoch
+        );
+
+        Ok(())
+    }
+
+    /// Emits the rest of the current validator set snapshot without fetching a new one
+    ///
+    /// Call before `close` to flush in-flight records on shutdown.
+    pub async fn drain(&mut self) -> StreamResult<Vec<Record<SuiValidator>>> {
+        Ok(self.buffer.drain(..).map(Record::new).collect())
+    }
+}
+
+#[async_trait]
+impl Source<SuiValidator> for SuiValidatorSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.client.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_client(&self.rpc_url).await?;
+
+        self.client = Some(client);
+        tracing::info!(
+            "SuiValidatorSource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<SuiValidator>>> {
+        if self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiValidatorSource not initialized".to_string(),
+            ));
+        }
+
+        if self.buffer.is_empty() {
+            // Take the first snapshot right away
+            if self.last_epoch.is_some() {
+                sleep(self.interval).await;
+            }
+            self.snapshot().await?;
+        }
+
+        Ok(self.buffer.pop_front().map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        // The buffered snapshot is kept, its epoch is already marked as taken
+        self.client = None;
+        tracing::info!("SuiValidatorSource closed");
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
+use tokio::sync::watch;
+
+/// Event-time progress of a source
+///
+/// Every transaction up to and including `checkpoint`, executed at or before
+/// `timestamp_ms`, has been emitted. Event-time windows ending at or before
+/// `timestamp_ms` can be closed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuiWatermark {
+    /// Last fully emitted checkpoint
+    pub checkpoint: CheckpointSequenceNumber,
+    /// Latest execution time in the checkpoint in milliseconds since the Unix epoch
+    pub timestamp_ms: u64,
+}
+
+/// Derives watermarks from the checkpoints of emitted transactions
+///
+/// Transactions are emitted in checkpoint order, so a checkpoint is complete
+/// once a transaction of a later checkpoint is emitted.
+#[derive(Debug)]
+pub(crate) struct WatermarkTracker {
+    /// Checkpoint being emitted and its latest execution time
+    open: Option<(CheckpointSequenceNumber, u64)>,
+    /// Latest watermark
+    sender: watch::Sender<Option<SuiWatermark>>,
+}
+
+impl Default for WatermarkTracker {
+    fn default() -> Self {
+        Self {
+            open: None,
+            sender: watch::Sender::new(None),
+        }
+    }
+}
+
+impl WatermarkTracker {
+    /// Records an emitted transaction, advancing the watermark past completed checkpoints
+    pub(crate) fn observe(&mut self, checkpoint: CheckpointSequenceNumber, timestamp_ms: u64) {
+        match self.open {
+            Some((open, latest)) if open == checkpoint => {
+                self.open = Some((open, latest.max(timestamp_ms)));
+            }
+            Some((open, _)) if open > checkpoint => {
+                tracing::debug!(
+                    "Transaction of checkpoint {} emitted after checkpoint {}, watermark not moved",
+                    checkpoint,
+                    open
+                );
+            }
+            _ => {
+                self.finish();
+                self.open = Some((checkpoint, timestamp_ms));
+            }
+        }
+    }
+
+    /// Publishes the open checkpoint as complete, e.g. at the end of a checkpoint range
+    pub(crate) fn finish(&mut self) {
+        if let Some((checkpoint, timestamp_ms)) = self.open.take() {
+            self.sender.send_replace(Some(SuiWatermark {
+                checkpoint,
+                timestamp_ms,
+            }));
+            tracing::debug!("Watermark advanced to checkpoint {}", checkpoint);
+        }
+    }
+
+    /// Latest watermark, `None` until a checkpoint completed
+    pub(crate) fn current(&self) -> Option<SuiWatermark> {
+        *self.sender.borrow()
+    }
+
+    /// Subscribes to watermark updates
+    pub(crate) fn subscribe(&self) -> watch::Receiver<Option<SuiWatermark>> {
+        self.sender.subscribe()
+    }
+}
@@ -0,0 +1,77 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{AdaptiveInterval, SuiSource};
+use std::time::Duration;
+
+#[test]
+fn test_adaptive_interval_adjustment() {
+    let adaptive = AdaptiveInterval::new(Duration::from_millis(100), Duration::from_secs(2));
+
+    assert_eq!(
+        adaptive.next_interval(Duration::from_millis(400), 10, 10),
+        Duration::from_millis(200),
+        "Full pages should shrink the interval"
+    );
+    assert_eq!(
+        adaptive.next_interval(Duration::from_millis(400), 0, 10),
+        Duration::from_millis(800),
+        "Empty polls should grow the interval"
+    );
+    assert_eq!(
+        adaptive.next_interval(Duration::from_millis(400), 5, 10),
+        Duration::from_millis(400),
+        "Partial pages should keep the interval"
+    );
+    assert_eq!(
+        adaptive.next_interval(Duration::from_millis(150), 10, 10),
+        Duration::from_millis(100),
+        "Interval should not drop below the minimum"
+    );
+    assert_eq!(
+        adaptive.next_interval(Duration::from_millis(1500), 0, 10),
+        Duration::from_secs(2),
+        "Interval should not exceed the maximum"
+    );
+}
+
+#[test]
+fn test_adaptive_interval_configuration() {
+    let result = SuiSource::builder()
+        .adaptive_interval(Duration::from_secs(2), Duration::from_millis(100))
+        .build();
+    assert!(result.is_err(), "Minimum above maximum should be rejected");
+
+    let result = SuiSource::builder()
+        .adaptive_interval(Duration::ZERO, Duration::from_secs(2))
+        .build();
+    assert!(result.is_err(), "Zero minimum should be rejected");
+
+    let source = SuiSource::builder()
+        .interval(Duration::from_secs(10))
+        .adaptive_interval(Duration::from_millis(100), Duration::from_secs(2))
+        .build()
+        .expect("Valid configuration");
+    assert_eq!(
+        source.current_interval(),
+        Duration::from_secs(2),
+        "Initial interval should be clamped to the bounds"
+    );
+}
+
+#[tokio::test]
+async fn test_adaptive_interval_speeds_up_under_load() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_secs(1))
+        .adaptive_interval(Duration::from_millis(50), Duration::from_secs(5))
+        .max_transactions(5)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    // The first poll anchors on the newest transactions, which always fill a page on mainnet
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching should succeed");
+    assert!(
+        source.current_interval() < Duration::from_secs(1),
+        "Full pages should shorten the interval"
+    );
+}
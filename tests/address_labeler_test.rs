@@ -0,0 +1,66 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{AddressLabeler, StaticAddressLabeler, SuiSource};
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_static_labeler_from_file() {
+    let path = std::env::temp_dir().join("fluxus_sui_address_labels.json");
+    std::fs::write(&path, r#"{ "0x5": "Sui System State" }"#).expect("Writing should succeed");
+
+    let labeler = StaticAddressLabeler::from_file(&path).expect("Labels should load");
+    assert_eq!(labeler.len(), 1);
+
+    // Addresses are matched regardless of their padding
+    let full_address = format!("0x{:0>64}", "5");
+    assert_eq!(
+        labeler.label(&full_address).await.as_deref(),
+        Some("Sui System State")
+    );
+    assert!(labeler.label("0x6").await.is_none());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_static_labeler_rejects_invalid_addresses() {
+    let labels = HashMap::from([("not-an-address".to_string(), "Bad".to_string())]);
+    assert!(StaticAddressLabeler::new(labels).is_err());
+}
+
+#[tokio::test]
+async fn test_sender_labeled() {
+    // System transactions are sent by the zero address
+    let labels = HashMap::from([("0x0".to_string(), "System".to_string())]);
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .address_labeler(StaticAddressLabeler::new(labels).expect("Valid labels"))
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut system_transaction_found = false;
+    for _ in 0..20 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        if record
+            .data
+            .sender
+            .trim_start_matches("0x")
+            .trim_start_matches('0')
+            .is_empty()
+        {
+            assert_eq!(record.data.sender_label.as_deref(), Some("System"));
+            system_transaction_found = true;
+        } else {
+            assert!(record.data.sender_label.is_none());
+        }
+    }
+    assert!(
+        system_transaction_found,
+        "Checkpoints should contain system transactions"
+    );
+}
@@ -0,0 +1,46 @@
+use fluxus::sources::Source;
+#[cfg(feature = "graphql")]
+use fluxus_source_sui::SuiGraphqlSource;
+use fluxus_source_sui::{SuiNetwork, SuiSource};
+
+#[test]
+fn test_invalid_headers_rejected() {
+    let result = SuiSource::builder()
+        .with_header("bad header", "value")
+        .build();
+    assert!(result.is_err(), "Invalid header name should be rejected");
+
+    let result = SuiSource::builder()
+        .with_header("x-api-key", "line\nbreak")
+        .build();
+    assert!(result.is_err(), "Invalid header value should be rejected");
+
+    let result = SuiSource::builder().with_bearer_token("secret").build();
+    assert!(result.is_ok(), "Bearer token should be accepted");
+}
+
+#[tokio::test]
+async fn test_headers_sent_to_public_endpoint() {
+    // Public fullnodes ignore unknown credentials
+    let mut source = SuiSource::builder()
+        .network(SuiNetwork::Mainnet)
+        .with_header("x-api-key", "unused")
+        .with_bearer_token("unused")
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+    let result = source.next().await;
+    assert!(
+        result.is_ok(),
+        "Fetching with custom headers should succeed"
+    );
+}
+
+#[cfg(feature = "graphql")]
+#[tokio::test]
+async fn test_graphql_headers_sent_to_public_endpoint() {
+    let mut source = SuiGraphqlSource::for_network(SuiNetwork::Mainnet, 500, 10)
+        .expect("Mainnet has a GraphQL endpoint")
+        .with_bearer_token("unused");
+    source.init().await.expect("Initialization failed");
+}
@@ -0,0 +1,58 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{AuthScheme, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_auth_scheme_detected() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(20)
+        .with_start_checkpoint(100_000_000)
+        .skip_system_transactions(true)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..10 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        assert!(
+            record.data.auth_scheme.is_some(),
+            "User transactions should carry the sender's signature scheme"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_zklogin_only() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(50)
+        .with_start_checkpoint(100_000_000)
+        .zklogin_only(true)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..10 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        assert_eq!(record.data.auth_scheme, Some(AuthScheme::ZkLogin));
+    }
+}
+
+#[test]
+fn test_auth_scheme_names() {
+    assert_eq!(AuthScheme::ZkLogin.to_string(), "zklogin");
+    assert_eq!(
+        serde_json::to_string(&AuthScheme::ZkLogin).expect("Serializable"),
+        "\"zklogin\""
+    );
+    assert_eq!(
+        "multisig".parse::<AuthScheme>().ok(),
+        Some(AuthScheme::Multisig)
+    );
+    assert!("rsa".parse::<AuthScheme>().is_err());
+}
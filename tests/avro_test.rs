@@ -0,0 +1,38 @@
+#![cfg(feature = "avro")]
+
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiEvent, SuiSource, sui_event_avro_schema};
+use std::time::Duration;
+
+#[test]
+fn test_avro_schema_is_named_record() {
+    let schema = sui_event_avro_schema();
+    assert!(
+        schema
+            .canonical_form()
+            .contains("\"name\":\"fluxus.sui.SuiEvent\"")
+    );
+}
+
+#[tokio::test]
+async fn test_avro_round_trip() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    let encoded = record.data.to_avro().expect("Event should encode");
+    let decoded = SuiEvent::from_avro(&encoded).expect("Event should decode");
+    assert_eq!(
+        serde_json::to_value(&decoded).expect("Event should serialize"),
+        serde_json::to_value(&record.data).expect("Event should serialize")
+    );
+}
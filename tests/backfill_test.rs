@@ -0,0 +1,37 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_backfill_signals_completion() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(50)
+        .with_checkpoint_range(100_000_000, 100_000_001)
+        .build()
+        .expect("Valid configuration");
+    let handle = source.handle();
+    source.init().await.expect("Initialization failed");
+    assert!(!handle.is_finished());
+
+    let mut emitted = 0;
+    while !handle.is_finished() {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            assert!(record.data.timestamp > 0);
+            emitted += 1;
+        }
+    }
+    assert!(emitted > 0, "The range should hold transactions");
+
+    tokio::time::timeout(Duration::from_secs(1), handle.wait_finished())
+        .await
+        .expect("Completion should already be signaled");
+    assert!(source.is_finished());
+    assert!(
+        source
+            .next()
+            .await
+            .expect("A finished source should not fail")
+            .is_none()
+    );
+}
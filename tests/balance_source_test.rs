@@ -0,0 +1,165 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiBalanceSource;
+use serde_json::{Value, json};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use sui_sdk::types::base_types::SuiAddress;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+#[tokio::test]
+async fn test_balance_source_baseline() {
+    // Watch an address whose balance never changes
+    let mut source = SuiBalanceSource::new(
+        "https://fullnode.mainnet.sui.io:443".to_string(),
+        vec![SuiAddress::ZERO],
+        Some("0x2::sui::SUI".to_string()),
+        500,
+    );
+    source.init().await.expect("Initialization failed");
+
+    // The first poll only records the baseline
+    let result = source.next().await;
+    assert!(
+        matches!(result, Ok(None)),
+        "Baseline poll should not emit changes"
+    );
+}
+
+/// Balance as returned by `suix_getAllBalances`
+fn balance(coin_type: &str, total_balance: u128) -> Value {
+    json!({
+        "coinType": coin_type,
+        "coinObjectCount": 1,
+        "totalBalance": total_balance.to_string(),
+        "lockedBalance": {},
+    })
+}
+
+/// Serves a JSON-RPC node answering `suix_getAllBalances` with the given
+/// responses in turn, repeating the last one, and returns its URL
+async fn mock_balance_node(responses: Vec<Value>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind mock node");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let responses = Arc::new(Mutex::new(VecDeque::from(responses)));
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let responses = responses.clone();
+            tokio::spawn(async move {
+                let (reader, mut writer) = stream.into_split();
+                let mut reader = BufReader::new(reader);
+                loop {
+                    // Headers, only the body length matters
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        if reader.read_line(&mut line).await.unwrap_or(0) == 0 {
+                            return;
+                        }
+                        let line = line.trim_end().to_ascii_lowercase();
+                        if line.is_empty() {
+                            break;
+                        }
+                        if let Some(length) = line.strip_prefix("content-length:") {
+                            content_length = length.trim().parse().unwrap_or(0);
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    if reader.read_exact(&mut body).await.is_err() {
+                        return;
+                    }
+                    let request: Value = serde_json::from_slice(&body).unwrap_or_default();
+
+                    let result = match request["method"].as_str() {
+                        Some("rpc.discover") => json!({
+                            "info": { "version": "1.0.0" },
+                            "methods": [{ "name": "suix_getAllBalances" }],
+                        }),
+                        Some("suix_getAllBalances") => {
+                            let mut responses = responses.lock().unwrap();
+                            if responses.len() > 1 {
+                                responses.pop_front().unwrap()
+                            } else {
+                                responses.front().cloned().unwrap_or_default()
+                            }
+                        }
+                        _ => Value::Null,
+                    };
+                    let response = json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "result": result,
+                    })
+                    .to_string();
+                    let reply = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                        response.len(),
+                        response
+                    );
+                    if writer.write_all(reply.as_bytes()).await.is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    url
+}
+
+#[tokio::test]
+async fn test_balance_source_changes() {
+    let url = mock_balance_node(vec![
+        // Baseline
+        json!([balance("0x2::sui::SUI", 100), balance("0x5::usdc::USDC", 5)]),
+        // USDC drained, nodes leave out zero balances
+        json!([balance("0x2::sui::SUI", 100)]),
+        // SUI received and USDC funded again
+        json!([balance("0x2::sui::SUI", 150), balance("0x5::usdc::USDC", 7)]),
+    ])
+    .await;
+    let mut source = SuiBalanceSource::new(url, vec![SuiAddress::ZERO], None, 10);
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await.expect("Baseline poll should succeed");
+    assert!(result.is_none(), "Baseline poll should not emit changes");
+
+    let drained = source
+        .next()
+        .await
+        .expect("Polling should succeed")
+        .expect("Drained balance should be a change")
+        .data;
+    assert_eq!(drained.coin_type, "0x5::usdc::USDC");
+    assert_eq!(drained.previous_balance, 5);
+    assert_eq!(drained.balance, 0);
+    assert_eq!(drained.delta, -5);
+
+    let received = source
+        .next()
+        .await
+        .expect("Polling should succeed")
+        .expect("Received balance should be a change")
+        .data;
+    assert_eq!(received.coin_type, "0x2::sui::SUI");
+    assert_eq!(received.previous_balance, 100);
+    assert_eq!(received.balance, 150);
+    assert_eq!(received.delta, 50);
+
+    let funded = source
+        .next()
+        .await
+        .expect("Polling should succeed")
+        .expect("Funded balance should be a change")
+        .data;
+    assert_eq!(funded.coin_type, "0x5::usdc::USDC");
+    assert_eq!(funded.previous_balance, 0);
+    assert_eq!(funded.balance, 7);
+
+    // Unchanged balances are not emitted
+    let result = source.next().await.expect("Polling should succeed");
+    assert!(result.is_none(), "Unchanged balances should not be emitted");
+}
@@ -0,0 +1,47 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_bcs_passed_through_when_enabled() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .include_bcs(true)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    let raw_transaction = record
+        .data
+        .raw_transaction
+        .expect("Transaction BCS should be included");
+    assert!(!raw_transaction.is_empty());
+    for event in &record.data.events {
+        assert!(event.bcs.is_some(), "Event BCS should be included");
+    }
+}
+
+#[tokio::test]
+async fn test_bcs_omitted_by_default() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    assert!(record.data.raw_transaction.is_none());
+    assert!(record.data.events.iter().all(|event| event.bcs.is_none()));
+}
@@ -0,0 +1,147 @@
+use fluxus_source_sui::{SuiQueryOrder, SuiSource};
+use std::time::Duration;
+use sui_sdk::rpc_types::TransactionFilter;
+use sui_sdk::types::base_types::{ObjectID, SuiAddress};
+
+#[test]
+fn test_builder_defaults() {
+    // Default builder should produce a valid source
+    let source = SuiSource::builder().build();
+    assert!(
+        source.is_ok(),
+        "Default builder configuration should be valid"
+    );
+}
+
+#[test]
+fn test_builder_full_configuration() {
+    // Every setter of the original builder surface combined
+    let source = SuiSource::builder()
+        .rpc_url("https://fullnode.testnet.sui.io:443")
+        .interval(Duration::from_millis(250))
+        .max_transactions(20)
+        .show_input(true)
+        .show_effects(true)
+        .show_events(false)
+        .show_balance_changes(true)
+        .order(SuiQueryOrder::Ascending)
+        .filter(TransactionFilter::FromAddress(SuiAddress::ZERO))
+        .request_timeout(Duration::from_secs(10))
+        .build()
+        .expect("Full configuration should be valid");
+    assert_eq!(
+        source.health().current_endpoint,
+        "https://fullnode.testnet.sui.io:443"
+    );
+}
+
+#[test]
+fn test_builder_order() {
+    let result = SuiSource::builder()
+        .order(SuiQueryOrder::Descending)
+        .build();
+    assert!(result.is_ok(), "Descending order should be accepted");
+
+    let result = SuiSource::builder()
+        .order(SuiQueryOrder::Descending)
+        .with_checkpoint_range(100, 200)
+        .build();
+    assert!(
+        result.is_err(),
+        "Descending order with a checkpoint range should be rejected"
+    );
+}
+
+#[test]
+fn test_builder_rejects_invalid_configuration() {
+    // Invalid RPC URL
+    let result = SuiSource::builder()
+        .rpc_url("fullnode.mainnet.sui.io")
+        .build();
+    assert!(result.is_err(), "RPC URL without scheme should be rejected");
+
+    // Batch size outside the fullnode page limit
+    let result = SuiSource::builder().max_transactions(0).build();
+    assert!(result.is_err(), "Zero batch size should be rejected");
+    let result = SuiSource::builder().max_transactions(51).build();
+    assert!(result.is_err(), "Batch size above 50 should be rejected");
+
+    // Zero timeout
+    let result = SuiSource::builder().request_timeout(Duration::ZERO).build();
+    assert!(result.is_err(), "Zero request timeout should be rejected");
+}
+
+#[test]
+fn test_builder_from_address_filter() {
+    let result = SuiSource::builder()
+        .with_from_address(SuiAddress::ZERO)
+        .build();
+    assert!(result.is_ok(), "Sender filter should be accepted");
+
+    // Conflicting filters
+    let result = SuiSource::builder()
+        .with_from_address(SuiAddress::ZERO)
+        .filter(TransactionFilter::Checkpoint(1))
+        .build();
+    assert!(result.is_err(), "Conflicting filters should be rejected");
+}
+
+#[test]
+fn test_builder_to_address_filter() {
+    let result = SuiSource::builder()
+        .with_to_address(SuiAddress::ZERO)
+        .build();
+    assert!(result.is_ok(), "Recipient filter should be accepted");
+
+    // Sender and recipient combine into a single filter
+    let result = SuiSource::builder()
+        .with_from_address(SuiAddress::ZERO)
+        .with_to_address(SuiAddress::ZERO)
+        .build();
+    assert!(
+        result.is_ok(),
+        "Sender and recipient filters should combine"
+    );
+}
+
+#[test]
+fn test_builder_move_function_filter() {
+    let result = SuiSource::builder()
+        .with_move_function(
+            ObjectID::ZERO,
+            Some("amm".to_string()),
+            Some("swap".to_string()),
+        )
+        .build();
+    assert!(result.is_ok(), "Move function filter should be accepted");
+
+    // A function without its module is ambiguous
+    let result = SuiSource::builder()
+        .with_move_function(ObjectID::ZERO, None, Some("swap".to_string()))
+        .build();
+    assert!(
+        result.is_err(),
+        "Function without module should be rejected"
+    );
+}
+
+#[test]
+fn test_builder_checkpoint_range() {
+    let result = SuiSource::builder().with_checkpoint_range(100, 200).build();
+    assert!(result.is_ok(), "Checkpoint range should be accepted");
+
+    let result = SuiSource::builder().with_checkpoint_range(200, 100).build();
+    assert!(
+        result.is_err(),
+        "Reversed checkpoint range should be rejected"
+    );
+
+    let result = SuiSource::builder()
+        .with_checkpoint_range(100, 200)
+        .with_from_address(SuiAddress::ZERO)
+        .build();
+    assert!(
+        result.is_err(),
+        "Checkpoint range with another filter should be rejected"
+    );
+}
@@ -0,0 +1,155 @@
+#![cfg(feature = "ingestion")]
+
+use fluxus::sources::Source;
+use fluxus_source_sui::{CheckpointStore, SuiCheckpointFileSource, SuiEvent};
+use std::path::PathBuf;
+
+/// First checkpoint of the fixtures
+const FIRST_CHECKPOINT: u64 = 100_000_000;
+
+/// Downloads Mainnet checkpoint files into a fresh local store
+async fn local_store(name: &str, checkpoints: &[u64]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("fluxus_sui_checkpoints_{}", name));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("Creating the store should succeed");
+
+    for checkpoint in checkpoints {
+        let bytes = reqwest::get(format!(
+            "https://checkpoints.mainnet.sui.io/{}.chk",
+            checkpoint
+        ))
+        .await
+        .and_then(|response| response.error_for_status())
+        .expect("Downloading the checkpoint should succeed")
+        .bytes()
+        .await
+        .expect("Reading the checkpoint should succeed");
+        std::fs::write(dir.join(format!("{}.chk", checkpoint)), bytes)
+            .expect("Writing the checkpoint should succeed");
+    }
+    dir
+}
+
+/// Reads transactions until the source is caught up with the store
+async fn read_available(source: &mut SuiCheckpointFileSource) -> Vec<SuiEvent> {
+    let mut events = Vec::new();
+    while let Some(record) = source.next().await.expect("Reading should succeed") {
+        events.push(record.data);
+    }
+    events
+}
+
+#[tokio::test]
+async fn test_missing_directory_rejected() {
+    let mut source = SuiCheckpointFileSource::new(
+        CheckpointStore::Local(std::env::temp_dir().join("fluxus_sui_no_such_store")),
+        FIRST_CHECKPOINT,
+        10,
+    );
+    let result = source.init().await;
+    assert!(result.is_err(), "Missing directory should be rejected");
+}
+
+#[tokio::test]
+async fn test_empty_store_waits_for_checkpoints() {
+    let dir = local_store("empty", &[]).await;
+    let mut source =
+        SuiCheckpointFileSource::new(CheckpointStore::Local(dir), FIRST_CHECKPOINT, 10);
+    source.init().await.expect("Initialization failed");
+
+    let record = source.next().await.expect("Reading should succeed");
+    assert!(record.is_none(), "No checkpoint should be available");
+    assert_eq!(source.next_checkpoint(), FIRST_CHECKPOINT);
+}
+
+#[tokio::test]
+async fn test_corrupt_checkpoint_rejected() {
+    let dir = local_store("corrupt", &[]).await;
+    std::fs::write(
+        dir.join(format!("{}.chk", FIRST_CHECKPOINT)),
+        b"not a checkpoint",
+    )
+    .expect("Writing should succeed");
+    let mut source =
+        SuiCheckpointFileSource::new(CheckpointStore::Local(dir), FIRST_CHECKPOINT, 10);
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(result.is_err(), "Undecodable checkpoints should fail");
+}
+
+#[tokio::test]
+async fn test_checkpoints_read_in_order() {
+    let checkpoints = [FIRST_CHECKPOINT, FIRST_CHECKPOINT + 1, FIRST_CHECKPOINT + 2];
+    let dir = local_store("ordered", &checkpoints).await;
+    let mut source =
+        SuiCheckpointFileSource::new(CheckpointStore::Local(dir), FIRST_CHECKPOINT, 10);
+    source.init().await.expect("Initialization failed");
+
+    let events = read_available(&mut source).await;
+    assert!(!events.is_empty(), "Checkpoints should hold transactions");
+    assert!(
+        events
+            .windows(2)
+            .all(|pair| pair[0].timestamp <= pair[1].timestamp),
+        "Transactions should be emitted in checkpoint order"
+    );
+    assert_eq!(source.next_checkpoint(), FIRST_CHECKPOINT + 3);
+}
+
+#[tokio::test]
+async fn test_reading_stops_at_first_missing_checkpoint() {
+    // Checkpoint FIRST_CHECKPOINT + 1 is missing
+    let dir = local_store("gap", &[FIRST_CHECKPOINT, FIRST_CHECKPOINT + 2]).await;
+    let mut source =
+        SuiCheckpointFileSource::new(CheckpointStore::Local(dir.clone()), FIRST_CHECKPOINT, 10);
+    source.init().await.expect("Initialization failed");
+
+    let before_gap = read_available(&mut source).await;
+    assert!(!before_gap.is_empty());
+    assert_eq!(
+        source.next_checkpoint(),
+        FIRST_CHECKPOINT + 1,
+        "Reading should not skip the missing checkpoint"
+    );
+
+    // Filling the gap resumes with it, followed by the checkpoint after it
+    let filled = local_store("gap_filled", &[FIRST_CHECKPOINT + 1]).await;
+    std::fs::copy(
+        filled.join(format!("{}.chk", FIRST_CHECKPOINT + 1)),
+        dir.join(format!("{}.chk", FIRST_CHECKPOINT + 1)),
+    )
+    .expect("Copying should succeed");
+    let after_gap = read_available(&mut source).await;
+    assert!(!after_gap.is_empty());
+    assert_eq!(source.next_checkpoint(), FIRST_CHECKPOINT + 3);
+}
+
+#[tokio::test]
+async fn test_concurrency_keeps_order() {
+    let checkpoints = [FIRST_CHECKPOINT, FIRST_CHECKPOINT + 1, FIRST_CHECKPOINT + 2];
+    let dir = local_store("concurrency", &checkpoints).await;
+
+    let mut sequential =
+        SuiCheckpointFileSource::new(CheckpointStore::Local(dir.clone()), FIRST_CHECKPOINT, 10)
+            .with_concurrency(1);
+    sequential.init().await.expect("Initialization failed");
+    let mut concurrent =
+        SuiCheckpointFileSource::new(CheckpointStore::Local(dir), FIRST_CHECKPOINT, 10)
+            .with_concurrency(8);
+    concurrent.init().await.expect("Initialization failed");
+
+    let digests = |events: Vec<SuiEvent>| {
+        events
+            .into_iter()
+            .map(|event| event.transaction_digest)
+            .collect::<Vec<_>>()
+    };
+    let sequential = digests(read_available(&mut sequential).await);
+    let concurrent = digests(read_available(&mut concurrent).await);
+    assert!(!sequential.is_empty());
+    assert_eq!(
+        sequential, concurrent,
+        "Concurrent reads should emit the same transactions in the same order"
+    );
+}
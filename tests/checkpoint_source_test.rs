@@ -0,0 +1,30 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiCheckpointSource;
+
+#[tokio::test]
+async fn test_checkpoint_source_sequential() {
+    // Create SuiCheckpointSource against mainnet
+    let mut source =
+        SuiCheckpointSource::new("https://fullnode.mainnet.sui.io:443".to_string(), 500, 5);
+    source.init().await.expect("Initialization failed");
+
+    // Collect a few checkpoints
+    let mut sequence_numbers = Vec::new();
+    for _ in 0..5 {
+        if let Ok(Some(checkpoint)) = source.next().await {
+            assert!(
+                checkpoint.data.transaction_count > 0,
+                "Checkpoint should contain transactions"
+            );
+            sequence_numbers.push(checkpoint.data.sequence_number);
+        }
+    }
+
+    assert!(!sequence_numbers.is_empty(), "Should fetch checkpoints");
+    assert!(
+        sequence_numbers
+            .windows(2)
+            .all(|pair| pair[1] == pair[0] + 1),
+        "Checkpoints should be emitted sequentially"
+    );
+}
@@ -0,0 +1,67 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiCheckpointTransactionSource, SuiNetwork, SuiSource};
+
+#[tokio::test]
+async fn test_checkpoint_transaction_source_ordering() {
+    let mut source =
+        SuiCheckpointTransactionSource::new(SuiSource::for_network(SuiNetwork::Mainnet, 500, 10))
+            .with_start_checkpoint(100_000_000);
+    source.init().await.expect("Initialization failed");
+
+    // Walk a few checkpoints and check every transaction is emitted in order
+    let mut previous: Option<(u64, usize, bool)> = None;
+    for _ in 0..50 {
+        let record = source
+            .next()
+            .await
+            .expect("Fetching transactions should succeed")
+            .expect("Historical checkpoints are available");
+        let current = (
+            record.data.checkpoint,
+            record.data.position,
+            record.data.last_in_checkpoint,
+        );
+
+        match previous {
+            None => assert_eq!(
+                current.0, 100_000_000,
+                "Should start at the given checkpoint"
+            ),
+            Some((checkpoint, _, true)) => {
+                assert_eq!(
+                    current.0,
+                    checkpoint + 1,
+                    "Checkpoints should not be skipped"
+                );
+                assert_eq!(current.1, 0, "Checkpoints should start at position 0");
+            }
+            Some((checkpoint, position, false)) => {
+                assert_eq!(current.0, checkpoint, "Checkpoints should be complete");
+                assert_eq!(current.1, position + 1, "Transactions should be in order");
+            }
+        }
+        previous = Some(current);
+    }
+}
+
+#[tokio::test]
+async fn test_checkpoint_transaction_source_timestamps_records() {
+    let mut source =
+        SuiCheckpointTransactionSource::new(SuiSource::for_network(SuiNetwork::Mainnet, 500, 10))
+            .with_start_checkpoint(100_000_000);
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Fetching transactions should succeed")
+        .expect("Historical checkpoints are available");
+    assert!(
+        record.data.transaction.timestamp > 0,
+        "Transactions should carry their on-chain timestamp"
+    );
+    assert_eq!(
+        record.timestamp, record.data.transaction.timestamp as i64,
+        "Records should be timestamped with the execution time"
+    );
+}
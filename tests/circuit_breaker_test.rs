@@ -0,0 +1,79 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{CircuitBreakerPolicy, CircuitState, RetryPolicy, SuiNetwork, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_circuit_opens_on_failing_endpoint() {
+    let mut source = SuiSource::builder()
+        .rpc_url("https://invalid.endpoint.example.com")
+        .with_fallback_rpc_url(SuiNetwork::Mainnet.rpc_url())
+        .retry_policy(RetryPolicy::none())
+        .circuit_breaker(CircuitBreakerPolicy {
+            failure_threshold: 1,
+            open_duration: Duration::from_secs(60),
+            max_open_duration: Duration::from_secs(60),
+        })
+        .build()
+        .expect("Valid configuration");
+
+    source
+        .init()
+        .await
+        .expect("Fallback endpoint should be used");
+
+    let health = source.endpoint_health();
+    assert_eq!(health[0].circuit, CircuitState::Open);
+    assert_eq!(health[1].circuit, CircuitState::Closed);
+    assert!(health[1].active, "Fallback endpoint should be active");
+
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching from the fallback should succeed");
+    assert_eq!(
+        source.endpoint_health()[0].total_failures,
+        1,
+        "No request should reach the open endpoint"
+    );
+}
+
+#[tokio::test]
+async fn test_circuit_half_open_after_open_duration() {
+    let mut source = SuiSource::builder()
+        .rpc_url("https://invalid.endpoint.example.com")
+        .retry_policy(RetryPolicy::none())
+        .circuit_breaker(CircuitBreakerPolicy {
+            failure_threshold: 1,
+            open_duration: Duration::from_millis(100),
+            max_open_duration: Duration::from_secs(1),
+        })
+        .build()
+        .expect("Valid configuration");
+
+    assert!(source.init().await.is_err(), "Invalid endpoint should fail");
+    assert_eq!(source.endpoint_health()[0].circuit, CircuitState::Open);
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(source.endpoint_health()[0].circuit, CircuitState::HalfOpen);
+}
+
+#[test]
+fn test_circuit_breaker_configuration() {
+    let result = SuiSource::builder()
+        .circuit_breaker(CircuitBreakerPolicy {
+            failure_threshold: 0,
+            ..CircuitBreakerPolicy::default()
+        })
+        .build();
+    assert!(result.is_err(), "Zero failure threshold should be rejected");
+
+    let result = SuiSource::builder()
+        .circuit_breaker(CircuitBreakerPolicy {
+            open_duration: Duration::from_secs(10),
+            max_open_duration: Duration::from_secs(1),
+            ..CircuitBreakerPolicy::default()
+        })
+        .build();
+    assert!(
+        result.is_err(),
+        "Open duration above its maximum should be rejected"
+    );
+}
@@ -0,0 +1,69 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_balance_changes_enriched_with_coin_metadata() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .coin_metadata(true)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut sui_change_found = false;
+    for _ in 0..50 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        if let Some(change) = record
+            .data
+            .balance_changes
+            .iter()
+            .find(|change| change.coin_type.ends_with("::sui::SUI"))
+        {
+            assert_eq!(change.decimals, Some(9));
+            assert_eq!(change.symbol.as_deref(), Some("SUI"));
+            let formatted = change
+                .formatted_amount
+                .as_ref()
+                .expect("Amount should be scaled");
+            assert_eq!(
+                formatted.replace(['.', '-'], "").trim_start_matches('0'),
+                change.amount.unsigned_abs().to_string(),
+                "Scaling should keep every digit"
+            );
+            sui_change_found = true;
+            break;
+        }
+    }
+    assert!(
+        sui_change_found,
+        "Some transaction should change a SUI balance"
+    );
+}
+
+#[tokio::test]
+async fn test_coin_metadata_enrichment_disabled_by_default() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    assert!(
+        record
+            .data
+            .balance_changes
+            .iter()
+            .all(|change| change.decimals.is_none())
+    );
+}
@@ -0,0 +1,26 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiCoinMetadataSource;
+
+#[tokio::test]
+async fn test_coin_metadata_source_fixed_list() {
+    let mut source = SuiCoinMetadataSource::new(
+        "https://fullnode.mainnet.sui.io:443".to_string(),
+        vec!["0x2::sui::SUI".to_string()],
+        500,
+    );
+    source.init().await.expect("Initialization failed");
+
+    let metadata = source
+        .next()
+        .await
+        .expect("Fetching metadata should succeed")
+        .expect("SUI metadata should be emitted");
+    assert_eq!(metadata.data.symbol, "SUI");
+    assert_eq!(metadata.data.decimals, 9);
+
+    // Every coin type is emitted once
+    assert!(
+        matches!(source.next().await, Ok(None)),
+        "Coin types should not be emitted twice"
+    );
+}
@@ -0,0 +1,23 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiCoinTransferSource, SuiNetwork, SuiSource};
+
+#[tokio::test]
+async fn test_coin_transfer_source_data_fetching() {
+    let mut source =
+        SuiCoinTransferSource::new(SuiSource::for_network(SuiNetwork::Mainnet, 500, 10));
+    source.init().await.expect("Initialization failed");
+
+    let mut transfer_count = 0;
+    for _ in 0..10 {
+        if let Ok(Some(transfer)) = source.next().await {
+            assert_ne!(
+                transfer.data.sender, transfer.data.recipient,
+                "Transfers should go to another address"
+            );
+            assert!(transfer.data.amount > 0, "Amount should be positive");
+            transfer_count += 1;
+        }
+    }
+
+    assert!(transfer_count > 0, "Should decode coin transfers");
+}
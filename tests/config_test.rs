@@ -0,0 +1,73 @@
+#![cfg(feature = "config")]
+
+use fluxus_source_sui::{SuiNetwork, SuiSource, SuiSourceConfig};
+
+#[test]
+fn test_config_from_toml() {
+    let config = SuiSourceConfig::from_toml_str(
+        r#"
+        network = "testnet"
+        interval_ms = 250
+        max_transactions = 50
+
+        [headers]
+        x-api-key = "secret"
+
+        [filter]
+        move_function = "0x2::coin"
+        "#,
+    )
+    .expect("Valid TOML configuration");
+
+    assert_eq!(config.network, Some(SuiNetwork::Testnet));
+    assert_eq!(
+        config.headers.get("x-api-key").map(String::as_str),
+        Some("secret")
+    );
+    assert!(
+        SuiSource::from_config(config).is_ok(),
+        "Configuration should be valid"
+    );
+}
+
+#[test]
+fn test_config_from_yaml() {
+    let config = SuiSourceConfig::from_yaml_str(
+        r#"
+network:
+  custom: "https://rpc.example.com"
+request_timeout_ms: 5000
+filter:
+  checkpoint_range: [100, 200]
+"#,
+    )
+    .expect("Valid YAML configuration");
+
+    assert_eq!(
+        config.network,
+        Some(SuiNetwork::Custom("https://rpc.example.com".to_string()))
+    );
+    assert!(
+        SuiSource::from_config(config).is_ok(),
+        "Configuration should be valid"
+    );
+}
+
+#[test]
+fn test_config_rejects_invalid_input() {
+    assert!(
+        SuiSourceConfig::from_toml_str("max_transaction = 5").is_err(),
+        "Unknown fields should be rejected"
+    );
+
+    let config = SuiSourceConfig::from_toml_str("max_transactions = 500").expect("Valid TOML");
+    assert!(
+        SuiSource::from_config(config).is_err(),
+        "Builder validation should apply"
+    );
+
+    assert!(
+        SuiSource::from_config_file("source.json").is_err(),
+        "Unsupported formats should be rejected"
+    );
+}
@@ -0,0 +1,44 @@
+use fluxus::sources::Source;
+use fluxus::utils::models::StreamError;
+use fluxus_source_sui::{ConversionMode, SuiSource};
+use std::time::Duration;
+use sui_sdk::rpc_types::SuiTransactionBlockResponseOptions;
+
+#[tokio::test]
+async fn test_strict_mode_emits_complete_transactions() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .conversion_mode(ConversionMode::Strict)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    assert_ne!(record.data.sender, "unknown");
+    assert_ne!(record.data.transaction_type, "unknown");
+    assert!(record.data.timestamp > 0, "Timestamp should be set");
+}
+
+#[tokio::test]
+async fn test_strict_mode_without_dead_letter_sink_returns_error() {
+    // Without the transaction input no record can be built
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .query_options(SuiTransactionBlockResponseOptions::new())
+        .conversion_mode(ConversionMode::Strict)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(
+        matches!(result, Err(StreamError::Serialization(_))),
+        "Incomplete transactions should fail in strict mode"
+    );
+}
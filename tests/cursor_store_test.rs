@@ -0,0 +1,71 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{
+    CursorStore, FileCursorStore, SuiCursor, SuiRawTransactionSource, SuiSource,
+};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_file_cursor_store_round_trip() {
+    let path = std::env::temp_dir().join("fluxus_sui_cursor_round_trip.json");
+    let _ = std::fs::remove_file(&path);
+    let store = FileCursorStore::new(&path);
+
+    let loaded = store.load().await.expect("Loading should succeed");
+    assert!(loaded.is_none(), "Missing file should yield no cursor");
+
+    let cursor = SuiCursor {
+        digest: Default::default(),
+        checkpoint: Some(42),
+    };
+    store.save(&cursor).await.expect("Saving should succeed");
+    let loaded = store.load().await.expect("Loading should succeed");
+    assert_eq!(loaded, Some(cursor), "Saved cursor should be loaded");
+
+    let _ = std::fs::remove_file(&path);
+}
+
+fn raw_source(cursor_file: Option<&std::path::Path>) -> SuiRawTransactionSource {
+    let mut builder = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000);
+    if let Some(cursor_file) = cursor_file {
+        builder = builder.cursor_file(cursor_file);
+    }
+    SuiRawTransactionSource::new(builder.build().expect("Valid configuration"))
+}
+
+#[tokio::test]
+async fn test_resume_from_saved_cursor() {
+    let path = std::env::temp_dir().join("fluxus_sui_cursor_resume.json");
+    let _ = std::fs::remove_file(&path);
+
+    // Reference sequence of transactions
+    let mut reference = raw_source(None);
+    reference.init().await.expect("Initialization failed");
+    let mut expected = Vec::new();
+    for _ in 0..4 {
+        let record = reference.next().await.expect("Fetching should succeed");
+        expected.push(record.expect("Transaction").data.digest);
+    }
+
+    // Emit three transactions and save the cursor on close
+    let mut source = raw_source(Some(&path));
+    source.init().await.expect("Initialization failed");
+    for _ in 0..3 {
+        source.next().await.expect("Fetching should succeed");
+    }
+    source.close().await.expect("Closing should succeed");
+
+    // The saved cursor takes precedence over the start checkpoint
+    let mut resumed = raw_source(Some(&path));
+    resumed.init().await.expect("Initialization failed");
+    let record = resumed.next().await.expect("Fetching should succeed");
+    assert_eq!(
+        record.expect("Transaction").data.digest,
+        expected[3],
+        "Source should resume after the last emitted transaction"
+    );
+
+    let _ = std::fs::remove_file(&path);
+}
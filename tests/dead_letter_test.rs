@@ -0,0 +1,31 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiDeadLetter, SuiSource};
+use std::time::Duration;
+use sui_sdk::rpc_types::SuiTransactionBlockResponseOptions;
+use tokio::sync::mpsc;
+
+#[tokio::test]
+async fn test_unconvertible_transactions_are_dead_lettered() {
+    // Without the transaction input no record can be built
+    let (sender, mut receiver) = mpsc::unbounded_channel::<SuiDeadLetter>();
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .query_options(SuiTransactionBlockResponseOptions::new())
+        .dead_letter_sink(sender)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    // Every transaction is skipped, so next() keeps polling
+    let result = tokio::time::timeout(Duration::from_secs(5), source.next()).await;
+    assert!(result.is_err(), "No record should be emitted");
+
+    assert!(
+        source.dead_letter_count() > 0,
+        "Skipped records should be counted"
+    );
+    let dead_letter = receiver.try_recv().expect("Dead letter should be sent");
+    assert!(dead_letter.transaction.transaction.is_none());
+    assert!(!dead_letter.error.is_empty(), "Reason should be given");
+}
@@ -0,0 +1,37 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::collections::HashSet;
+use std::time::Duration;
+
+#[test]
+fn test_dedup_window_configuration() {
+    let result = SuiSource::builder().dedup_window(0).build();
+    assert!(result.is_err(), "Empty dedup window should be rejected");
+
+    let result = SuiSource::builder().dedup_window(100).build();
+    assert!(result.is_ok(), "Non-empty dedup window should be accepted");
+}
+
+#[tokio::test]
+async fn test_no_duplicate_transactions() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .dedup_window(100)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut digests = HashSet::new();
+    for _ in 0..20 {
+        if let Ok(Some(record)) = source.next().await {
+            assert!(
+                digests.insert(record.data.transaction_digest.clone()),
+                "Transaction {} should only be emitted once",
+                record.data.transaction_digest
+            );
+        }
+    }
+
+    assert!(!digests.is_empty(), "Should fetch transactions");
+}
@@ -0,0 +1,31 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiDynamicFieldSource;
+use sui_sdk::types::SUI_SYSTEM_STATE_OBJECT_ID;
+
+#[tokio::test]
+async fn test_dynamic_field_source_baseline() {
+    // The system state object stores its inner state as a dynamic field
+    let mut source = SuiDynamicFieldSource::new(
+        "https://fullnode.mainnet.sui.io:443".to_string(),
+        SUI_SYSTEM_STATE_OBJECT_ID,
+        500,
+    );
+    source.init().await.expect("Initialization failed");
+
+    // The first poll only records the baseline
+    let result = source.next().await;
+    assert!(
+        matches!(result, Ok(None)),
+        "Baseline poll should not emit changes"
+    );
+}
+
+#[tokio::test]
+async fn test_dynamic_field_source_not_initialized() {
+    let mut source = SuiDynamicFieldSource::new(
+        "https://fullnode.mainnet.sui.io:443".to_string(),
+        SUI_SYSTEM_STATE_OBJECT_ID,
+        500,
+    );
+    assert!(source.next().await.is_err());
+}
@@ -0,0 +1,41 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{ObjectChangeKind, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_effects_summary_counts() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..20 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        let event = record.data;
+        assert_eq!(event.events_count, event.events.len());
+
+        let created = event
+            .object_changes
+            .iter()
+            .filter(|change| {
+                matches!(
+                    change.change_type,
+                    ObjectChangeKind::Created | ObjectChangeKind::Published
+                )
+            })
+            .count();
+        assert_eq!(
+            event.created_count, created,
+            "Effects and object changes should agree on created objects"
+        );
+        assert!(
+            event.mutated_count > 0,
+            "Every transaction mutates at least its gas coin or a system object"
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use fluxus_source_sui::SuiSourceBuilder;
+
+// Environment variables are process-wide, so every case runs in one test
+#[test]
+fn test_builder_from_env() {
+    // SAFETY: no other test in this binary reads or writes the environment
+    unsafe {
+        std::env::set_var("SUI_NETWORK", "testnet");
+        std::env::set_var("SUI_POLL_INTERVAL_MS", "250");
+        std::env::set_var("SUI_MAX_TRANSACTIONS", "20");
+        std::env::set_var("SUI_MOVE_FUNCTION", "0x2::coin");
+        std::env::set_var("SUI_RPC_HEADERS", "x-api-key=secret==,x-team=indexer");
+    }
+    let builder = SuiSourceBuilder::from_env().expect("Valid environment");
+    assert!(builder.build().is_ok(), "Configuration should be valid");
+
+    // Invalid numbers are configuration errors rather than silently ignored
+    unsafe { std::env::set_var("SUI_MAX_TRANSACTIONS", "many") };
+    assert!(
+        SuiSourceBuilder::from_env().is_err(),
+        "Invalid batch size should be rejected"
+    );
+    unsafe { std::env::set_var("SUI_MAX_TRANSACTIONS", "20") };
+
+    unsafe { std::env::set_var("SUI_RPC_HEADERS", "authorization") };
+    assert!(
+        SuiSourceBuilder::from_env().is_err(),
+        "Headers without a value should be rejected"
+    );
+    unsafe { std::env::remove_var("SUI_RPC_HEADERS") };
+
+    unsafe { std::env::set_var("SUI_NETWORK", "moonnet") };
+    assert!(
+        SuiSourceBuilder::from_env().is_err(),
+        "Unknown networks should be rejected"
+    );
+}
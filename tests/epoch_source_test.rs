@@ -0,0 +1,25 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiEpochSource;
+
+#[tokio::test]
+async fn test_epoch_source_current_epoch() {
+    let mut source = SuiEpochSource::new("https://fullnode.mainnet.sui.io:443".to_string(), 500);
+    source.init().await.expect("Initialization failed");
+
+    // The current epoch is emitted first
+    let epoch = source
+        .next()
+        .await
+        .expect("Fetching epoch should succeed")
+        .expect("Current epoch should be emitted");
+    assert!(
+        epoch.data.validator_count > 0,
+        "Epoch should have validators"
+    );
+
+    // Polling again within the same epoch emits nothing
+    assert!(
+        matches!(source.next().await, Ok(None)),
+        "Unchanged epoch should not be emitted"
+    );
+}
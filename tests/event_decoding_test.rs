@@ -0,0 +1,152 @@
+use fluxus_source_sui::{
+    BridgeAction, DeepBookAction, KioskAction, NameServiceAction, OrderSide, StakingAction,
+    SuiBridgeEvent, SuiDeepBookEvent, SuiKioskEvent, SuiMoveEvent, SuiNameServiceEvent,
+    SuiStakingEvent,
+};
+use serde_json::json;
+
+fn move_event(event_type: &str, parsed_json: serde_json::Value) -> SuiMoveEvent {
+    SuiMoveEvent {
+        transaction_digest: "digest".to_string(),
+        event_seq: 0,
+        package_id: "0x1".to_string(),
+        module: "module".to_string(),
+        event_type: event_type.to_string(),
+        sender: "0xsender".to_string(),
+        timestamp: 1_700_000_000_000,
+        parsed_json,
+    }
+}
+
+#[test]
+fn test_event_type_parsing() {
+    let event = move_event(
+        "0x2::kiosk::ItemListed<0xabc::nft::Nft<0x2::sui::SUI>>",
+        json!({}),
+    );
+    assert_eq!(event.event_name(), "ItemListed");
+    assert_eq!(event.type_param(), Some("0xabc::nft::Nft<0x2::sui::SUI>"));
+
+    let event = move_event("0x3::validator::StakingRequestEvent", json!({}));
+    assert_eq!(event.event_name(), "StakingRequestEvent");
+    assert_eq!(event.type_param(), None);
+}
+
+#[test]
+fn test_name_service_decoding() {
+    let event = move_event(
+        "0xd22b::register::NameRegistered",
+        json!({
+            "domain": { "labels": ["sui", "example"] },
+            "owner": "0xowner",
+            "expiration_timestamp_ms": "1800000000000",
+        }),
+    );
+
+    let decoded = SuiNameServiceEvent::from(event);
+    assert_eq!(decoded.action, NameServiceAction::Registered);
+    assert_eq!(decoded.name.as_deref(), Some("example.sui"));
+    assert_eq!(decoded.owner, "0xowner");
+    assert_eq!(decoded.expiry, Some(1_800_000_000_000));
+}
+
+#[test]
+fn test_staking_decoding() {
+    let event = move_event(
+        "0x3::validator::UnstakingRequestEvent",
+        json!({
+            "pool_id": "0xpool",
+            "validator_address": "0xvalidator",
+            "staker_address": "0xstaker",
+            "stake_activation_epoch": "400",
+            "unstaking_epoch": "420",
+            "principal_amount": "1000000000",
+            "reward_amount": "5000000",
+        }),
+    );
+
+    let decoded = SuiStakingEvent::decode(&event).expect("Unstaking event should decode");
+    assert_eq!(decoded.action, StakingAction::StakeWithdrawn);
+    assert_eq!(decoded.amount, 1_000_000_000);
+    assert_eq!(decoded.reward_amount, Some(5_000_000));
+    assert_eq!(decoded.epoch, 420);
+
+    // Unrelated validator events are skipped
+    let event = move_event("0x3::validator::ValidatorEpochInfoEventV2", json!({}));
+    assert!(SuiStakingEvent::decode(&event).is_none());
+}
+
+#[test]
+fn test_deepbook_decoding() {
+    let event = move_event(
+        "0x2c8d::order_info::OrderFilled",
+        json!({
+            "pool_id": "0xpool",
+            "maker_order_id": "170141183460469231731687303715884105728",
+            "price": "3500000",
+            "taker_is_bid": false,
+            "base_quantity": "1000",
+            "quote_quantity": "3500",
+        }),
+    );
+
+    let decoded = SuiDeepBookEvent::decode(&event).expect("Fill event should decode");
+    assert_eq!(decoded.action, DeepBookAction::OrderFilled);
+    assert_eq!(decoded.pool_id, "0xpool");
+    assert_eq!(decoded.price, Some(3_500_000));
+    assert_eq!(decoded.quantity, Some(1_000));
+    assert_eq!(decoded.side, Some(OrderSide::Ask));
+}
+
+#[test]
+fn test_kiosk_decoding() {
+    let event = move_event(
+        "0x2::kiosk::ItemPurchased<0xabc::nft::Nft>",
+        json!({
+            "kiosk": "0xkiosk",
+            "id": "0xitem",
+            "price": "2500000000",
+        }),
+    );
+
+    let decoded = SuiKioskEvent::decode(&event).expect("Purchase event should decode");
+    assert_eq!(decoded.action, KioskAction::Purchased);
+    assert_eq!(decoded.item_type, "0xabc::nft::Nft");
+    assert_eq!(decoded.price, Some(2_500_000_000));
+}
+
+#[test]
+fn test_bridge_decoding() {
+    let event = move_event(
+        "0xb::bridge::TokenDepositedEvent",
+        json!({
+            "seq_num": "42",
+            "source_chain": 0,
+            "sender_address": [1, 2, 255],
+            "target_chain": 10,
+            "target_address": [171, 205],
+            "token_type": 2,
+            "amount": "150000000",
+        }),
+    );
+
+    let decoded = SuiBridgeEvent::decode(&event).expect("Deposit event should decode");
+    assert_eq!(decoded.action, BridgeAction::Deposited);
+    assert_eq!(decoded.seq_num, 42);
+    assert_eq!(decoded.source_chain, "sui_mainnet");
+    assert_eq!(decoded.target_chain.as_deref(), Some("eth_mainnet"));
+    assert_eq!(decoded.token.as_deref(), Some("ETH"));
+    assert_eq!(decoded.sender.as_deref(), Some("0x0102ff"));
+    assert_eq!(decoded.recipient.as_deref(), Some("0xabcd"));
+
+    let event = move_event(
+        "0xb::bridge::TokenTransferClaimed",
+        json!({
+            "message_key": { "source_chain": 10, "message_type": 0, "bridge_seq_num": "7" },
+        }),
+    );
+    let decoded = SuiBridgeEvent::decode(&event).expect("Claim event should decode");
+    assert_eq!(decoded.action, BridgeAction::Claimed);
+    assert_eq!(decoded.seq_num, 7);
+    assert_eq!(decoded.source_chain, "eth_mainnet");
+}
@@ -0,0 +1,41 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_with_filter_drops_non_matching_events() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(20)
+        .with_start_checkpoint(100_000_000)
+        .with_filter(|event| event.transaction_type == "ProgrammableTransaction")
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..5 {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            assert_eq!(record.data.transaction_type, "ProgrammableTransaction");
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_filters_combine() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(20)
+        .with_start_checkpoint(100_000_000)
+        .with_filter(|event| event.success)
+        .with_async_filter(|event| async move { event.events_count > 0 })
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..5 {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            assert!(record.data.success);
+            assert!(record.data.events_count > 0);
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiEventSource;
+use sui_sdk::rpc_types::EventFilter;
+use sui_sdk::types::{Identifier, SUI_SYSTEM_PACKAGE_ID};
+
+#[tokio::test]
+async fn test_event_source_data_fetching() {
+    // Stream events from staking calls into the system package
+    let filter = EventFilter::MoveModule {
+        package: SUI_SYSTEM_PACKAGE_ID,
+        module: Identifier::new("sui_system").unwrap(),
+    };
+    let mut source = SuiEventSource::new(
+        "https://fullnode.mainnet.sui.io:443".to_string(),
+        filter,
+        500,
+        5,
+    );
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching event data should succeed");
+
+    if let Ok(Some(event)) = result {
+        assert!(
+            !event.data.transaction_digest.is_empty(),
+            "Transaction ID should not be empty"
+        );
+        assert!(
+            !event.data.event_type.is_empty(),
+            "Event type should not be empty"
+        );
+    }
+}
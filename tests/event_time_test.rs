@@ -0,0 +1,24 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_record_timestamp_is_execution_time() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut emitted = 0;
+    for _ in 0..5 {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            assert!(record.data.timestamp > 0, "Execution time should be known");
+            assert_eq!(record.timestamp, record.data.timestamp as i64);
+            emitted += 1;
+        }
+    }
+    assert!(emitted > 0, "Historical transactions should be emitted");
+}
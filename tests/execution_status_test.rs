@@ -0,0 +1,44 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_execution_status_is_reported() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..20 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        assert_eq!(
+            record.data.success,
+            record.data.execution_error.is_none(),
+            "Only failed transactions should carry an error"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_failed_transactions_can_be_excluded() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(50)
+        .with_start_checkpoint(100_000_000)
+        .include_failed(false)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..100 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        assert!(record.data.success, "Failed transactions should be dropped");
+    }
+}
@@ -0,0 +1,44 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{RetryPolicy, SuiNetwork, SuiSource};
+
+#[tokio::test]
+async fn test_failover_to_fallback_endpoint() {
+    let mut source = SuiSource::builder()
+        .rpc_url("https://invalid.endpoint.example.com")
+        .with_fallback_rpc_url(SuiNetwork::Mainnet.rpc_url())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .expect("Valid configuration");
+
+    // The unreachable primary is skipped during initialization
+    source
+        .init()
+        .await
+        .expect("Fallback endpoint should be used");
+
+    let health = source.endpoint_health();
+    assert_eq!(health.len(), 2, "Primary and fallback should be tracked");
+    assert!(!health[0].active, "Primary endpoint should be inactive");
+    assert_eq!(
+        health[0].total_failures, 1,
+        "Primary failure should be recorded"
+    );
+    assert!(health[1].active, "Fallback endpoint should be active");
+
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching from the fallback should succeed");
+}
+
+#[test]
+fn test_failover_configuration() {
+    let result = SuiSource::builder()
+        .with_fallback_rpc_url("fullnode.testnet.sui.io")
+        .build();
+    assert!(result.is_err(), "Invalid fallback URL should be rejected");
+
+    let result = SuiSource::builder().failover_threshold(0).build();
+    assert!(
+        result.is_err(),
+        "Zero failover threshold should be rejected"
+    );
+}
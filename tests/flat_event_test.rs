@@ -0,0 +1,39 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiEventFlat, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_flat_event_from_event() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    let event = record.data;
+    let flat = SuiEventFlat::from(event.clone());
+
+    assert_eq!(flat.transaction_digest, event.transaction_digest);
+    assert_eq!(flat.events_count, event.events_count as u64);
+    let balance_changes: Vec<serde_json::Value> =
+        serde_json::from_str(&flat.balance_changes).expect("Balance changes should be JSON");
+    assert_eq!(balance_changes.len(), event.balance_changes.len());
+    assert!(!flat.metadata_kind.is_empty());
+
+    // Every column is a scalar
+    let row = serde_json::to_value(&flat).expect("Flat event should serialize");
+    for (column, value) in row.as_object().expect("Flat event is an object") {
+        assert!(
+            !value.is_array() && !value.is_object(),
+            "Column {} is not scalar",
+            column
+        );
+    }
+}
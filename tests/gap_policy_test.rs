@@ -0,0 +1,48 @@
+use fluxus::sources::Source;
+#[cfg(feature = "config")]
+use fluxus_source_sui::SuiSourceConfig;
+use fluxus_source_sui::{GapPolicy, SuiRawTransactionSource, SuiSource};
+use std::time::Duration;
+
+#[test]
+fn test_gap_policy_default() {
+    assert_eq!(GapPolicy::default(), GapPolicy::Backfill);
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn test_gap_policy_configuration() {
+    let config =
+        SuiSourceConfig::from_toml_str(r#"gap_policy = "skip""#).expect("Valid configuration");
+    assert_eq!(config.gap_policy, Some(GapPolicy::Skip));
+
+    let result = SuiSourceConfig::from_toml_str(r#"gap_policy = "ignore""#);
+    assert!(result.is_err(), "Unknown gap policy should be rejected");
+}
+
+#[tokio::test]
+async fn test_live_polling_has_no_gaps() {
+    // Walking forward from the cursor never skips checkpoints, so failing on gaps is safe
+    let mut source = SuiRawTransactionSource::new(
+        SuiSource::builder()
+            .interval(Duration::from_millis(200))
+            .max_transactions(10)
+            .gap_policy(GapPolicy::Fail)
+            .build()
+            .expect("Valid configuration"),
+    );
+    source.init().await.expect("Initialization failed");
+
+    let mut checkpoints = Vec::new();
+    for _ in 0..30 {
+        let record = source.next().await.expect("No gap should be detected");
+        if let Some(checkpoint) = record.and_then(|record| record.data.checkpoint) {
+            checkpoints.push(checkpoint);
+        }
+    }
+
+    assert!(
+        checkpoints.windows(2).all(|pair| pair[1] <= pair[0] + 1),
+        "Emitted checkpoints should be contiguous"
+    );
+}
@@ -0,0 +1,29 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiGasPriceSource;
+
+#[tokio::test]
+async fn test_gas_price_source_percentiles() {
+    let mut source = SuiGasPriceSource::new("https://fullnode.mainnet.sui.io:443".to_string(), 500)
+        .with_percentiles(20);
+    source.init().await.expect("Initialization failed");
+
+    // The current prices are emitted first
+    let price = source
+        .next()
+        .await
+        .expect("Fetching gas price should succeed")
+        .expect("Current gas price should be emitted");
+    assert!(
+        price.data.reference_gas_price > 0,
+        "Reference gas price should be positive"
+    );
+
+    let percentiles = price
+        .data
+        .percentiles
+        .expect("Percentiles should be computed");
+    assert!(
+        percentiles.p50 <= percentiles.p90 && percentiles.p90 <= percentiles.p99,
+        "Percentiles should be ordered"
+    );
+}
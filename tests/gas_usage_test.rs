@@ -0,0 +1,39 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_gas_usage_from_effects() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut user_transaction_found = false;
+    for _ in 0..50 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        let event = record.data;
+        assert_eq!(
+            event.gas_fee,
+            event.computation_cost as i64 + event.storage_cost as i64 - event.storage_rebate as i64,
+            "Gas fee should be the costs minus the rebate"
+        );
+        if event.transaction_type == "ProgrammableTransaction" {
+            assert!(
+                event.computation_cost > 0,
+                "User transactions should pay for computation"
+            );
+            user_transaction_found = true;
+            break;
+        }
+    }
+    assert!(
+        user_transaction_found,
+        "Some user transaction should be streamed"
+    );
+}
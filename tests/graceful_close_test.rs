@@ -0,0 +1,264 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{
+    CursorStore, FileCursorStore, SuiCheckpointSource, SuiCheckpointTransactionSource,
+    SuiObjectChangeSource, SuiSource,
+};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_drain_then_close_persists_cursor() {
+    let path = std::env::temp_dir().join("fluxus_sui_graceful_close.json");
+    let _ = std::fs::remove_file(&path);
+
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .cursor_file(&path)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let first = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    let drained = source.drain().await.expect("Draining should succeed");
+    let last_digest = drained
+        .last()
+        .map_or(&first.data.transaction_digest, |record| {
+            &record.data.transaction_digest
+        })
+        .clone();
+    source.close().await.expect("Closing should succeed");
+
+    let cursor = FileCursorStore::new(&path)
+        .load()
+        .await
+        .expect("Loading should succeed")
+        .expect("Close should persist the cursor");
+    assert_eq!(cursor.digest.to_string(), last_digest);
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_close_without_drain_resumes_buffered_transactions() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let first = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    let second_digest = {
+        let mut reference = SuiSource::builder()
+            .interval(Duration::from_millis(200))
+            .max_transactions(10)
+            .with_start_checkpoint(100_000_000)
+            .build()
+            .expect("Valid configuration");
+        reference.init().await.expect("Initialization failed");
+        reference.next().await.expect("Fetching should succeed");
+        reference
+            .next()
+            .await
+            .expect("Fetching should succeed")
+            .expect("No record available")
+            .data
+            .transaction_digest
+    };
+
+    source.close().await.expect("Closing should succeed");
+    source.init().await.expect("Reinitialization failed");
+    let resumed = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    assert_ne!(
+        resumed.data.transaction_digest,
+        first.data.transaction_digest
+    );
+    assert_eq!(resumed.data.transaction_digest, second_digest);
+}
+
+#[tokio::test]
+async fn test_close_without_drain_resumes_checkpoint_range() {
+    let range_source = || {
+        SuiSource::builder()
+            .interval(Duration::from_millis(200))
+            .max_transactions(10)
+            .with_checkpoint_range(100_000_000, 100_000_001)
+            .build()
+            .expect("Valid configuration")
+    };
+
+    let mut reference = range_source();
+    reference.init().await.expect("Initialization failed");
+    let mut expected = Vec::new();
+    while !reference.is_finished() {
+        if let Some(record) = reference.next().await.expect("Fetching should succeed") {
+            expected.push(record.data.transaction_digest);
+        }
+    }
+
+    // Close with the rest of the first page still buffered
+    let mut source = range_source();
+    source.init().await.expect("Initialization failed");
+    let mut digests = Vec::new();
+    let first = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    digests.push(first.data.transaction_digest);
+    source.close().await.expect("Closing should succeed");
+
+    source.init().await.expect("Reinitialization failed");
+    while !source.is_finished() {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            digests.push(record.data.transaction_digest);
+        }
+    }
+    assert_eq!(digests, expected, "No transaction should be skipped");
+}
+
+#[tokio::test]
+async fn test_close_without_drain_resumes_object_changes() {
+    let object_change_source = || {
+        SuiObjectChangeSource::new(
+            SuiSource::builder()
+                .interval(Duration::from_millis(200))
+                .max_transactions(10)
+                .with_start_checkpoint(100_000_000)
+                .build()
+                .expect("Valid configuration"),
+        )
+    };
+
+    let mut reference = object_change_source();
+    reference.init().await.expect("Initialization failed");
+    let mut expected = Vec::new();
+    while expected.len() < 2 {
+        if let Some(record) = reference.next().await.expect("Fetching should succeed") {
+            expected.push((record.data.transaction_digest, record.data.object_id));
+        }
+    }
+
+    // Close with the rest of the first transaction's changes still buffered
+    let mut source = object_change_source();
+    source.init().await.expect("Initialization failed");
+    let first = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    assert_eq!(
+        (first.data.transaction_digest, first.data.object_id),
+        expected[0]
+    );
+    source.close().await.expect("Closing should succeed");
+
+    source.init().await.expect("Reinitialization failed");
+    let resumed = loop {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            break record;
+        }
+    };
+    assert_eq!(
+        (resumed.data.transaction_digest, resumed.data.object_id),
+        expected[1],
+        "No object change should be skipped or repeated"
+    );
+}
+
+#[tokio::test]
+async fn test_close_without_drain_resumes_checkpoint_transactions() {
+    let checkpoint_transaction_source = || {
+        SuiCheckpointTransactionSource::new(
+            SuiSource::builder()
+                .interval(Duration::from_millis(200))
+                .build()
+                .expect("Valid configuration"),
+        )
+        .with_start_checkpoint(100_000_000)
+    };
+
+    let mut source = checkpoint_transaction_source();
+    source.init().await.expect("Initialization failed");
+    let first = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    source.close().await.expect("Closing should succeed");
+
+    source.init().await.expect("Reinitialization failed");
+    let resumed = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    assert_eq!(resumed.data.checkpoint, first.data.checkpoint);
+    assert!(
+        resumed.data.position > first.data.position,
+        "The checkpoint should resume after the emitted transaction"
+    );
+}
+
+#[tokio::test]
+async fn test_checkpoint_transaction_drain_emits_rest_of_checkpoint() {
+    let mut source = SuiCheckpointTransactionSource::new(
+        SuiSource::builder()
+            .interval(Duration::from_millis(200))
+            .build()
+            .expect("Valid configuration"),
+    )
+    .with_start_checkpoint(100_000_000);
+    source.init().await.expect("Initialization failed");
+
+    let first = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    let drained = source.drain().await.expect("Draining should succeed");
+    let last = drained.last().unwrap_or(&first);
+    assert!(last.data.last_in_checkpoint);
+    assert!(
+        drained
+            .iter()
+            .all(|record| record.data.checkpoint == first.data.checkpoint),
+        "Draining should not fetch the next checkpoint"
+    );
+}
+
+#[tokio::test]
+async fn test_checkpoint_source_close_without_drain_resumes() {
+    let mut source =
+        SuiCheckpointSource::new("https://fullnode.mainnet.sui.io:443".to_string(), 200, 5);
+    source.init().await.expect("Initialization failed");
+
+    let first = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    source.close().await.expect("Closing should succeed");
+
+    source.init().await.expect("Reinitialization failed");
+    let resumed = loop {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            break record;
+        }
+    };
+    assert_eq!(resumed.data.sequence_number, first.data.sequence_number + 1);
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "graphql")]
+
+use fluxus::sources::Source;
+use fluxus_source_sui::{ObjectChangeKind, SuiGraphqlSource, SuiNetwork};
+use sui_sdk::rpc_types::TransactionFilter;
+use sui_sdk::types::base_types::SuiAddress;
+
+#[tokio::test]
+async fn test_graphql_source_data_fetching() {
+    // Create SuiGraphqlSource against the mainnet GraphQL endpoint
+    let mut source = SuiGraphqlSource::for_network(SuiNetwork::Mainnet, 500, 5)
+        .expect("Mainnet has a GraphQL endpoint");
+    source.init().await.expect("Initialization failed");
+
+    // Get first batch of transactions
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching transaction data should succeed");
+
+    if let Ok(Some(transaction)) = result {
+        assert!(
+            !transaction.data.transaction_digest.is_empty(),
+            "Transaction ID should not be empty"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_graphql_source_requires_init() {
+    let mut source = SuiGraphqlSource::for_network(SuiNetwork::Mainnet, 500, 5)
+        .expect("Mainnet has a GraphQL endpoint");
+    assert!(
+        source.next().await.is_err(),
+        "Fetching before initialization should fail"
+    );
+}
+
+#[tokio::test]
+async fn test_graphql_source_maps_effects() {
+    let mut source = SuiGraphqlSource::for_network(SuiNetwork::Mainnet, 500, 5)
+        .expect("Mainnet has a GraphQL endpoint");
+    source.init().await.expect("Initialization failed");
+
+    let Ok(Some(transaction)) = source.next().await else {
+        return;
+    };
+    let event = transaction.data;
+    assert_eq!(
+        event.object_changes.len(),
+        event.created_count
+            + event.mutated_count
+            + event.deleted_count
+            + event
+                .object_changes
+                .iter()
+                .filter(|change| change.change_type == ObjectChangeKind::Wrapped)
+                .count(),
+        "Counts should be derived from the object changes"
+    );
+    assert_eq!(event.uses_shared_objects, !event.shared_objects.is_empty());
+    if event.transaction_type == "ProgrammableTransaction" {
+        assert!(
+            event.auth_scheme.is_some(),
+            "User transactions should carry the sender's signature scheme"
+        );
+    }
+}
+
+#[test]
+fn test_graphql_source_rejects_unsupported_filter() {
+    let source = SuiGraphqlSource::for_network(SuiNetwork::Mainnet, 500, 5)
+        .expect("Mainnet has a GraphQL endpoint");
+    let result = source.with_filter(TransactionFilter::ToAddress(SuiAddress::ZERO));
+    assert!(
+        result.is_err(),
+        "Filters without a GraphQL counterpart should be rejected"
+    );
+}
+
+#[tokio::test]
+async fn test_graphql_source_with_filter() {
+    let mut source = SuiGraphqlSource::for_network(SuiNetwork::Mainnet, 500, 5)
+        .expect("Mainnet has a GraphQL endpoint")
+        .with_filter(TransactionFilter::FromAddress(SuiAddress::ZERO))
+        .expect("Sender filters are supported");
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(transaction)) = source.next().await {
+        assert_eq!(transaction.data.sender, SuiAddress::ZERO.to_string());
+    }
+}
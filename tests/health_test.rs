@@ -0,0 +1,33 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiNetwork, SuiSource};
+
+#[tokio::test]
+async fn test_source_health() {
+    let mut source = SuiSource::for_network(SuiNetwork::Mainnet, 500, 5);
+
+    let health = source.health();
+    assert!(
+        !health.connected,
+        "Source should not be connected before init"
+    );
+    assert!(!health.is_healthy());
+
+    source.init().await.expect("Initialization failed");
+    source.next().await.expect("Fetching should succeed");
+
+    let health = source.health();
+    assert!(health.connected, "Source should be connected after init");
+    assert!(health.is_healthy(), "Source should be healthy");
+    assert!(
+        health.last_successful_fetch.is_some(),
+        "Successful fetch should be recorded"
+    );
+    assert_eq!(health.current_endpoint, SuiNetwork::Mainnet.rpc_url());
+    assert_eq!(health.endpoints.len(), 1);
+
+    source.close().await.expect("Closing should succeed");
+    assert!(
+        !source.health().connected,
+        "Source should be disconnected after close"
+    );
+}
@@ -0,0 +1,49 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiIngestionPhase, SuiSource};
+use std::collections::HashSet;
+use std::time::Duration;
+use sui_sdk::SuiClientBuilder;
+
+#[tokio::test]
+async fn test_backfill_switches_to_live() {
+    let client = SuiClientBuilder::default()
+        .build_mainnet()
+        .await
+        .expect("Mainnet should be reachable");
+    let latest = client
+        .read_api()
+        .get_latest_checkpoint_sequence_number()
+        .await
+        .expect("Latest checkpoint should be available");
+
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(50)
+        .with_start_checkpoint(latest - 5)
+        .build()
+        .expect("Valid configuration");
+    let handle = source.handle();
+    assert_eq!(source.phase(), SuiIngestionPhase::Live);
+    source.init().await.expect("Initialization failed");
+    assert_eq!(source.phase(), SuiIngestionPhase::Backfill);
+
+    let mut digests = HashSet::new();
+    let mut last_checkpoint_timestamp = 0;
+    for _ in 0..500 {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            assert!(
+                digests.insert(record.data.transaction_digest),
+                "Transactions should not be emitted twice"
+            );
+            assert!(record.data.timestamp >= last_checkpoint_timestamp);
+            last_checkpoint_timestamp = record.data.timestamp;
+        }
+        if handle.phase() == SuiIngestionPhase::Live {
+            break;
+        }
+    }
+
+    tokio::time::timeout(Duration::from_secs(1), handle.wait_live())
+        .await
+        .expect("Source should have caught up with the network");
+}
@@ -0,0 +1,37 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiCheckpointTransactionSource, SuiSource};
+
+#[test]
+fn test_hydration_concurrency_configuration() {
+    let result = SuiSource::builder().hydration_concurrency(0).build();
+    assert!(result.is_err(), "Zero concurrency should be rejected");
+}
+
+#[tokio::test]
+async fn test_concurrent_hydration_keeps_order() {
+    let source = SuiSource::builder()
+        .hydration_concurrency(8)
+        .build()
+        .expect("Valid configuration");
+    let mut source = SuiCheckpointTransactionSource::new(source).with_start_checkpoint(100_000_000);
+    source.init().await.expect("Initialization failed");
+
+    // Transactions of a checkpoint are hydrated concurrently but emitted in order
+    let mut expected_position = 0;
+    loop {
+        let record = source
+            .next()
+            .await
+            .expect("Fetching transactions should succeed")
+            .expect("Historical checkpoints are available");
+        assert_eq!(record.data.checkpoint, 100_000_000);
+        assert_eq!(
+            record.data.position, expected_position,
+            "Transactions should be emitted in checkpoint order"
+        );
+        expected_position += 1;
+        if record.data.last_in_checkpoint {
+            break;
+        }
+    }
+}
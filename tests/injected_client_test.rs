@@ -0,0 +1,72 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiNetwork, SuiSource};
+use std::time::Duration;
+use sui_sdk::SuiClientBuilder;
+
+#[tokio::test]
+async fn test_injected_client_reused() {
+    let client = SuiClientBuilder::default()
+        .request_timeout(Duration::from_secs(30))
+        .build(SuiNetwork::Mainnet.rpc_url())
+        .await
+        .expect("Failed to build Sui client");
+
+    let mut source =
+        SuiSource::with_client(client, SuiNetwork::Mainnet.rpc_url().to_string(), 500, 5);
+    source.init().await.expect("Initialization failed");
+    let result = source.next().await;
+    assert!(
+        result.is_ok(),
+        "Fetching with the injected client should succeed"
+    );
+
+    // The injected client is kept across close and init
+    source.close().await.expect("Close failed");
+    source.init().await.expect("Reinitialization failed");
+    let result = source.next().await;
+    assert!(
+        result.is_ok(),
+        "Fetching after reinitialization should succeed"
+    );
+}
+
+#[tokio::test]
+async fn test_injected_client_reports_its_url() {
+    let client = SuiClientBuilder::default()
+        .build(SuiNetwork::Mainnet.rpc_url())
+        .await
+        .expect("Failed to build Sui client");
+
+    let source = SuiSource::with_client(client, SuiNetwork::Mainnet.rpc_url().to_string(), 500, 5);
+    assert_eq!(
+        source.health().current_endpoint,
+        SuiNetwork::Mainnet.rpc_url(),
+        "Health should report the URL the client is connected to"
+    );
+}
+
+#[tokio::test]
+async fn test_builder_injected_client() {
+    let client = SuiClientBuilder::default()
+        .build(SuiNetwork::Mainnet.rpc_url())
+        .await
+        .expect("Failed to build Sui client");
+
+    let mut source = SuiSource::builder()
+        .network(SuiNetwork::Mainnet)
+        .client(client)
+        .max_transactions(5)
+        .build()
+        .expect("Failed to build SuiSource");
+    assert_eq!(
+        source.health().current_endpoint,
+        SuiNetwork::Mainnet.rpc_url()
+    );
+
+    source.init().await.expect("Initialization failed");
+    let result = source.next().await;
+    assert!(
+        result.is_ok(),
+        "Fetching with the client supplied to the builder should succeed"
+    );
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "json-schema")]
+
+use fluxus_source_sui::{payload_json_schemas, sui_event_json_schema};
+
+#[test]
+fn test_sui_event_json_schema() {
+    let schema = serde_json::to_value(sui_event_json_schema()).expect("Schema should serialize");
+    assert_eq!(schema["title"], "SuiEvent");
+    let properties = schema["properties"]
+        .as_object()
+        .expect("SuiEvent should have properties");
+    for field in [
+        "transaction_digest",
+        "sender",
+        "balance_changes",
+        "metadata",
+    ] {
+        assert!(properties.contains_key(field), "Missing property {}", field);
+    }
+}
+
+#[test]
+fn test_payload_json_schemas_cover_sources() {
+    let schemas = payload_json_schemas();
+    assert!(schemas.contains_key("SuiEvent"));
+    assert!(schemas.contains_key("SuiCheckpoint"));
+    assert!(schemas.contains_key("SuiMoveEvent"));
+}
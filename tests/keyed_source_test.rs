@@ -0,0 +1,36 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiKeyedSource, SuiSource};
+use std::time::Duration;
+
+fn source() -> SuiSource {
+    SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration")
+}
+
+#[tokio::test]
+async fn test_keyed_source_keys_by_sender() {
+    let mut source = SuiKeyedSource::new(source());
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..5 {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            assert_eq!(record.data.key, record.data.event.sender);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_keyed_source_custom_key_fn() {
+    let mut source = SuiKeyedSource::with_key_fn(source(), |event| event.transaction_type.clone());
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..5 {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            assert_eq!(record.data.key, record.data.event.transaction_type);
+        }
+    }
+}
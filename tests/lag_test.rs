@@ -0,0 +1,34 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_lag_of_historical_resume() {
+    let mut source = SuiSource::builder()
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .lag_check_interval(Duration::from_millis(100))
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+    assert_eq!(source.lag(), None, "Lag is unknown before emitting");
+
+    let record = source.next().await.expect("Fetching should succeed");
+    assert!(
+        record.is_some(),
+        "Historical transactions should be available"
+    );
+
+    // Resuming far in the past leaves the source far behind the network
+    let lag = source.lag().expect("Lag should be measured while polling");
+    assert!(lag > 1_000, "Source should lag behind, got {}", lag);
+
+    let refreshed = source
+        .refresh_lag()
+        .await
+        .expect("Refreshing should succeed");
+    assert!(
+        refreshed >= Some(lag),
+        "Lag should not shrink without progress"
+    );
+}
@@ -0,0 +1,50 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{LoadBalancing, SuiNetwork, SuiSource};
+
+#[tokio::test]
+async fn test_round_robin_load_balancing() {
+    let mut source = SuiSource::builder()
+        .network(SuiNetwork::Mainnet)
+        .with_fallback_rpc_url(SuiNetwork::Mainnet.rpc_url())
+        .load_balancing(LoadBalancing::RoundRobin)
+        .max_transactions(1)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..4 {
+        let result = source.next().await;
+        assert!(result.is_ok(), "Fetching should succeed");
+    }
+
+    // Both endpoints should have served requests
+    let health = source.endpoint_health();
+    assert!(
+        health.iter().all(|endpoint| endpoint.latency_ms.is_some()),
+        "Requests should be spread across all endpoints"
+    );
+}
+
+#[test]
+fn test_weighted_load_balancing_configuration() {
+    let result = SuiSource::builder()
+        .with_fallback_rpc_url(SuiNetwork::Testnet.rpc_url())
+        .load_balancing(LoadBalancing::Weighted(vec![1]))
+        .build();
+    assert!(
+        result.is_err(),
+        "Weights must match the number of endpoints"
+    );
+
+    let result = SuiSource::builder()
+        .with_fallback_rpc_url(SuiNetwork::Testnet.rpc_url())
+        .load_balancing(LoadBalancing::Weighted(vec![0, 0]))
+        .build();
+    assert!(result.is_err(), "All-zero weights should be rejected");
+
+    let result = SuiSource::builder()
+        .with_fallback_rpc_url(SuiNetwork::Testnet.rpc_url())
+        .load_balancing(LoadBalancing::Weighted(vec![3, 1]))
+        .build();
+    assert!(result.is_ok(), "Matching weights should be accepted");
+}
@@ -0,0 +1,87 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{ProgrammableCommand, SuiSource, TransactionMetadata};
+use std::time::Duration;
+
+#[test]
+fn test_metadata_serializes_to_tagged_json() {
+    let metadata = TransactionMetadata::Programmable {
+        commands: vec![
+            ProgrammableCommand::MoveCall {
+                package: "0x2".to_string(),
+                module: "pay".to_string(),
+                function: "split".to_string(),
+                type_arguments: vec!["0x2::sui::SUI".to_string()],
+            },
+            ProgrammableCommand::TransferObjects,
+        ],
+    };
+    let json = serde_json::to_value(&metadata).expect("Metadata should serialize");
+    assert_eq!(json["kind"], "programmable");
+    assert_eq!(json["commands"][0]["command"], "move_call");
+    assert_eq!(json["commands"][0]["type_arguments"][0], "0x2::sui::SUI");
+    assert_eq!(json["commands"][1]["command"], "transfer_objects");
+
+    let parsed: TransactionMetadata =
+        serde_json::from_value(json).expect("Metadata should deserialize");
+    assert_eq!(parsed, metadata);
+}
+
+#[tokio::test]
+async fn test_metadata_matches_transaction_type() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..20 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        let event = record.data;
+        if event.transaction_type == "ProgrammableTransaction" {
+            let TransactionMetadata::Programmable { commands, .. } = event.metadata else {
+                panic!("Programmable transactions should have programmable metadata");
+            };
+            assert!(!commands.is_empty(), "Commands should be listed");
+        }
+        assert_ne!(event.metadata, TransactionMetadata::Unknown);
+    }
+}
+
+#[tokio::test]
+async fn test_move_calls_broken_down() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(50)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..200 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        let TransactionMetadata::Programmable { commands } = record.data.metadata else {
+            continue;
+        };
+        let move_call = commands.into_iter().find_map(|command| match command {
+            ProgrammableCommand::MoveCall {
+                package,
+                module,
+                function,
+                ..
+            } => Some((package, module, function)),
+            _ => None,
+        });
+        if let Some((package, module, function)) = move_call {
+            assert!(package.starts_with("0x"));
+            assert!(!module.is_empty() && !function.is_empty());
+            return;
+        }
+    }
+    panic!("Some transaction should call a Move function");
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "test-util")]
+
+use fluxus::sources::Source;
+use fluxus_source_sui::{MockEnd, MockSuiSource};
+
+#[tokio::test]
+async fn test_mock_source_script() {
+    let mut source = MockSuiSource::new()
+        .with_event(MockSuiSource::event("first"))
+        .with_empty()
+        .with_error("node unavailable")
+        .with_event(MockSuiSource::event("second"));
+    source.init().await.expect("Initialization failed");
+
+    let first = source
+        .next()
+        .await
+        .expect("Event expected")
+        .expect("Event expected");
+    assert_eq!(first.data.transaction_digest, "first");
+    assert!(
+        matches!(source.next().await, Ok(None)),
+        "Empty poll expected"
+    );
+    assert!(source.next().await.is_err(), "Error expected");
+    let second = source
+        .next()
+        .await
+        .expect("Event expected")
+        .expect("Event expected");
+    assert_eq!(second.data.transaction_digest, "second");
+
+    // The script is exhausted
+    assert!(matches!(source.next().await, Ok(None)), "Idle end expected");
+}
+
+#[tokio::test]
+async fn test_mock_source_end_behavior() {
+    let mut source = MockSuiSource::new()
+        .with_event(MockSuiSource::event("only"))
+        .with_end(MockEnd::Repeat);
+    source.init().await.expect("Initialization failed");
+    for _ in 0..3 {
+        let event = source
+            .next()
+            .await
+            .expect("Event expected")
+            .expect("Event expected");
+        assert_eq!(event.data.transaction_digest, "only");
+    }
+
+    let mut source = MockSuiSource::new().with_end(MockEnd::Error("end of stream".to_string()));
+    source.init().await.expect("Initialization failed");
+    assert!(source.next().await.is_err(), "Error end expected");
+}
+
+#[tokio::test]
+async fn test_mock_source_init_error() {
+    let mut source = MockSuiSource::new().with_init_error("bad endpoint");
+    assert!(source.init().await.is_err(), "Init error expected");
+    assert!(
+        source.next().await.is_err(),
+        "Uninitialized source should fail"
+    );
+}
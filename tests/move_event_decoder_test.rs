@@ -0,0 +1,38 @@
+#[cfg(feature = "ingestion")]
+use fluxus::sources::Source;
+#[cfg(feature = "ingestion")]
+use fluxus_source_sui::SuiCheckpointFileSource;
+use fluxus_source_sui::{MoveEventDecoder, SuiNetwork};
+
+#[cfg(feature = "ingestion")]
+#[tokio::test]
+async fn test_checkpoint_events_decoded_with_package_layouts() {
+    let mut source = SuiCheckpointFileSource::for_network(SuiNetwork::Mainnet, 100_000_000, 500)
+        .expect("Mainnet publishes checkpoints")
+        .with_event_decoder(MoveEventDecoder::for_network(SuiNetwork::Mainnet));
+    source.init().await.expect("Initialization failed");
+
+    let mut event_found = false;
+    for _ in 0..200 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        if let Some(event) = record.data.events.first() {
+            assert!(
+                event.parsed_json.is_object(),
+                "Event {} should decode to its fields",
+                event.event_type
+            );
+            event_found = true;
+            break;
+        }
+    }
+    assert!(event_found, "Some transaction should emit events");
+}
+
+#[tokio::test]
+async fn test_decoder_rejects_invalid_types() {
+    let mut decoder = MoveEventDecoder::for_network(SuiNetwork::Mainnet);
+    assert!(decoder.decode("not a type", &[]).await.is_err());
+    assert_eq!(decoder.cached_packages(), 0);
+}
@@ -0,0 +1,95 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiMultiAddressSource, SuiNetwork, SuiSource};
+use std::collections::HashSet;
+use std::time::Duration;
+use sui_sdk::types::base_types::SuiAddress;
+
+#[tokio::test]
+async fn test_multi_address_source_deduplication() {
+    // The zero address is both sender and recipient of system transactions
+    let mut source = SuiMultiAddressSource::new(
+        "https://fullnode.mainnet.sui.io:443".to_string(),
+        vec![SuiAddress::ZERO],
+        500,
+        5,
+    );
+    source.init().await.expect("Initialization failed");
+
+    let mut digests = HashSet::new();
+    for _ in 0..5 {
+        if let Ok(Some(event)) = source.next().await {
+            assert!(
+                digests.insert(event.data.transaction_digest.clone()),
+                "Transactions should be emitted once"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_multi_address_source_applies_event_filters() {
+    let source = SuiSource::builder()
+        .network(SuiNetwork::Mainnet)
+        .max_transactions(5)
+        .with_filter(|event| event.success)
+        .build()
+        .expect("Valid configuration");
+    let mut source = SuiMultiAddressSource::from_source(source, vec![SuiAddress::ZERO])
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..5 {
+        if let Ok(Some(event)) = source.next().await {
+            assert!(
+                event.data.success,
+                "Failed transactions should be filtered out"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_multi_address_source_pause() {
+    let mut source = SuiMultiAddressSource::new(
+        "https://fullnode.mainnet.sui.io:443".to_string(),
+        vec![SuiAddress::ZERO],
+        200,
+        5,
+    );
+    source.init().await.expect("Initialization failed");
+
+    let handle = source.handle();
+    handle.pause();
+    let record = source.next().await.expect("Paused source should not fail");
+    assert!(record.is_none(), "Paused source should not emit");
+}
+
+#[tokio::test]
+async fn test_multi_address_source_next_timeout() {
+    // No fullnode answers within a millisecond
+    let source = SuiSource::builder()
+        .network(SuiNetwork::Mainnet)
+        .next_timeout(Duration::from_millis(1))
+        .build()
+        .expect("Valid configuration");
+    let mut source = SuiMultiAddressSource::from_source(source, vec![SuiAddress::ZERO])
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(result.is_err(), "Polling past the deadline should fail");
+}
+
+#[test]
+fn test_multi_address_source_rejects_watermarks() {
+    let source = SuiSource::builder()
+        .network(SuiNetwork::Mainnet)
+        .watermarks(true)
+        .build()
+        .expect("Valid configuration");
+    let result = SuiMultiAddressSource::from_source(source, vec![SuiAddress::ZERO]);
+    assert!(
+        result.is_err(),
+        "Watermarks should be rejected for out of order addresses"
+    );
+}
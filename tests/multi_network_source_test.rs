@@ -0,0 +1,60 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiMultiNetworkSource, SuiNetwork, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_multi_network_source_tags_records() {
+    let mut source =
+        SuiMultiNetworkSource::new(vec![SuiNetwork::Mainnet, SuiNetwork::Testnet], 200, 5);
+    assert_eq!(
+        source.networks(),
+        &[SuiNetwork::Mainnet, SuiNetwork::Testnet]
+    );
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..10 {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            assert!(
+                matches!(
+                    record.data.network,
+                    SuiNetwork::Mainnet | SuiNetwork::Testnet
+                ),
+                "Records should be tagged with their network"
+            );
+            assert!(!record.data.event.transaction_digest.is_empty());
+        }
+    }
+
+    source.close().await.expect("Close failed");
+}
+
+#[tokio::test]
+async fn test_multi_network_source_with_configured_sources() {
+    let mainnet = SuiSource::builder()
+        .network(SuiNetwork::Mainnet)
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_checkpoint_range(100_000_000, 100_000_000)
+        .build()
+        .expect("Valid configuration");
+    let mut source = SuiMultiNetworkSource::default().with_source(SuiNetwork::Mainnet, mainnet);
+    source.init().await.expect("Initialization failed");
+
+    let mut count = 0;
+    while let Some(record) = source.next().await.expect("Fetching should succeed") {
+        assert_eq!(record.data.network, SuiNetwork::Mainnet);
+        count += 1;
+    }
+    assert!(
+        count > 0,
+        "The checkpoint should be emitted before the stream ends"
+    );
+
+    source.close().await.expect("Close failed");
+}
+
+#[tokio::test]
+async fn test_multi_network_source_requires_networks() {
+    let mut source = SuiMultiNetworkSource::default();
+    assert!(source.init().await.is_err());
+}
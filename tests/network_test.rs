@@ -0,0 +1,61 @@
+#[cfg(feature = "graphql")]
+use fluxus_source_sui::SuiGraphqlSource;
+use fluxus_source_sui::{SuiNetwork, SuiSource};
+
+#[test]
+fn test_network_endpoints() {
+    assert_eq!(
+        SuiNetwork::Testnet.rpc_url(),
+        "https://fullnode.testnet.sui.io:443"
+    );
+    assert_eq!(
+        SuiNetwork::Testnet.ws_url(),
+        "wss://fullnode.testnet.sui.io:443"
+    );
+    assert_eq!(SuiNetwork::Localnet.ws_url(), "ws://127.0.0.1:9000");
+
+    let custom = SuiNetwork::Custom("https://rpc.example.com".to_string());
+    assert_eq!(custom.rpc_url(), "https://rpc.example.com");
+    assert!(
+        custom.graphql_url().is_none(),
+        "Custom networks have no known GraphQL endpoint"
+    );
+    assert!(SuiNetwork::Devnet.checkpoint_bucket_url().is_none());
+}
+
+#[test]
+fn test_network_from_str() {
+    assert_eq!(
+        "Mainnet".parse::<SuiNetwork>().ok(),
+        Some(SuiNetwork::Mainnet)
+    );
+    assert_eq!(
+        "localnet".parse::<SuiNetwork>().ok(),
+        Some(SuiNetwork::Localnet)
+    );
+    assert_eq!(
+        "http://10.0.0.1:9000".parse::<SuiNetwork>().ok(),
+        Some(SuiNetwork::Custom("http://10.0.0.1:9000".to_string()))
+    );
+    assert!(
+        "moonnet".parse::<SuiNetwork>().is_err(),
+        "Unknown names should be rejected"
+    );
+}
+
+#[test]
+fn test_network_constructors() {
+    let source = SuiSource::builder().network(SuiNetwork::Devnet).build();
+    assert!(source.is_ok(), "Network endpoints should be valid");
+}
+
+#[cfg(feature = "graphql")]
+#[test]
+fn test_graphql_network_constructors() {
+    let source = SuiGraphqlSource::for_network(
+        SuiNetwork::Custom("https://rpc.example.com".to_string()),
+        500,
+        5,
+    );
+    assert!(source.is_err(), "Custom networks have no GraphQL endpoint");
+}
@@ -0,0 +1,28 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiNetwork, SuiNftTransferSource, SuiSource};
+
+#[tokio::test]
+async fn test_nft_transfer_source_not_initialized() {
+    let mut source = SuiNftTransferSource::new(SuiSource::for_network(SuiNetwork::Mainnet, 500, 5));
+    assert!(
+        source.next().await.is_err(),
+        "Uninitialized source should fail"
+    );
+}
+
+#[tokio::test]
+async fn test_nft_transfer_source_data_fetching() {
+    let mut source =
+        SuiNftTransferSource::new(SuiSource::for_network(SuiNetwork::Mainnet, 500, 10));
+    source.init().await.expect("Initialization failed");
+
+    // NFT transfers are rare, only check the records that do show up
+    for _ in 0..10 {
+        if let Ok(Some(transfer)) = source.next().await {
+            assert!(
+                !transfer.data.collection.is_empty(),
+                "Collection should not be empty"
+            );
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiNetwork, SuiObjectChangeSource, SuiSource};
+
+#[tokio::test]
+async fn test_object_change_source_data_fetching() {
+    // Wrap a mainnet transaction source
+    let mut source =
+        SuiObjectChangeSource::new(SuiSource::for_network(SuiNetwork::Mainnet, 500, 5));
+    source.init().await.expect("Initialization failed");
+
+    // Object changes are only emitted for transactions touching objects
+    let mut change_count = 0;
+    for _ in 0..10 {
+        if let Ok(Some(change)) = source.next().await {
+            assert!(
+                !change.data.object_id.is_empty(),
+                "Object ID should not be empty"
+            );
+            change_count += 1;
+        }
+    }
+
+    assert!(change_count > 0, "Should extract object changes");
+}
@@ -0,0 +1,20 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiOwnedObjectsSource;
+use sui_sdk::types::base_types::SuiAddress;
+
+#[tokio::test]
+async fn test_owned_objects_source_baseline() {
+    let mut source = SuiOwnedObjectsSource::new(
+        "https://fullnode.mainnet.sui.io:443".to_string(),
+        vec![SuiAddress::ZERO],
+        500,
+    );
+    source.init().await.expect("Initialization failed");
+
+    // The first poll only records the baseline
+    let result = source.next().await;
+    assert!(
+        matches!(result, Ok(None)),
+        "Baseline poll should not emit changes"
+    );
+}
@@ -0,0 +1,145 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiPackagePublishSource, SuiSource};
+use serde_json::{Value, json};
+use std::sync::{Arc, Mutex};
+use sui_sdk::types::base_types::ObjectID;
+use sui_sdk::types::digests::{ObjectDigest, TransactionDigest};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+/// Transaction publishing a package, as returned by `suix_queryTransactionBlocks`
+fn publish_transaction(digest: TransactionDigest, package_id: ObjectID, version: u64) -> Value {
+    json!({
+        "digest": digest.to_string(),
+        "timestampMs": "1700000000000",
+        "objectChanges": [{
+            "type": "published",
+            "packageId": package_id.to_string(),
+            "version": version.to_string(),
+            "digest": ObjectDigest::new([0; 32]).to_string(),
+            "modules": ["pool", "router"],
+        }],
+    })
+}
+
+/// Serves a JSON-RPC node returning the given transactions on the first
+/// `suix_queryTransactionBlocks` call and nothing after, and returns its URL
+async fn mock_transaction_node(transactions: Vec<Value>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind mock node");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let transactions = Arc::new(Mutex::new(Some(transactions)));
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let transactions = transactions.clone();
+            tokio::spawn(async move {
+                let (reader, mut writer) = stream.into_split();
+                let mut reader = BufReader::new(reader);
+                loop {
+                    // Headers, only the body length matters
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        if reader.read_line(&mut line).await.unwrap_or(0) == 0 {
+                            return;
+                        }
+                        let line = line.trim_end().to_ascii_lowercase();
+                        if line.is_empty() {
+                            break;
+                        }
+                        if let Some(length) = line.strip_prefix("content-length:") {
+                            content_length = length.trim().parse().unwrap_or(0);
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    if reader.read_exact(&mut body).await.is_err() {
+                        return;
+                    }
+                    let request: Value = serde_json::from_slice(&body).unwrap_or_default();
+
+                    let result = match request["method"].as_str() {
+                        Some("rpc.discover") => json!({
+                            "info": { "version": "1.0.0" },
+                            "methods": [{ "name": "suix_queryTransactionBlocks" }],
+                        }),
+                        Some("suix_queryTransactionBlocks") => {
+                            let data = transactions.lock().unwrap().take().unwrap_or_default();
+                            json!({
+                                "data": data,
+                                "nextCursor": data.first().map(|transaction| &transaction["digest"]),
+                                "hasNextPage": false,
+                            })
+                        }
+                        Some("sui_getLatestCheckpointSequenceNumber") => json!("1"),
+                        _ => Value::Null,
+                    };
+                    let response = json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "result": result,
+                    })
+                    .to_string();
+                    let reply = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                        response.len(),
+                        response
+                    );
+                    if writer.write_all(reply.as_bytes()).await.is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    url
+}
+
+#[tokio::test]
+async fn test_package_source_publish_and_upgrade() {
+    let published_package = ObjectID::from_single_byte(0xa);
+    let upgraded_package = ObjectID::from_single_byte(0xb);
+    let publish_digest = TransactionDigest::new([1; 32]);
+    let upgrade_digest = TransactionDigest::new([2; 32]);
+
+    // The first page is queried newest first
+    let url = mock_transaction_node(vec![
+        publish_transaction(upgrade_digest, upgraded_package, 3),
+        publish_transaction(publish_digest, published_package, 1),
+    ])
+    .await;
+    let mut source = SuiPackagePublishSource::new(SuiSource::new(url, 10, 10));
+    source.init().await.expect("Initialization failed");
+
+    let publish = source
+        .next()
+        .await
+        .expect("Polling should succeed")
+        .expect("Publish should be emitted")
+        .data;
+    assert_eq!(publish.transaction_digest, publish_digest.to_string());
+    assert_eq!(publish.package_id, published_package.to_string());
+    assert_eq!(publish.modules, vec!["pool", "router"]);
+    assert_eq!(publish.version, 1);
+    assert!(!publish.is_upgrade, "Version 1 is a fresh publish");
+
+    let upgrade = source
+        .next()
+        .await
+        .expect("Polling should succeed")
+        .expect("Upgrade should be emitted")
+        .data;
+    assert_eq!(upgrade.transaction_digest, upgrade_digest.to_string());
+    assert_eq!(upgrade.package_id, upgraded_package.to_string());
+    assert_eq!(upgrade.version, 3);
+    assert!(upgrade.is_upgrade, "Later versions are upgrades");
+
+    // Nothing else was published
+    let result = source.next().await.expect("Polling should succeed");
+    assert!(
+        result.is_none(),
+        "Only published packages should be emitted"
+    );
+}
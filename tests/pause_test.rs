@@ -0,0 +1,31 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiNetwork, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_pause_and_resume() {
+    let mut source = SuiSource::for_network(SuiNetwork::Mainnet, 200, 5);
+    source.init().await.expect("Initialization failed");
+    let handle = source.handle();
+
+    handle.pause();
+    assert!(source.is_paused(), "Pause should apply to the source");
+    let record = source.next().await.expect("Paused source should not fail");
+    assert!(record.is_none(), "Paused source should not emit");
+
+    // Resuming from another task wakes up the idle source
+    let resumer = handle.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        resumer.resume();
+    });
+    let mut emitted = false;
+    for _ in 0..10 {
+        if let Ok(Some(_)) = source.next().await {
+            emitted = true;
+            break;
+        }
+    }
+    assert!(!source.is_paused(), "Source should be resumed");
+    assert!(emitted, "Resumed source should emit transactions");
+}
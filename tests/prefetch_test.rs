@@ -0,0 +1,39 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiRawTransactionSource, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_prefetch_matches_sequential_fetching() {
+    let raw_source = |prefetch_depth| {
+        SuiRawTransactionSource::new(
+            SuiSource::builder()
+                .interval(Duration::from_millis(200))
+                .max_transactions(5)
+                .with_start_checkpoint(100_000_000)
+                .prefetch_depth(prefetch_depth)
+                .build()
+                .expect("Valid configuration"),
+        )
+    };
+
+    let mut sequential = raw_source(0);
+    let mut prefetching = raw_source(3);
+    sequential.init().await.expect("Initialization failed");
+    prefetching.init().await.expect("Initialization failed");
+
+    // Prefetching must not change the emitted sequence
+    for _ in 0..20 {
+        let expected = sequential.next().await.expect("Fetching should succeed");
+        let actual = prefetching
+            .next()
+            .await
+            .expect("Prefetching should succeed");
+        assert_eq!(
+            actual.map(|record| record.data.digest),
+            expected.map(|record| record.data.digest),
+            "Prefetched transactions should match sequentially fetched ones"
+        );
+    }
+
+    prefetching.close().await.expect("Closing should succeed");
+}
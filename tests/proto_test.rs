@@ -0,0 +1,31 @@
+#![cfg(feature = "proto")]
+
+use fluxus::sources::Source;
+use fluxus_source_sui::{ProtoSuiEvent, SuiEvent, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_protobuf_round_trip() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    let message = ProtoSuiEvent::from(record.data.clone());
+    assert_eq!(message.transaction_digest, record.data.transaction_digest);
+    assert_eq!(message.events.len(), record.data.events.len());
+
+    let decoded = SuiEvent::from_protobuf(&record.data.to_protobuf()).expect("Event should decode");
+    assert_eq!(
+        serde_json::to_value(&decoded).expect("Event should serialize"),
+        serde_json::to_value(&record.data).expect("Event should serialize")
+    );
+}
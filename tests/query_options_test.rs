@@ -0,0 +1,70 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+#[cfg(feature = "config")]
+use fluxus_source_sui::SuiSourceConfig;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_unrequested_fields_left_empty() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .show_events(false)
+        .show_object_changes(false)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..5 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        assert!(record.data.events.is_empty());
+        assert!(record.data.object_changes.is_empty());
+        assert!(!record.data.transaction_digest.is_empty());
+    }
+}
+
+#[tokio::test]
+async fn test_raw_effects_requested() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .show_raw_effects(true)
+        .include_raw_json(true)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    let raw = record.data.raw.expect("Raw response should be attached");
+    assert!(raw["rawEffects"].is_array());
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn test_query_options_from_config() {
+    let config = SuiSourceConfig::from_toml_str(
+        r#"
+        [query_options]
+        events = false
+        raw_input = true
+        "#,
+    )
+    .expect("Valid TOML configuration");
+    assert!(
+        SuiSource::from_config(config).is_ok(),
+        "Configuration should be valid"
+    );
+
+    assert!(
+        SuiSourceConfig::from_toml_str("[query_options]\nsignatures = true").is_err(),
+        "Unknown options should be rejected"
+    );
+}
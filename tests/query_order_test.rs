@@ -0,0 +1,70 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiQueryOrder, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_ascending_order_starts_at_genesis() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .order(SuiQueryOrder::Ascending)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut last_timestamp = 0;
+    for _ in 0..10 {
+        let record = source
+            .next()
+            .await
+            .expect("Failed to get next record")
+            .expect("No record available");
+        let timestamp = record.data.timestamp;
+        assert!(
+            timestamp >= last_timestamp,
+            "Records should be chronological"
+        );
+        last_timestamp = timestamp;
+    }
+    // Mainnet launched in May 2023
+    assert!(
+        last_timestamp < 1_685_000_000_000,
+        "Should start at the oldest transactions"
+    );
+}
+
+#[tokio::test]
+async fn test_descending_order_walks_backward() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .order(SuiQueryOrder::Descending)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut last_timestamp = u64::MAX;
+    for _ in 0..10 {
+        let record = source
+            .next()
+            .await
+            .expect("Failed to get next record")
+            .expect("No record available");
+        let timestamp = record.data.timestamp;
+        assert!(
+            timestamp <= last_timestamp,
+            "Records should be newest first"
+        );
+        last_timestamp = timestamp;
+    }
+    assert!(!source.is_finished());
+}
+
+#[test]
+fn test_descending_order_rejects_checkpoint_ranges() {
+    let result = SuiSource::builder()
+        .order(SuiQueryOrder::Descending)
+        .with_checkpoint_range(100, 200)
+        .build();
+    assert!(result.is_err());
+}
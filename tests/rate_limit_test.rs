@@ -0,0 +1,46 @@
+use fluxus_source_sui::{SuiRateLimiter, SuiSource};
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_rate_limiter_throttles() {
+    let rate_limiter = SuiRateLimiter::new(10, 1).expect("Valid rate limit");
+
+    // Clones share the bucket, so the second and third request have to wait
+    let shared = rate_limiter.clone();
+    let start = Instant::now();
+    rate_limiter.acquire().await;
+    shared.acquire().await;
+    rate_limiter.acquire().await;
+    assert!(
+        start.elapsed() >= Duration::from_millis(150),
+        "Requests beyond the burst should be delayed"
+    );
+}
+
+#[test]
+fn test_rate_limit_configuration() {
+    assert!(
+        SuiRateLimiter::new(0, 1).is_err(),
+        "Zero rate should be rejected"
+    );
+    assert!(
+        SuiRateLimiter::new(1, 0).is_err(),
+        "Zero burst should be rejected"
+    );
+
+    let result = SuiSource::builder().rate_limit(0, 10).build();
+    assert!(
+        result.is_err(),
+        "Zero rate should be rejected by the builder"
+    );
+
+    let rate_limiter = SuiRateLimiter::new(20, 5).expect("Valid rate limit");
+    let first = SuiSource::builder()
+        .rate_limiter(rate_limiter.clone())
+        .build();
+    let second = SuiSource::builder().rate_limiter(rate_limiter).build();
+    assert!(
+        first.is_ok() && second.is_ok(),
+        "Sources should accept a shared rate limiter"
+    );
+}
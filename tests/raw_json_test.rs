@@ -0,0 +1,45 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_raw_json_attached_when_enabled() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .include_raw_json(true)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    let raw = record.data.raw.expect("Raw response should be attached");
+    assert_eq!(raw["digest"], record.data.transaction_digest);
+    assert!(
+        raw["effects"].is_object(),
+        "Requested effects should be included"
+    );
+}
+
+#[tokio::test]
+async fn test_raw_json_omitted_by_default() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    assert!(record.data.raw.is_none());
+}
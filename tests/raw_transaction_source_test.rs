@@ -0,0 +1,28 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiRawTransactionSource, SuiSource};
+use sui_sdk::rpc_types::SuiTransactionBlockResponseOptions;
+
+#[tokio::test]
+async fn test_raw_transaction_source_data_fetching() {
+    let source = SuiSource::builder()
+        .max_transactions(5)
+        .query_options(SuiTransactionBlockResponseOptions::full_content())
+        .build()
+        .expect("Valid configuration");
+    let mut source = SuiRawTransactionSource::new(source);
+    source.init().await.expect("Initialization failed");
+
+    let transaction = source
+        .next()
+        .await
+        .expect("Fetching transactions should succeed")
+        .expect("A transaction should be emitted");
+    assert!(
+        transaction.data.effects.is_some(),
+        "Requested effects should be populated"
+    );
+    assert!(
+        transaction.data.object_changes.is_some(),
+        "Requested object changes should be populated"
+    );
+}
@@ -0,0 +1,56 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_recipient_extracted_from_balance_changes() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(50)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut recipient_found = false;
+    for _ in 0..200 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        if let Some(recipient) = record.data.recipient {
+            assert_ne!(
+                recipient, record.data.sender,
+                "The sender should not be its own recipient"
+            );
+            recipient_found = true;
+            break;
+        }
+    }
+    assert!(recipient_found, "Some transfer should have a recipient");
+}
+
+#[tokio::test]
+async fn test_amount_extracted_from_balance_changes() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(50)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut amount_found = false;
+    for _ in 0..200 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        if record.data.recipient.is_some()
+            && let Some(amount) = record.data.amount
+        {
+            assert!(amount > 0, "Transfers should move a positive amount");
+            amount_found = true;
+            break;
+        }
+    }
+    assert!(amount_found, "Some transfer should have an amount");
+}
@@ -0,0 +1,35 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiNetwork, SuiSource, SuiSourceUpdate};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_runtime_reconfiguration() {
+    let mut source = SuiSource::for_network(SuiNetwork::Mainnet, 1_000, 5);
+    source.init().await.expect("Initialization failed");
+    let handle = source.handle();
+
+    // Changes are merged until the next poll
+    handle.set_interval(Duration::from_millis(100));
+    handle.update(SuiSourceUpdate {
+        max_transactions: Some(10),
+        ..SuiSourceUpdate::default()
+    });
+    assert_eq!(
+        source.current_interval(),
+        Duration::from_millis(1_000),
+        "Updates should wait for the next poll"
+    );
+
+    source.next().await.expect("Fetching should succeed");
+    assert_eq!(
+        source.current_interval(),
+        Duration::from_millis(100),
+        "Interval should be updated at the next poll"
+    );
+
+    // Invalid page sizes are ignored instead of breaking the running source
+    handle.set_max_transactions(0);
+    for _ in 0..12 {
+        source.next().await.expect("Fetching should succeed");
+    }
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "arrow")]
+
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiSource, sui_event_arrow_schema, sui_events_to_record_batch};
+use std::time::Duration;
+
+#[test]
+fn test_empty_record_batch_has_schema() {
+    let batch = sui_events_to_record_batch(&[]).expect("Empty batch should build");
+    assert_eq!(batch.num_rows(), 0);
+    assert_eq!(batch.schema(), sui_event_arrow_schema());
+}
+
+#[tokio::test]
+async fn test_record_batch_from_events() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut events = Vec::new();
+    for _ in 0..5 {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            events.push(record.data);
+        }
+    }
+
+    let batch = sui_events_to_record_batch(&events).expect("Batch should build");
+    assert_eq!(batch.num_rows(), events.len());
+    assert_eq!(batch.num_columns(), sui_event_arrow_schema().fields().len());
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "redis")]
+
+use fluxus_source_sui::{CursorStore, RedisCursorStore, SuiCursor};
+
+#[test]
+fn test_redis_cursor_store_key() {
+    let store = RedisCursorStore::new("redis://127.0.0.1/", "pipeline")
+        .expect("Valid Redis URL")
+        .with_key_prefix("offsets:");
+    assert_eq!(store.key(), "offsets:pipeline");
+
+    let result = RedisCursorStore::new("not a url", "pipeline");
+    assert!(result.is_err(), "Invalid Redis URL should be rejected");
+}
+
+#[tokio::test]
+async fn test_redis_cursor_store_round_trip() {
+    // Requires a Redis server on localhost
+    let store =
+        RedisCursorStore::new("redis://127.0.0.1/", "round_trip_test").expect("Valid Redis URL");
+
+    let cursor = SuiCursor {
+        digest: Default::default(),
+        checkpoint: Some(42),
+    };
+    store.save(&cursor).await.expect("Saving should succeed");
+    let loaded = store.load().await.expect("Loading should succeed");
+    assert_eq!(loaded, Some(cursor), "Saved cursor should be loaded");
+}
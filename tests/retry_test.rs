@@ -0,0 +1,79 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{RetryPolicy, SuiSource, is_rate_limited};
+use std::time::Duration;
+
+#[test]
+fn test_retry_policy_backoff() {
+    let policy = RetryPolicy {
+        max_attempts: 10,
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_secs(1),
+        jitter: 0.0,
+        rate_limit_cooldown: Duration::from_secs(1),
+    };
+
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(4), Duration::from_millis(800));
+    assert_eq!(
+        policy.backoff(5),
+        Duration::from_secs(1),
+        "Delay should be capped"
+    );
+    assert_eq!(
+        policy.backoff(64),
+        Duration::from_secs(1),
+        "Large retries should not overflow"
+    );
+}
+
+#[test]
+fn test_builder_rejects_zero_attempts() {
+    let result = SuiSource::builder()
+        .retry_policy(RetryPolicy {
+            max_attempts: 0,
+            ..RetryPolicy::default()
+        })
+        .build();
+    assert!(result.is_err(), "Zero attempts should be rejected");
+}
+
+#[tokio::test]
+async fn test_retry_on_init_failure() {
+    let mut source = SuiSource::builder()
+        .rpc_url("https://invalid.endpoint.example.com")
+        .retry_policy(RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            jitter: 0.0,
+            rate_limit_cooldown: Duration::from_millis(10),
+        })
+        .build()
+        .expect("Valid configuration");
+
+    assert!(source.init().await.is_err(), "Invalid endpoint should fail");
+    assert_eq!(source.retry_count(), 2, "Failed attempts should be retried");
+}
+
+#[tokio::test]
+async fn test_connection_errors_are_not_rate_limits() {
+    let mut source = SuiSource::builder()
+        .rpc_url("https://invalid.endpoint.example.com")
+        .retry_policy(RetryPolicy {
+            max_attempts: 2,
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            jitter: 0.0,
+            rate_limit_cooldown: Duration::from_millis(10),
+        })
+        .build()
+        .expect("Valid configuration");
+
+    let error = source
+        .init()
+        .await
+        .expect_err("Invalid endpoint should fail");
+    assert!(!is_rate_limited(&error));
+    assert_eq!(source.rate_limited_count(), 0);
+}
@@ -0,0 +1,76 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiCheckpointSource, SuiNetwork, SuiShard, SuiSource};
+use std::collections::HashSet;
+use std::time::Duration;
+
+#[test]
+fn test_shard_assignment() {
+    let shards: Vec<SuiShard> = (0..3)
+        .map(|index| SuiShard::new(index, 3).expect("Valid shard"))
+        .collect();
+    for checkpoint in 100..110 {
+        let owners = shards
+            .iter()
+            .filter(|shard| shard.contains(checkpoint))
+            .count();
+        assert_eq!(
+            owners, 1,
+            "Every checkpoint should belong to exactly one shard"
+        );
+    }
+
+    assert!(
+        SuiShard::new(3, 3).is_err(),
+        "Index should be below the count"
+    );
+    assert!(
+        SuiShard::new(0, 0).is_err(),
+        "Zero shards should be rejected"
+    );
+    assert!(SuiSource::builder().shard(2, 2).build().is_err());
+}
+
+#[tokio::test]
+async fn test_sharded_checkpoint_range_splits_transactions() {
+    let mut digests = Vec::new();
+    for index in 0..2 {
+        let mut source = SuiSource::builder()
+            .interval(Duration::from_millis(200))
+            .with_checkpoint_range(100_000_000, 100_000_003)
+            .shard(index, 2)
+            .build()
+            .expect("Valid configuration");
+        source.init().await.expect("Initialization failed");
+
+        let mut shard_digests = HashSet::new();
+        while !source.is_finished() {
+            if let Some(record) = source.next().await.expect("Fetching should succeed") {
+                shard_digests.insert(record.data.transaction_digest);
+            }
+        }
+        assert!(
+            !shard_digests.is_empty(),
+            "Every shard should get checkpoints"
+        );
+        digests.push(shard_digests);
+    }
+
+    assert!(
+        digests[0].is_disjoint(&digests[1]),
+        "Shards should not emit the same transaction"
+    );
+}
+
+#[tokio::test]
+async fn test_sharded_checkpoint_source() {
+    let mut source = SuiCheckpointSource::new(SuiNetwork::Mainnet.rpc_url().to_string(), 200, 10)
+        .with_shard(1, 2)
+        .expect("Valid shard");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..5 {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            assert_eq!(record.data.sequence_number % 2, 1);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_shared_object_usage_reported() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut shared_found = false;
+    for _ in 0..30 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        let event = record.data;
+        assert_eq!(event.uses_shared_objects, !event.shared_objects.is_empty());
+        shared_found |= event.uses_shared_objects;
+    }
+    // Consensus commit prologues update the shared clock object
+    assert!(shared_found, "Some transaction should touch shared objects");
+}
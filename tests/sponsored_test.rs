@@ -0,0 +1,23 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_gas_owner_and_sponsorship() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..20 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        let event = record.data;
+        assert!(event.gas_owner.starts_with("0x"), "Gas owner should be set");
+        assert_eq!(event.is_sponsored, event.gas_owner != event.sender);
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "sqlite")]
+
+use fluxus_source_sui::{CursorStore, SqlCursorStore, SuiCursor};
+
+#[tokio::test]
+async fn test_sqlite_cursor_store_round_trip() {
+    let path = std::env::temp_dir().join("fluxus_sui_cursors.db");
+    let _ = std::fs::remove_file(&path);
+    let database_url = format!("sqlite://{}?mode=rwc", path.display());
+
+    let store = SqlCursorStore::connect(&database_url, "pipeline")
+        .await
+        .expect("Connecting should succeed");
+    let loaded = store.load().await.expect("Loading should succeed");
+    assert!(loaded.is_none(), "New source should have no cursor");
+
+    let mut cursor = SuiCursor {
+        digest: Default::default(),
+        checkpoint: Some(42),
+    };
+    store.save(&cursor).await.expect("Saving should succeed");
+    cursor.checkpoint = Some(43);
+    store.save(&cursor).await.expect("Updating should succeed");
+
+    let loaded = store.load().await.expect("Loading should succeed");
+    assert_eq!(loaded, Some(cursor), "Latest cursor should be loaded");
+
+    // Other sources keep their own cursor
+    let other = SqlCursorStore::connect(&database_url, "other")
+        .await
+        .expect("Connecting should succeed");
+    let loaded = other.load().await.expect("Loading should succeed");
+    assert!(loaded.is_none(), "Cursors should be kept per source");
+
+    let _ = std::fs::remove_file(&path);
+}
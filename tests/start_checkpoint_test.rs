@@ -0,0 +1,40 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiRawTransactionSource, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_resume_from_start_checkpoint() {
+    let start_checkpoint = 100_000_000;
+    let mut source = SuiRawTransactionSource::new(
+        SuiSource::builder()
+            .interval(Duration::from_millis(200))
+            .max_transactions(10)
+            .with_start_checkpoint(start_checkpoint)
+            .build()
+            .expect("Valid configuration"),
+    );
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Fetching should succeed")
+        .expect("Historical transactions should be available");
+    assert_eq!(
+        record.data.checkpoint,
+        Some(start_checkpoint),
+        "The first transaction should come from the start checkpoint"
+    );
+}
+
+#[test]
+fn test_start_checkpoint_conflicts_with_range() {
+    let result = SuiSource::builder()
+        .with_checkpoint_range(10, 20)
+        .with_start_checkpoint(5)
+        .build();
+    assert!(
+        result.is_err(),
+        "Start checkpoint should not be combined with a checkpoint range"
+    );
+}
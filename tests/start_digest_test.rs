@@ -0,0 +1,54 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiRawTransactionSource, SuiSource, SuiSourceBuilder};
+use std::time::Duration;
+
+fn raw_source(
+    configure: impl FnOnce(SuiSourceBuilder) -> SuiSourceBuilder,
+) -> SuiRawTransactionSource {
+    let builder = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(5);
+    SuiRawTransactionSource::new(configure(builder).build().expect("Valid configuration"))
+}
+
+#[tokio::test]
+async fn test_resume_after_start_digest() {
+    // Record two consecutive historical transactions
+    let mut source = raw_source(|builder| builder.with_start_checkpoint(100_000_000));
+    source.init().await.expect("Initialization failed");
+    let first = source
+        .next()
+        .await
+        .expect("Fetching should succeed")
+        .expect("Transaction");
+    let second = source
+        .next()
+        .await
+        .expect("Fetching should succeed")
+        .expect("Transaction");
+
+    // Resuming after the first one continues with the second
+    let mut resumed = raw_source(|builder| builder.with_start_digest(first.data.digest));
+    resumed.init().await.expect("Initialization failed");
+    let next = resumed
+        .next()
+        .await
+        .expect("Fetching should succeed")
+        .expect("Transaction");
+    assert_eq!(
+        next.data.digest, second.data.digest,
+        "Source should resume right after the start digest"
+    );
+}
+
+#[test]
+fn test_start_digest_conflicts_with_start_checkpoint() {
+    let result = SuiSource::builder()
+        .with_start_checkpoint(5)
+        .with_start_digest(Default::default())
+        .build();
+    assert!(
+        result.is_err(),
+        "Start digest should not be combined with a start checkpoint"
+    );
+}
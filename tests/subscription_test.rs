@@ -0,0 +1,75 @@
+#![cfg(feature = "websocket")]
+
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiNetwork, SuiSource, SuiSubscriptionSource};
+use std::time::Duration;
+use sui_sdk::rpc_types::TransactionFilter;
+use sui_sdk::types::base_types::SuiAddress;
+
+/// Source following the transactions sent by the zero address
+fn filtered_source() -> SuiSource {
+    SuiSource::builder()
+        .network(SuiNetwork::Mainnet)
+        .max_transactions(5)
+        .filter(TransactionFilter::FromAddress(SuiAddress::ZERO))
+        .build()
+        .expect("Valid configuration")
+}
+
+#[tokio::test]
+async fn test_subscription_requires_initialization() {
+    let mut source = SuiSubscriptionSource::new(filtered_source(), SuiNetwork::Mainnet.ws_url())
+        .expect("Filtered sources can subscribe")
+        .with_resubscribe_interval(Duration::from_secs(1))
+        .with_stall_timeout(Duration::from_secs(5));
+    assert!(!source.is_subscribed(), "Nothing is subscribed before init");
+
+    let result = source.next().await;
+    assert!(result.is_err(), "Polling fallback requires initialization");
+}
+
+#[test]
+fn test_subscription_requires_filter() {
+    let result = SuiSubscriptionSource::new(
+        SuiSource::for_network(SuiNetwork::Mainnet, 500, 5),
+        SuiNetwork::Mainnet.ws_url(),
+    );
+    assert!(
+        result.is_err(),
+        "Subscriptions without a transaction filter should be rejected"
+    );
+}
+
+#[test]
+fn test_subscription_rejects_watermarks() {
+    let source = SuiSource::builder()
+        .network(SuiNetwork::Mainnet)
+        .filter(TransactionFilter::FromAddress(SuiAddress::ZERO))
+        .watermarks(true)
+        .build()
+        .expect("Valid configuration");
+    let result = SuiSubscriptionSource::new(source, SuiNetwork::Mainnet.ws_url());
+    assert!(
+        result.is_err(),
+        "Watermarks should be rejected for pushed transactions"
+    );
+}
+
+#[tokio::test]
+async fn test_subscription_falls_back_to_polling() {
+    // Nothing listens on the WebSocket endpoint
+    let mut source = SuiSubscriptionSource::new(filtered_source(), "ws://127.0.0.1:1")
+        .expect("Filtered sources can subscribe")
+        .with_resubscribe_interval(Duration::from_secs(60));
+    source
+        .init()
+        .await
+        .expect("An unreachable WebSocket should not fail initialization");
+    assert!(!source.is_subscribed(), "The subscription cannot be opened");
+
+    let result = source.next().await;
+    assert!(
+        result.is_ok(),
+        "Transactions should be polled while the WebSocket is unreachable"
+    );
+}
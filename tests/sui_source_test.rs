@@ -1,12 +1,12 @@
 use fluxus::sources::Source;
-use fluxus_source_sui::SuiSource;
+use fluxus_source_sui::{SuiNetwork, SuiSource};
 use std::time::Duration;
 use tokio::time::sleep;
 
 #[tokio::test]
 async fn test_sui_source_initialization() {
     // Create a new SuiSource instance with mainnet configuration
-    let mut source = SuiSource::new_with_mainnet(500, 10);
+    let mut source = SuiSource::for_network(SuiNetwork::Mainnet, 500, 10);
 
     // Test initialization
     let init_result = source.init().await;
@@ -29,7 +29,7 @@ async fn test_sui_source_custom_endpoint() {
 #[tokio::test]
 async fn test_sui_source_data_fetching() {
     // Create SuiSource instance
-    let mut source = SuiSource::new_with_mainnet(500, 5);
+    let mut source = SuiSource::for_network(SuiNetwork::Mainnet, 500, 5);
 
     // Initialize
     source.init().await.expect("Initialization failed");
@@ -54,7 +54,7 @@ async fn test_sui_source_data_fetching() {
 #[tokio::test]
 async fn test_sui_source_polling_interval() {
     // Create SuiSource with longer polling interval
-    let mut source = SuiSource::new_with_mainnet(1000, 5);
+    let mut source = SuiSource::for_network(SuiNetwork::Mainnet, 1000, 5);
     source.init().await.expect("Initialization failed");
 
     // Record start time
@@ -76,7 +76,7 @@ async fn test_sui_source_polling_interval() {
 async fn test_sui_source_batch_size() {
     // Create SuiSource with specified batch size
     let batch_size = 3;
-    let mut source = SuiSource::new_with_mainnet(500, batch_size);
+    let mut source = SuiSource::for_network(SuiNetwork::Mainnet, 500, batch_size);
     source.init().await.expect("Initialization failed");
 
     // Get multiple batches of data
@@ -106,3 +106,23 @@ async fn test_sui_source_error_handling() {
         "Initialization with invalid endpoint should fail"
     );
 }
+
+#[tokio::test]
+async fn test_sui_source_checkpoint_range() {
+    // Create SuiSource bounded to a single historical checkpoint
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(100))
+        .with_checkpoint_range(1_000_000, 1_000_000)
+        .build()
+        .expect("Invalid configuration");
+    source.init().await.expect("Initialization failed");
+
+    // Drain the range
+    while let Ok(Some(_)) = source.next().await {}
+
+    assert!(source.is_finished(), "Checkpoint range should be exhausted");
+    assert!(
+        matches!(source.next().await, Ok(None)),
+        "Finished source should keep returning None"
+    );
+}
@@ -0,0 +1,28 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSystemStateSource;
+
+#[tokio::test]
+async fn test_system_state_source_initial_snapshot() {
+    let mut source =
+        SuiSystemStateSource::new("https://fullnode.mainnet.sui.io:443".to_string(), 500);
+    source.init().await.expect("Initialization failed");
+
+    // The initial snapshot has no previous state
+    let change = source
+        .next()
+        .await
+        .expect("Fetching system state should succeed")
+        .expect("Initial snapshot should be emitted");
+    assert!(
+        change.data.previous.is_none(),
+        "Initial snapshot has no previous state"
+    );
+    assert!(
+        change.data.state.total_stake > 0,
+        "Network should have stake"
+    );
+    assert!(
+        !change.data.state.safe_mode,
+        "Mainnet should not be in safe mode"
+    );
+}
@@ -0,0 +1,43 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiSource, TransactionMetadata};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_system_transactions_can_be_skipped() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(50)
+        .with_start_checkpoint(100_000_000)
+        .skip_system_transactions(true)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..100 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        assert!(
+            !record.data.metadata.is_system(),
+            "System transactions should be dropped"
+        );
+    }
+}
+
+#[test]
+fn test_system_transaction_detection() {
+    assert!(TransactionMetadata::ChangeEpoch { epoch: 1 }.is_system());
+    assert!(
+        TransactionMetadata::System {
+            kind: "RandomnessStateUpdate".to_string()
+        }
+        .is_system()
+    );
+    assert!(
+        !TransactionMetadata::Programmable {
+            commands: Vec::new()
+        }
+        .is_system()
+    );
+    assert!(!TransactionMetadata::Unknown.is_system());
+}
@@ -0,0 +1,29 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[test]
+fn test_timeout_configuration() {
+    let result = SuiSource::builder().next_timeout(Duration::ZERO).build();
+    assert!(result.is_err(), "Zero next() deadline should be rejected");
+
+    let result = SuiSource::builder().request_timeout(Duration::ZERO).build();
+    assert!(result.is_err(), "Zero request timeout should be rejected");
+}
+
+#[tokio::test]
+async fn test_next_deadline_exceeded() {
+    // The polling interval alone exceeds the deadline
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_secs(5))
+        .next_timeout(Duration::from_millis(100))
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(
+        result.is_err(),
+        "next() should fail once the deadline passed"
+    );
+}
@@ -0,0 +1,44 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_balance_changes_included_in_transactions() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut changes_found = false;
+    for _ in 0..50 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        let event = record.data;
+        if event.transaction_type != "ProgrammableTransaction" {
+            continue;
+        }
+
+        // Every user transaction pays gas, so some SUI balance changes
+        assert!(
+            event
+                .balance_changes
+                .iter()
+                .any(|change| change.coin_type.ends_with("::sui::SUI")),
+            "The gas payment should appear as a balance change"
+        );
+        assert!(
+            event
+                .balance_changes
+                .iter()
+                .all(|change| change.amount != 0),
+            "Only actual changes should be listed"
+        );
+        changes_found = true;
+        break;
+    }
+    assert!(changes_found, "Some user transaction should be streamed");
+}
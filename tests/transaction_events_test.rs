@@ -0,0 +1,29 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_move_events_included_in_transactions() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(50)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut events_found = false;
+    for _ in 0..200 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        if let Some(event) = record.data.events.first() {
+            assert!(event.event_type.contains("::"), "Type should be qualified");
+            assert!(event.package_id.starts_with("0x"));
+            assert!(!event.parsed_json.is_null(), "Fields should be decoded");
+            events_found = true;
+            break;
+        }
+    }
+    assert!(events_found, "Some transaction should emit events");
+}
@@ -0,0 +1,32 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{ObjectChangeKind, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_object_changes_included_in_transactions() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration");
+    source.init().await.expect("Initialization failed");
+
+    let mut changes_found = false;
+    for _ in 0..50 {
+        let Some(record) = source.next().await.expect("Failed to get next record") else {
+            continue;
+        };
+        for change in &record.data.object_changes {
+            assert!(change.object_id.starts_with("0x"));
+            if change.change_type != ObjectChangeKind::Published {
+                assert!(change.object_type.is_some(), "Objects should have a type");
+            }
+            changes_found = true;
+        }
+        if changes_found {
+            break;
+        }
+    }
+    assert!(changes_found, "Some transaction should change objects");
+}
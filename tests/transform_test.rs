@@ -0,0 +1,52 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiSource;
+use std::time::Duration;
+use sui_sdk::rpc_types::SuiTransactionBlockEffectsAPI;
+
+#[tokio::test]
+async fn test_transform_emits_custom_type() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration")
+        .with_transform(|transaction| {
+            let effects = transaction.effects?;
+            Some((transaction.digest.to_string(), effects.created().len()))
+        });
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Failed to get next record")
+        .expect("No record available");
+    let (digest, _created) = record.data;
+    assert!(!digest.is_empty());
+}
+
+#[tokio::test]
+async fn test_transform_skips_rejected_transactions() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(10)
+        .with_start_checkpoint(100_000_000)
+        .build()
+        .expect("Valid configuration")
+        .with_transform(|transaction| {
+            let checkpoint = transaction.checkpoint?;
+            (checkpoint % 2 == 0).then_some(checkpoint)
+        });
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..5 {
+        if let Some(record) = source.next().await.expect("Fetching should succeed") {
+            assert_eq!(
+                record.data % 2,
+                0,
+                "Rejected transactions should be skipped"
+            );
+        }
+    }
+}
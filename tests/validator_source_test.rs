@@ -0,0 +1,24 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiValidatorSource, ValidatorSnapshotTrigger};
+
+#[tokio::test]
+async fn test_validator_source_snapshot() {
+    let mut source = SuiValidatorSource::new(
+        "https://fullnode.mainnet.sui.io:443".to_string(),
+        500,
+        ValidatorSnapshotTrigger::EpochChange,
+    );
+    source.init().await.expect("Initialization failed");
+
+    // The first snapshot is emitted right away
+    let validator = source
+        .next()
+        .await
+        .expect("Fetching validators should succeed")
+        .expect("Validator snapshot should be emitted");
+    assert!(
+        !validator.data.address.is_empty(),
+        "Validator address should not be empty"
+    );
+    assert!(validator.data.stake > 0, "Validator should have stake");
+}
@@ -0,0 +1,53 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiQueryOrder, SuiSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_watermark_follows_completed_checkpoints() {
+    let mut source = SuiSource::builder()
+        .interval(Duration::from_millis(200))
+        .max_transactions(50)
+        .with_checkpoint_range(100_000_000, 100_000_002)
+        .watermarks(true)
+        .build()
+        .expect("Valid configuration");
+    let mut receiver = source
+        .watermark_receiver()
+        .expect("Watermarks should be enabled");
+    source.init().await.expect("Initialization failed");
+    assert_eq!(source.watermark(), None);
+
+    while !source.is_finished() {
+        let Some(record) = source.next().await.expect("Fetching should succeed") else {
+            continue;
+        };
+        if let Some(watermark) = source.watermark() {
+            assert!(watermark.checkpoint <= 100_000_002);
+            assert!(watermark.timestamp_ms <= record.data.timestamp);
+        }
+    }
+
+    let watermark = source.watermark().expect("Range end should be a watermark");
+    assert_eq!(watermark.checkpoint, 100_000_002);
+    assert!(receiver.has_changed().expect("Source should be alive"));
+    assert_eq!(*receiver.borrow_and_update(), Some(watermark));
+}
+
+#[tokio::test]
+async fn test_watermarks_disabled_by_default() {
+    let source = SuiSource::builder().build().expect("Valid configuration");
+    assert!(source.watermark_receiver().is_none());
+    assert_eq!(source.watermark(), None);
+}
+
+#[test]
+fn test_watermarks_reject_descending_order() {
+    let result = SuiSource::builder()
+        .order(SuiQueryOrder::Descending)
+        .watermarks(true)
+        .build();
+    assert!(
+        result.is_err(),
+        "Watermarks should be rejected with descending order"
+    );
+}